@@ -18,11 +18,14 @@
 #![allow(clippy::too_many_arguments)]
 
 use crate::{
+    cli::Sealing,
     client::{RuntimeApiCommon, RuntimeApiNimbus},
+    dev_timestamp::DevTimestamp,
     instant_finalize::InstantFinalizeBlockImport,
     service::{Client, StateBackend, TransactionPool},
 };
-use std::future::Future;
+use futures::{channel::mpsc::Receiver, future::BoxFuture};
+use sc_consensus_manual_seal::EngineCommand;
 
 pub use manta_primitives::types::{AccountId, Balance, Block, Hash, Header, Index as Nonce};
 use polkadot_service::CollatorPair;
@@ -150,20 +153,27 @@ where
     }))
 }
 
-/// build standalone mode dev consensus using manual instant seal
+/// build standalone mode dev consensus using manual seal
+///
+/// Blocks are sealed according to `sealing`, as well as on the `rpc_commands` sent by the
+/// manual seal RPC. Their timestamps are taken from `timestamp`.
 pub fn build_dev_nimbus_consensus<RuntimeApi>(
     client: Arc<Client<RuntimeApi>>,
     transaction_pool: Arc<TransactionPool<RuntimeApi>>,
     keystore_container: &KeystoreContainer,
     select_chain: LongestChain<TFullBackend<Block>, Block>,
     task_manager: &TaskManager,
-) -> Result<impl Future<Output = ()> + Send + 'static, Error>
+    sealing: Sealing,
+    rpc_commands: Receiver<EngineCommand<Hash>>,
+    timestamp: DevTimestamp,
+) -> Result<BoxFuture<'static, ()>, Error>
 where
     RuntimeApi: ConstructRuntimeApi<Block, Client<RuntimeApi>> + Send + Sync + 'static,
     RuntimeApi::RuntimeApi: RuntimeApiCommon<StateBackend = StateBackend> + RuntimeApiNimbus,
 {
-    use futures::{Stream, StreamExt};
-    use sc_consensus_manual_seal::{run_manual_seal, EngineCommand, ManualSealParams};
+    use futures::{stream, FutureExt, Stream, StreamExt};
+    use sc_consensus_manual_seal::{run_manual_seal, ManualSealParams};
+    use std::{pin::Pin, time::Duration};
 
     let proposer_factory = sc_basic_authorship::ProposerFactory::new(
         task_manager.spawn_handle(),
@@ -173,8 +183,8 @@ where
         None,
     );
 
-    let commands_stream: Box<dyn Stream<Item = EngineCommand<Hash>> + Send + Sync + Unpin> =
-        Box::new(
+    let commands_stream: Pin<Box<dyn Stream<Item = EngineCommand<Hash>> + Send>> = match sealing {
+        Sealing::Instant => Box::pin(stream::select(
             // This bit cribbed from the implementation of instant seal.
             transaction_pool
                 .pool()
@@ -186,53 +196,89 @@ where
                     parent_hash: None,
                     sender: None,
                 }),
-        );
+            rpc_commands,
+        )),
+        Sealing::Manual => Box::pin(rpc_commands),
+        Sealing::Interval(millis) => Box::pin(stream::select(
+            stream::unfold((), move |()| async move {
+                futures_timer::Delay::new(Duration::from_millis(millis)).await;
+                Some((
+                    EngineCommand::SealNewBlock {
+                        create_empty: true,
+                        finalize: true,
+                        parent_hash: None,
+                        sender: None,
+                    },
+                    (),
+                ))
+            }),
+            rpc_commands,
+        )),
+    };
 
     let client_set_aside_for_cidp = client.clone();
 
-    let consensus = run_manual_seal(ManualSealParams {
-        block_import: InstantFinalizeBlockImport::new(client.clone()),
-        env: proposer_factory,
+    let consensus_data_provider = Box::new(NimbusManualSealConsensusDataProvider {
+        keystore: keystore_container.sync_keystore(),
         client: client.clone(),
-        pool: transaction_pool,
-        commands_stream,
-        select_chain,
-        consensus_data_provider: Some(Box::new(NimbusManualSealConsensusDataProvider {
-            keystore: keystore_container.sync_keystore(),
-            client,
-            additional_digests_provider: (),
-            _phantom: Default::default(),
-        })),
-        create_inherent_data_providers: move |block: Hash, ()| {
-            let current_para_block = client_set_aside_for_cidp
-                .number(block)
-                .expect("Header lookup should succeed")
-                .expect("Header passed in as parent should be present in backend.");
-
-            let client_for_xcm = client_set_aside_for_cidp.clone();
-            async move {
-                let time = sp_timestamp::InherentDataProvider::from_system_time();
-
-                let mocked_parachain = MockValidationDataInherentDataProvider {
-                    current_para_block,
-                    relay_offset: 1000,
-                    relay_blocks_per_para_block: 2,
-                    para_blocks_per_relay_epoch: 0,
-                    relay_randomness_config: (),
-                    xcm_config: MockXcmConfig::new(
-                        &*client_for_xcm,
-                        block,
-                        Default::default(),
-                        Default::default(),
-                    ),
-                    raw_downward_messages: vec![],
-                    raw_horizontal_messages: vec![],
-                };
-
-                Ok((time, mocked_parachain))
-            }
-        },
+        additional_digests_provider: (),
+        _phantom: Default::default(),
     });
 
+    let create_inherent_data_providers = move |block: Hash, ()| {
+        let current_para_block = client_set_aside_for_cidp
+            .number(block)
+            .expect("Header lookup should succeed")
+            .expect("Header passed in as parent should be present in backend.");
+
+        let client_for_xcm = client_set_aside_for_cidp.clone();
+        let time = timestamp.next();
+        async move {
+            let mocked_parachain = MockValidationDataInherentDataProvider {
+                current_para_block,
+                relay_offset: 1000,
+                relay_blocks_per_para_block: 2,
+                para_blocks_per_relay_epoch: 0,
+                relay_randomness_config: (),
+                xcm_config: MockXcmConfig::new(
+                    &*client_for_xcm,
+                    block,
+                    Default::default(),
+                    Default::default(),
+                ),
+                raw_downward_messages: vec![],
+                raw_horizontal_messages: vec![],
+            };
+
+            Ok((time, mocked_parachain))
+        }
+    };
+
+    // Instantly sealed blocks are finalized on import, the other ones only when requested.
+    let consensus = match sealing {
+        Sealing::Instant => run_manual_seal(ManualSealParams {
+            block_import: InstantFinalizeBlockImport::new(client.clone()),
+            env: proposer_factory,
+            client,
+            pool: transaction_pool,
+            commands_stream,
+            select_chain,
+            consensus_data_provider: Some(consensus_data_provider),
+            create_inherent_data_providers,
+        })
+        .boxed(),
+        _ => run_manual_seal(ManualSealParams {
+            block_import: client.clone(),
+            env: proposer_factory,
+            client,
+            pool: transaction_pool,
+            commands_stream,
+            select_chain,
+            consensus_data_provider: Some(consensus_data_provider),
+            create_inherent_data_providers,
+        })
+        .boxed(),
+    };
+
     Ok(consensus)
 }
@@ -231,10 +231,108 @@ fn calamari_dev_genesis(
         council_membership: Default::default(),
         technical_membership: Default::default(),
         asset_manager: Default::default(),
+        calamari_vesting: Default::default(),
         parachain_system: Default::default(),
         polkadot_xcm: calamari_runtime::PolkadotXcmConfig {
             safe_xcm_version: Some(SAFE_XCM_VERSION),
         },
+        manta_pay: Default::default(),
+        manta_sbt: Default::default(),
+    }
+}
+
+/// Returns the Calamari chainspec of the local testnet described by `testnet`.
+pub fn calamari_local_testnet_config(testnet: &LocalTestnet) -> Result<CalamariChainSpec, String> {
+    if testnet.sudo.is_some() {
+        return Err("Calamari has no sudo key".into());
+    }
+    let genesis = testnet.resolve(
+        CALAMARI_ENDOWMENT,
+        calamari_runtime::staking::NORMAL_COLLATOR_MINIMUM_STAKE,
+    )?;
+    Ok(CalamariChainSpec::from_genesis(
+        testnet
+            .name
+            .as_deref()
+            .unwrap_or("Calamari Parachain Local"),
+        testnet.id.as_deref().unwrap_or("calamari_local"),
+        ChainType::Local,
+        move || calamari_local_testnet_genesis(genesis.clone()),
+        vec![],
+        None,
+        Some(CALAMARI_PROTOCOL_ID),
+        None,
+        Some(calamari_properties()),
+        Extensions {
+            relay_chain: testnet
+                .relay_chain
+                .clone()
+                .unwrap_or_else(|| KUSAMA_RELAYCHAIN_LOCAL_NET.into()),
+            para_id: CALAMARI_PARACHAIN_ID,
+        },
+    ))
+}
+
+fn calamari_local_testnet_genesis(genesis: LocalTestnetGenesis) -> GenesisConfig {
+    let invulnerables = genesis.collator_keys(SessionKeys::from_seed_unchecked);
+    GenesisConfig {
+        system: calamari_runtime::SystemConfig {
+            code: calamari_runtime::WASM_BINARY
+                .expect("WASM binary was not build, please build it!")
+                .to_vec(),
+        },
+        balances: calamari_runtime::BalancesConfig {
+            balances: genesis.balances,
+        },
+        aura: Default::default(),
+        parachain_staking: ParachainStakingConfig {
+            candidates: invulnerables
+                .iter()
+                .map(|(account, _)| (account.clone(), genesis.collator_stake))
+                .collect(),
+            delegations: genesis.delegations,
+            inflation_config: calamari_runtime::staking::inflation_config::<
+                calamari_runtime::Runtime,
+            >(),
+        },
+        parachain_info: calamari_runtime::ParachainInfoConfig {
+            parachain_id: CALAMARI_PARACHAIN_ID.into(),
+        },
+        collator_selection: calamari_runtime::CollatorSelectionConfig {
+            invulnerables: invulnerables.iter().map(|(acc, _)| acc.clone()).collect(),
+            candidacy_bond: 400_000 * KMA,
+            ..Default::default()
+        },
+        session: calamari_runtime::SessionConfig {
+            keys: invulnerables
+                .into_iter()
+                .map(|(acc, session_keys)| (acc.clone(), acc, session_keys))
+                .collect(),
+        },
+        democracy: DemocracyConfig::default(),
+        council: CouncilConfig {
+            members: genesis.council,
+            phantom: Default::default(),
+        },
+        technical_committee: TechnicalCommitteeConfig {
+            members: genesis.technical_committee,
+            phantom: Default::default(),
+        },
+        council_membership: Default::default(),
+        technical_membership: Default::default(),
+        asset_manager: calamari_runtime::AssetManagerConfig {
+            assets: genesis.assets,
+            ..Default::default()
+        },
+        calamari_vesting: calamari_runtime::CalamariVestingConfig {
+            vesting: genesis.vesting,
+        },
+        parachain_system: Default::default(),
+        polkadot_xcm: calamari_runtime::PolkadotXcmConfig {
+            safe_xcm_version: Some(SAFE_XCM_VERSION),
+        },
+        manta_pay: genesis.manta_pay,
+        manta_sbt: genesis.manta_sbt,
     }
 }
 /// Returns the Calamari testnet chainspec.
@@ -214,6 +214,87 @@ fn dolphin_dev_genesis(
         polkadot_xcm: dolphin_runtime::PolkadotXcmConfig {
             safe_xcm_version: Some(SAFE_XCM_VERSION),
         },
+        manta_pay: Default::default(),
+        manta_sbt: Default::default(),
+    }
+}
+
+/// Returns the Dolphin chainspec of the local testnet described by `testnet`.
+pub fn dolphin_local_testnet_config(testnet: &LocalTestnet) -> Result<DolphinChainSpec, String> {
+    if !testnet.delegations.is_empty() || testnet.collator_stake.is_some() {
+        return Err("Dolphin has no parachain staking".into());
+    }
+    if !testnet.vesting.is_empty() {
+        return Err("Dolphin has no vesting".into());
+    }
+    let genesis = testnet.resolve(DOLPHIN_ENDOWMENT, 0)?;
+    Ok(DolphinChainSpec::from_genesis(
+        testnet.name.as_deref().unwrap_or("Dolphin Parachain Local"),
+        testnet.id.as_deref().unwrap_or("dolphin_local"),
+        ChainType::Local,
+        move || dolphin_local_testnet_genesis(genesis.clone()),
+        vec![],
+        None,
+        Some(DOLPHIN_PROTOCOL_ID),
+        None,
+        Some(dolphin_properties()),
+        Extensions {
+            relay_chain: testnet.relay_chain.clone().unwrap_or_default(),
+            para_id: DOLPHIN_PARACHAIN_ID,
+        },
+    ))
+}
+
+fn dolphin_local_testnet_genesis(genesis: LocalTestnetGenesis) -> GenesisConfig {
+    let invulnerables = genesis.collator_keys(SessionKeys::from_seed_unchecked);
+    GenesisConfig {
+        system: dolphin_runtime::SystemConfig {
+            code: dolphin_runtime::WASM_BINARY
+                .expect("WASM binary was not build, please build it!")
+                .to_vec(),
+        },
+        balances: dolphin_runtime::BalancesConfig {
+            balances: genesis.balances,
+        },
+        aura: Default::default(),
+        sudo: dolphin_runtime::SudoConfig {
+            key: Some(genesis.sudo),
+        },
+        parachain_info: dolphin_runtime::ParachainInfoConfig {
+            parachain_id: DOLPHIN_PARACHAIN_ID.into(),
+        },
+        collator_selection: dolphin_runtime::CollatorSelectionConfig {
+            invulnerables: invulnerables.iter().map(|(acc, _)| acc.clone()).collect(),
+            candidacy_bond: DOL * 1000,
+            ..Default::default()
+        },
+        session: dolphin_runtime::SessionConfig {
+            keys: invulnerables
+                .into_iter()
+                .map(|(acc, session_keys)| (acc.clone(), acc, session_keys))
+                .collect(),
+        },
+        democracy: DemocracyConfig::default(),
+        council: CouncilConfig {
+            members: genesis.council,
+            phantom: Default::default(),
+        },
+        technical_committee: TechnicalCommitteeConfig {
+            members: genesis.technical_committee,
+            phantom: Default::default(),
+        },
+        asset_manager: dolphin_runtime::AssetManagerConfig {
+            assets: genesis.assets,
+            ..Default::default()
+        },
+        council_membership: Default::default(),
+        technical_membership: Default::default(),
+        parachain_system: Default::default(),
+        polkadot_xcm: dolphin_runtime::PolkadotXcmConfig {
+            safe_xcm_version: Some(SAFE_XCM_VERSION),
+        },
+        manta_pay: genesis.manta_pay,
+        manta_sbt: genesis.manta_sbt,
     }
 }
 
@@ -0,0 +1,461 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Local Testnet Descriptions
+//!
+//! A local testnet is described in TOML or JSON, for example:
+//!
+//! ```toml
+//! collators = 3
+//! sudo = "//Alice"
+//! council = ["//Alice", "//Bob"]
+//!
+//! [[endowments]]
+//! account = "//Ferdie"
+//! balance = "1000000000000000000000"
+//!
+//! [[delegations]]
+//! delegator = "//Ferdie"
+//! collator = "//Alice"
+//! amount = "5000000000000000000000"
+//!
+//! [[assets]]
+//! location = { parents = 1 }
+//! name = "Kusama"
+//! symbol = "KSM"
+//! decimals = 12
+//! min_balance = 1
+//! ```
+//!
+//! The shielded ledgers of MantaPay and MantaSBT can be preloaded with `manta_pay = "<path>"` and
+//! `manta_sbt = "<path>"`, pointing to the JSON genesis configurations written by the
+//! `precompute_coins --genesis` binaries of the pallets.
+//!
+//! Accounts are SS58 addresses or development seeds starting with `//`, and balances are given
+//! in the smallest unit of the chain, as strings when they do not fit in 64 bits.
+
+use super::*;
+use manta_primitives::assets::{AssetLocation, AssetRegistryMetadata, AssetStorageMetadata};
+use session_key_primitives::util::unchecked_account_id;
+use sp_core::crypto::Ss58Codec;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use xcm::{
+    v1::{Junction, Junctions, MultiLocation},
+    VersionedMultiLocation,
+};
+
+/// Development seeds of the generated collators, extended by `Collator<n>` seeds.
+const COLLATOR_SEEDS: &[&str] = &["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"];
+
+/// Local Testnet Description
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LocalTestnet {
+    /// Chain name, defaults to the name of the local chain spec.
+    pub name: Option<String>,
+
+    /// Chain id, defaults to the id of the local chain spec.
+    pub id: Option<String>,
+
+    /// Relay chain of the parachain, defaults to the local relay chain.
+    pub relay_chain: Option<String>,
+
+    /// Number of collators, derived from the development seeds `//Alice`, `//Bob`, ...
+    pub collators: usize,
+
+    /// Balance endowed to every collator, defaults to the chain endowment.
+    pub collator_balance: Option<Amount>,
+
+    /// Bond of every collator staking candidate, defaults to the minimum collator stake.
+    pub collator_stake: Option<Amount>,
+
+    /// Endowed accounts
+    pub endowments: Vec<Endowment>,
+
+    /// Assets registered in the asset manager
+    pub assets: Vec<Asset>,
+
+    /// Delegations to the collator staking candidates
+    pub delegations: Vec<Delegation>,
+
+    /// Vesting accounts
+    pub vesting: Vec<Vesting>,
+
+    /// Council members, defaults to the first collator.
+    pub council: Option<Vec<String>>,
+
+    /// Technical committee members, defaults to the first collator.
+    pub technical_committee: Option<Vec<String>>,
+
+    /// Sudo key, defaults to the first collator.
+    pub sudo: Option<String>,
+
+    /// MantaPay genesis configuration preloading the shielded ledger
+    pub manta_pay: Option<PathBuf>,
+
+    /// MantaSBT genesis configuration preloading the SBT ledger
+    pub manta_sbt: Option<PathBuf>,
+}
+
+/// Endowed Account
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Endowment {
+    /// Account
+    pub account: String,
+
+    /// Free balance
+    pub balance: Amount,
+}
+
+/// Staking Delegation
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Delegation {
+    /// Delegator account, which must be endowed with at least `amount`
+    pub delegator: String,
+
+    /// Collator account
+    pub collator: String,
+
+    /// Delegated amount
+    pub amount: Amount,
+}
+
+/// Vesting Account
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Vesting {
+    /// Account, which must be endowed with at least `amount`
+    pub account: String,
+
+    /// Amount locked by the vesting schedule
+    pub amount: Amount,
+}
+
+/// Registered Asset
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Asset {
+    /// Asset location
+    pub location: Location,
+
+    /// Asset name
+    pub name: String,
+
+    /// Asset symbol
+    pub symbol: String,
+
+    /// Number of decimals
+    pub decimals: u8,
+
+    /// Minimum balance
+    pub min_balance: Amount,
+
+    /// Sufficiency flag
+    #[serde(default = "default_is_sufficient")]
+    pub is_sufficient: bool,
+}
+
+/// Returns the default sufficiency flag of registered assets.
+fn default_is_sufficient() -> bool {
+    true
+}
+
+/// Asset Location
+///
+/// The interior junctions are appended in the order of the fields.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Location {
+    /// Number of parents
+    pub parents: u8,
+
+    /// Parachain id
+    pub parachain: Option<u32>,
+
+    /// Pallet instance
+    pub pallet_instance: Option<u8>,
+
+    /// General index
+    pub general_index: Option<u64>,
+
+    /// Hex encoded general key
+    pub general_key: Option<String>,
+}
+
+impl Location {
+    /// Converts the description into an [`AssetLocation`].
+    fn to_asset_location(&self) -> Result<AssetLocation, String> {
+        let mut junctions = Vec::new();
+        if let Some(id) = self.parachain {
+            junctions.push(Junction::Parachain(id));
+        }
+        if let Some(index) = self.pallet_instance {
+            junctions.push(Junction::PalletInstance(index));
+        }
+        if let Some(index) = self.general_index {
+            junctions.push(Junction::GeneralIndex(index.into()));
+        }
+        if let Some(key) = &self.general_key {
+            let key = sp_core::bytes::from_hex(key)
+                .map_err(|err| format!("Invalid general key {key}: {err}"))?;
+            junctions.push(Junction::GeneralKey(
+                key.try_into()
+                    .map_err(|_| "General key is too long".to_string())?,
+            ));
+        }
+        let interior = match junctions[..] {
+            [] => Junctions::Here,
+            [ref a] => Junctions::X1(a.clone()),
+            [ref a, ref b] => Junctions::X2(a.clone(), b.clone()),
+            [ref a, ref b, ref c] => Junctions::X3(a.clone(), b.clone(), c.clone()),
+            [ref a, ref b, ref c, ref d] => {
+                Junctions::X4(a.clone(), b.clone(), c.clone(), d.clone())
+            }
+            _ => unreachable!("A location has at most four junctions."),
+        };
+        Ok(AssetLocation(VersionedMultiLocation::V1(MultiLocation {
+            parents: self.parents,
+            interior,
+        })))
+    }
+}
+
+/// Balance in the smallest unit of the chain, given as an integer or a decimal string.
+#[derive(Clone, Copy, Debug)]
+pub struct Amount(pub Balance);
+
+impl<'de> Deserialize<'de> for Amount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Integer(u64),
+            Text(String),
+        }
+        match Raw::deserialize(deserializer)? {
+            Raw::Integer(amount) => Ok(Self(amount.into())),
+            Raw::Text(amount) => {
+                amount.replace('_', "").parse().map(Self).map_err(|err| {
+                    serde::de::Error::custom(format!("Invalid amount {amount}: {err}"))
+                })
+            }
+        }
+    }
+}
+
+/// Resolved Local Testnet
+///
+/// Genesis parameters which are shared by all runtimes, with the accounts of the description
+/// resolved and the balances checked.
+#[derive(Clone, Debug)]
+pub struct LocalTestnetGenesis {
+    /// Collator accounts and the development seeds of their session keys
+    pub collators: Vec<(AccountId, String)>,
+
+    /// Bond of every collator staking candidate
+    pub collator_stake: Balance,
+
+    /// Endowed balances
+    pub balances: Vec<(AccountId, Balance)>,
+
+    /// Registered assets
+    pub assets: Vec<(AssetLocation, AssetRegistryMetadata<Balance>)>,
+
+    /// Delegations as `(delegator, collator, amount)`
+    pub delegations: Vec<(AccountId, AccountId, Balance)>,
+
+    /// Vesting accounts
+    pub vesting: Vec<(AccountId, Balance)>,
+
+    /// Council members
+    pub council: Vec<AccountId>,
+
+    /// Technical committee members
+    pub technical_committee: Vec<AccountId>,
+
+    /// Sudo key
+    pub sudo: AccountId,
+
+    /// Preloaded MantaPay ledger
+    pub manta_pay: pallet_manta_pay::GenesisConfig,
+
+    /// Preloaded MantaSBT ledger
+    pub manta_sbt: pallet_manta_sbt::GenesisConfig,
+}
+
+impl LocalTestnet {
+    /// Loads the description from a TOML file, or from a JSON file for any other extension.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+        if path
+            .extension()
+            .map_or(false, |extension| extension == "toml")
+        {
+            toml::from_str(&content).map_err(|err| format!("Invalid {}: {err}", path.display()))
+        } else {
+            serde_json::from_str(&content)
+                .map_err(|err| format!("Invalid {}: {err}", path.display()))
+        }
+    }
+
+    /// Resolves the description with the chain specific collator `balance` and `stake` defaults.
+    pub fn resolve(&self, balance: Balance, stake: Balance) -> Result<LocalTestnetGenesis, String> {
+        if self.collators == 0 {
+            return Err("A local testnet needs at least one collator".into());
+        }
+        let collators = (0..self.collators)
+            .map(|index| {
+                let seed = COLLATOR_SEEDS
+                    .get(index)
+                    .map(|seed| seed.to_string())
+                    .unwrap_or_else(|| format!("Collator{index}"));
+                (unchecked_account_id::<sr25519::Public>(&seed), seed)
+            })
+            .collect::<Vec<_>>();
+        let collator_balance = self.collator_balance.map_or(balance, |amount| amount.0);
+        let collator_stake = self.collator_stake.map_or(stake, |amount| amount.0);
+        if collator_balance < collator_stake {
+            return Err("The collator balance does not cover the collator stake".into());
+        }
+
+        let mut balances = BTreeMap::<AccountId, Balance>::new();
+        for (account, _) in &collators {
+            *balances.entry(account.clone()).or_default() += collator_balance;
+        }
+        for endowment in &self.endowments {
+            *balances.entry(account_id(&endowment.account)?).or_default() += endowment.balance.0;
+        }
+
+        let mut locked = BTreeMap::<AccountId, Balance>::new();
+        let delegations = self
+            .delegations
+            .iter()
+            .map(|delegation| {
+                let delegator = account_id(&delegation.delegator)?;
+                let collator = account_id(&delegation.collator)?;
+                if !collators.iter().any(|(account, _)| *account == collator) {
+                    return Err(format!("{} is not a collator", delegation.collator));
+                }
+                *locked.entry(delegator.clone()).or_default() += delegation.amount.0;
+                Ok((delegator, collator, delegation.amount.0))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        let vesting = self
+            .vesting
+            .iter()
+            .map(|vesting| {
+                let account = account_id(&vesting.account)?;
+                *locked.entry(account.clone()).or_default() += vesting.amount.0;
+                Ok((account, vesting.amount.0))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+        for (account, amount) in &locked {
+            if balances.get(account).copied().unwrap_or_default() < *amount {
+                return Err(format!(
+                    "{} is not endowed with the {amount} it delegates or vests",
+                    account.to_ss58check()
+                ));
+            }
+        }
+
+        let assets = self
+            .assets
+            .iter()
+            .map(|asset| {
+                Ok((
+                    asset.location.to_asset_location()?,
+                    AssetRegistryMetadata {
+                        metadata: AssetStorageMetadata {
+                            name: asset.name.as_bytes().to_vec(),
+                            symbol: asset.symbol.as_bytes().to_vec(),
+                            decimals: asset.decimals,
+                            is_frozen: false,
+                        },
+                        min_balance: asset.min_balance.0,
+                        is_sufficient: asset.is_sufficient,
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let first_collator = collators[0].0.clone();
+        let members = |members: &Option<Vec<String>>| match members {
+            Some(members) => members.iter().map(|member| account_id(member)).collect(),
+            None => Ok(vec![first_collator.clone()]),
+        };
+        Ok(LocalTestnetGenesis {
+            council: members(&self.council)?,
+            technical_committee: members(&self.technical_committee)?,
+            sudo: match &self.sudo {
+                Some(sudo) => account_id(sudo)?,
+                None => first_collator.clone(),
+            },
+            collators,
+            collator_stake,
+            balances: balances.into_iter().collect(),
+            assets,
+            delegations,
+            vesting,
+            manta_pay: load_json(&self.manta_pay)?,
+            manta_sbt: load_json(&self.manta_sbt)?,
+        })
+    }
+}
+
+impl LocalTestnetGenesis {
+    /// Returns the collator accounts with the session keys built by `keys` from their seeds.
+    pub fn collator_keys<K>(&self, keys: impl Fn(&str) -> K) -> Vec<(AccountId, K)> {
+        self.collators
+            .iter()
+            .map(|(account, seed)| (account.clone(), keys(seed)))
+            .collect()
+    }
+}
+
+/// Loads a JSON genesis configuration, or returns the default configuration without a `path`.
+fn load_json<T>(path: &Option<PathBuf>) -> Result<T, String>
+where
+    T: Default + serde::de::DeserializeOwned,
+{
+    match path {
+        Some(path) => {
+            let content = fs::read(path)
+                .map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+            serde_json::from_slice(&content)
+                .map_err(|err| format!("Invalid {}: {err}", path.display()))
+        }
+        None => Ok(T::default()),
+    }
+}
+
+/// Parses an SS58 address or a development seed starting with `//`.
+fn account_id(account: &str) -> Result<AccountId, String> {
+    match account.strip_prefix("//") {
+        Some(seed) => Ok(unchecked_account_id::<sr25519::Public>(seed)),
+        None => AccountId::from_ss58check(account)
+            .map_err(|err| format!("Invalid account {account}: {err:?}")),
+    }
+}
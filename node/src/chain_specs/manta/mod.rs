@@ -152,3 +152,83 @@ fn manta_devnet_genesis(genesis_collators: Vec<Collator>) -> GenesisConfig {
         asset_manager: Default::default(),
     }
 }
+
+/// Returns the Manta chainspec of the local testnet described by `testnet`.
+pub fn manta_local_testnet_config(testnet: &LocalTestnet) -> Result<MantaChainSpec, String> {
+    if testnet.council.is_some() || testnet.technical_committee.is_some() {
+        return Err("Manta has no council or technical committee".into());
+    }
+    if !testnet.vesting.is_empty() {
+        return Err("Manta has no vesting".into());
+    }
+    if testnet.manta_pay.is_some() || testnet.manta_sbt.is_some() {
+        return Err("Manta has no MantaPay or MantaSBT".into());
+    }
+    let genesis = testnet.resolve(MANTA_ENDOWMENT, NORMAL_COLLATOR_MINIMUM_STAKE)?;
+    Ok(MantaChainSpec::from_genesis(
+        testnet.name.as_deref().unwrap_or("Manta Parachain Local"),
+        testnet.id.as_deref().unwrap_or("manta_local"),
+        ChainType::Local,
+        move || manta_local_testnet_genesis(genesis.clone()),
+        vec![],
+        None,
+        Some(MANTA_PROTOCOL_ID),
+        None,
+        Some(manta_properties()),
+        Extensions {
+            relay_chain: testnet
+                .relay_chain
+                .clone()
+                .unwrap_or_else(|| POLKADOT_RELAYCHAIN_LOCAL_NET.into()),
+            para_id: MANTA_PARACHAIN_ID,
+        },
+    ))
+}
+
+fn manta_local_testnet_genesis(genesis: LocalTestnetGenesis) -> GenesisConfig {
+    let collators = genesis.collator_keys(SessionKeys::from_seed_unchecked);
+    GenesisConfig {
+        system: manta_runtime::SystemConfig {
+            code: manta_runtime::WASM_BINARY
+                .expect("WASM binary was not build, please build it!")
+                .to_vec(),
+        },
+        balances: manta_runtime::BalancesConfig {
+            balances: genesis.balances,
+        },
+        aura: Default::default(),
+        sudo: manta_runtime::SudoConfig {
+            key: Some(genesis.sudo),
+        },
+        parachain_staking: ParachainStakingConfig {
+            candidates: collators
+                .iter()
+                .map(|(acc, _)| (acc.clone(), genesis.collator_stake))
+                .collect(),
+            delegations: genesis.delegations,
+            inflation_config: manta_runtime::staking::inflation_config::<manta_runtime::Runtime>(),
+        },
+        parachain_info: manta_runtime::ParachainInfoConfig {
+            parachain_id: MANTA_PARACHAIN_ID.into(),
+        },
+        collator_selection: manta_runtime::CollatorSelectionConfig {
+            invulnerables: vec![],
+            candidacy_bond: 0,
+            ..Default::default()
+        },
+        session: manta_runtime::SessionConfig {
+            keys: collators
+                .into_iter()
+                .map(|(acc, keys)| (acc.clone(), acc, keys))
+                .collect(),
+        },
+        parachain_system: Default::default(),
+        polkadot_xcm: PolkadotXcmConfig {
+            safe_xcm_version: Some(SAFE_XCM_VERSION),
+        },
+        asset_manager: manta_runtime::AssetManagerConfig {
+            assets: genesis.assets,
+            ..Default::default()
+        },
+    }
+}
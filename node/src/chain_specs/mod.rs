@@ -32,9 +32,15 @@ use sp_core::sr25519;
 
 pub mod calamari;
 pub mod dolphin;
+pub mod local_testnet;
 pub mod manta;
 
-pub use self::{calamari::*, dolphin::*, manta::*};
+pub use self::{
+    calamari::*,
+    dolphin::*,
+    local_testnet::{LocalTestnet, LocalTestnetGenesis},
+    manta::*,
+};
 pub use calamari_runtime::currency::KMA;
 pub use dolphin_runtime::currency::DOL;
 pub use manta_runtime::currency::MANTA;
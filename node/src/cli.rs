@@ -18,7 +18,7 @@
 
 use crate::chain_specs;
 use clap::Parser;
-use std::path::PathBuf;
+use std::{path::PathBuf, str::FromStr};
 
 /// Sub-commands supported by the collator.
 #[derive(Debug, clap::Subcommand)]
@@ -50,6 +50,18 @@ pub enum Subcommand {
     /// Export the genesis wasm of the parachain.
     ExportGenesisWasm(cumulus_client_cli::ExportGenesisWasmCommand),
 
+    /// Check the session keys in the local keystore against the on-chain registration.
+    CheckSessionKeys(crate::session_keys::CheckSessionKeysCmd),
+
+    /// Rewrite exported chain state into a development chain spec.
+    ForkState(crate::fork_state::ForkStateCmd),
+
+    /// Generate the chain spec of a local testnet from a description.
+    GenerateLocalSpec(crate::local_spec::GenerateLocalSpecCmd),
+
+    /// Export the shielded ledger of MantaPay or MantaSBT into a snapshot file.
+    ExportLedgerSnapshot(crate::ledger_snapshot::ExportLedgerSnapshotCmd),
+
     /// Sub-commands concerned with benchmarking.
     /// The pallet benchmarking moved to the `pallet` sub-command.
     #[command(subcommand)]
@@ -91,11 +103,64 @@ pub struct Cli {
     #[arg(long)]
     pub no_hardware_benchmarks: bool,
 
+    /// When blocks are sealed in the dev standalone mode.
+    ///
+    /// Options are `instant`, to seal a block as soon as a transaction enters the pool, `manual`,
+    /// to only seal blocks through the `engine_createBlock` RPC, or a number of milliseconds, to
+    /// seal a block at that interval. Blocks can be sealed with `engine_createBlock` in any mode.
+    #[arg(long, default_value = "instant")]
+    pub sealing: Sealing,
+
+    /// Serve a ledger snapshot file written by `export-ledger-snapshot` with the
+    /// `mantaPay_ledger_snapshot_info` and `mantaPay_ledger_snapshot_chunk` RPCs.
+    ///
+    /// Can be passed once for the MantaPay and once for the MantaSBT snapshot.
+    #[arg(long = "ledger-snapshot", value_name = "PATH")]
+    pub ledger_snapshots: Vec<PathBuf>,
+
+    /// Index the MantaPay ledger of the best chain in the node database, and serve
+    /// `mantaPay_pull_ledger_diff` and `mantaPay_dense_pull_ledger_diff` from it instead of runtime
+    /// API calls.
+    #[arg(long)]
+    pub manta_pay_index: bool,
+
     /// Relay chain arguments
     #[arg(raw = true)]
     pub relaychain_args: Vec<String>,
 }
 
+/// Block Sealing of the Dev Standalone Mode
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sealing {
+    /// Seal a finalized block when a transaction enters the pool
+    Instant,
+
+    /// Seal blocks through the `engine_createBlock` RPC only
+    Manual,
+
+    /// Seal a finalized block every given number of milliseconds, even if it is empty
+    Interval(u64),
+}
+
+impl FromStr for Sealing {
+    type Err = String;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "instant" => Ok(Self::Instant),
+            "manual" => Ok(Self::Manual),
+            interval => match interval.parse() {
+                Ok(0) => Err("sealing interval must be greater than zero".into()),
+                Ok(millis) => Ok(Self::Interval(millis)),
+                Err(_) => Err(format!(
+                    "invalid sealing `{interval}`: expected `instant`, `manual` or a number of milliseconds"
+                )),
+            },
+        }
+    }
+}
+
 /// Relay Chain CLI
 #[derive(Debug)]
 pub struct RelayChainCli {
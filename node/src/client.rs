@@ -31,6 +31,7 @@ pub trait RuntimeApiCommon:
     + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
     + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
     + frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+    + session_key_primitives::runtime::SessionKeysRegistryApi<Block, AccountId>
 where
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
@@ -51,7 +52,8 @@ where
         + sp_session::SessionKeys<Block>
         + sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block>
         + pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-        + frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+        + frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+        + session_key_primitives::runtime::SessionKeysRegistryApi<Block, AccountId>,
     <Self as sp_api::ApiExt<Block>>::StateBackend: sp_api::StateBackend<BlakeTwo256>,
 {
 }
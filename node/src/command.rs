@@ -299,6 +299,19 @@ pub fn run_with(cli: Cli) -> Result {
                 Ok(cmd.run(components.client, config.chain_spec))
             })
         }
+        Some(Subcommand::CheckSessionKeys(cmd)) => {
+            construct_async_run!(|components, cli, cmd, config| {
+                let keystore = components.keystore_container.sync_keystore();
+                Ok(async move { cmd.run(components.client, keystore) })
+            })
+        }
+        Some(Subcommand::ForkState(cmd)) => cmd.run(),
+        Some(Subcommand::GenerateLocalSpec(cmd)) => cmd.run(),
+        Some(Subcommand::ExportLedgerSnapshot(cmd)) => {
+            construct_async_run!(|components, cli, cmd, config| {
+                Ok(async move { cmd.run(components.client) })
+            })
+        }
         Some(Subcommand::ImportBlocks(cmd)) => {
             construct_async_run!(|components, cli, cmd, config| {
                 Ok(cmd.run(components.client, components.import_queue))
@@ -442,6 +455,17 @@ pub fn run_with(cli: Cli) -> Result {
             let is_dev = chain_spec.is_localdev();
             info!("id:{}", chain_spec.id());
             let collator_options = cli.run.collator_options();
+            let ledger_snapshots =
+                crate::ledger_snapshot::load_ledger_snapshots(&cli.ledger_snapshots)?;
+            if !ledger_snapshots.is_empty() && chain_spec.is_manta() {
+                return Err(
+                    "Manta has no MantaPay or MantaSBT ledger to serve snapshots of".into(),
+                );
+            }
+            let manta_pay_index = cli.manta_pay_index;
+            if manta_pay_index && chain_spec.is_manta() {
+                return Err("Manta has no MantaPay ledger to index".into());
+            }
 
             runner.run_node_until_exit(|config| async move {
                 if is_dev {
@@ -449,13 +473,17 @@ pub fn run_with(cli: Cli) -> Result {
                     if config.chain_spec.is_dolphin() {
                         return crate::service::start_dev_nimbus_node::<dolphin_runtime::RuntimeApi, _>(
                             config,
-                            rpc::create_dolphin_full,
+                            cli.sealing,
+                            manta_pay_index,
+                            rpc::with_ledger_snapshots(rpc::create_dolphin_full, ledger_snapshots),
                         ).await
                             .map_err(Into::into);
                     } else if config.chain_spec.is_calamari() {
                         return crate::service::start_dev_nimbus_node::<calamari_runtime::RuntimeApi, _>(
                             config,
-                            rpc::create_calamari_full,
+                            cli.sealing,
+                            manta_pay_index,
+                            rpc::with_ledger_snapshots(rpc::create_calamari_full, ledger_snapshots),
                         ).await
                             .map_err(Into::into);
                     } else {
@@ -520,6 +548,7 @@ pub fn run_with(cli: Cli) -> Result {
                         collator_options,
                         id,
                         hwbench,
+                        false,
                         rpc::create_common_full,
                     )
                     .await
@@ -532,7 +561,8 @@ pub fn run_with(cli: Cli) -> Result {
                         collator_options,
                         id,
                         hwbench,
-                        rpc::create_calamari_full,
+                        manta_pay_index,
+                        rpc::with_ledger_snapshots(rpc::create_calamari_full, ledger_snapshots),
                     )
                     .await
                     .map(|r| r.0)
@@ -544,7 +574,8 @@ pub fn run_with(cli: Cli) -> Result {
                         collator_options,
                         id,
                         hwbench,
-                        rpc::create_dolphin_full,
+                        manta_pay_index,
+                        rpc::with_ledger_snapshots(rpc::create_dolphin_full, ledger_snapshots),
                     )
                     .await
                     .map(|r| r.0)
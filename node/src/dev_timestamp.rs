@@ -0,0 +1,69 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dev Timestamp
+
+use manta_primitives::constants::time::SLOT_DURATION;
+use sp_timestamp::{InherentDataProvider, Timestamp};
+use std::sync::{Arc, Mutex};
+
+/// Dev Timestamp State
+#[derive(Debug, Default)]
+struct State {
+    /// Offset added to the system time, in milliseconds
+    offset: u64,
+
+    /// Timestamp of the last sealed block
+    last: u64,
+}
+
+/// Dev Timestamp
+///
+/// Timestamps the blocks sealed in the dev standalone mode with the system time shifted by an
+/// offset, which can be advanced at any time to travel forward in time. Timestamps always
+/// increase by at least one slot, so that blocks can be sealed back to back. The offset is not
+/// persisted, so it starts from zero whenever the node restarts.
+#[derive(Clone, Debug, Default)]
+pub struct DevTimestamp(Arc<Mutex<State>>);
+
+impl DevTimestamp {
+    /// Advances the timestamp of the next sealed blocks by `millis`, returning the total offset
+    /// from the system time.
+    #[inline]
+    pub fn advance(&self, millis: u64) -> u64 {
+        let mut state = self
+            .0
+            .lock()
+            .expect("Dev timestamp lock is never poisoned.");
+        state.offset = state.offset.saturating_add(millis);
+        state.offset
+    }
+
+    /// Returns the timestamp inherent data provider for the next sealed block.
+    #[inline]
+    pub fn next(&self) -> InherentDataProvider {
+        let mut state = self
+            .0
+            .lock()
+            .expect("Dev timestamp lock is never poisoned.");
+        let timestamp = Timestamp::current()
+            .as_millis()
+            .saturating_add(state.offset)
+            .max(state.last.saturating_add(SLOT_DURATION));
+        state.last = timestamp;
+        InherentDataProvider::new(timestamp.into())
+    }
+}
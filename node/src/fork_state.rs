@@ -0,0 +1,665 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Fork State Command
+//!
+//! Rewrites the state of a live chain into a chain spec which boots as a single-collator
+//! development chain, so that runtime upgrades and storage migrations can be rehearsed offline
+//! against real data:
+//!
+//! ```text
+//! manta export-state --chain calamari <block> > calamari-state.json
+//! manta fork-state --chain calamari --input calamari-state.json \
+//!     --runtime calamari_runtime.compact.compressed.wasm --output calamari-fork.json
+//! manta --chain calamari-fork.json --alice --tmp --sealing manual
+//! ```
+
+use crate::chain_specs;
+use codec::{Decode, Encode};
+use manta_primitives::types::{AccountId, Balance, BlockNumber, Index};
+use pallet_parachain_staking::{Delegator, COLLATOR_LOCK_ID};
+use sc_service::ChainSpec;
+use serde_json::Value;
+use session_key_primitives::{util::unchecked_account_id, AuraId};
+use sp_core::{
+    hashing::{blake2_128, twox_128, twox_64},
+    sr25519,
+    storage::Storage,
+};
+use sp_runtime::{traits::OpaqueKeys, BuildStorage};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Raw top-level storage of the forked chain.
+type TopStorage = BTreeMap<Vec<u8>, Vec<u8>>;
+
+/// Well known key of the runtime wasm blob.
+const CODE_KEY: &[u8] = b":code";
+
+/// Balance lock stored in `Balances::Locks`: lock identifier, amount and withdraw reasons.
+type BalanceLock = ([u8; 8], Balance, u8);
+
+/// Account stored in `System::Account`: nonce, consumers, providers, sufficients and the
+/// `free`, `reserved`, `misc_frozen` and `fee_frozen` balances.
+type AccountInfo = (Index, u32, u32, u32, Balance, Balance, Balance, Balance);
+
+/// Encoding of the `Fee` withdraw reasons of a [`BalanceLock`], the only ones which do not
+/// freeze the `misc_frozen` balance.
+const FEE_REASONS: u8 = 0;
+
+/// Encoding of the `Misc` withdraw reasons of a [`BalanceLock`], the only ones which do not
+/// freeze the `fee_frozen` balance.
+const MISC_REASONS: u8 = 1;
+
+/// `ParachainSystem` storage items derived from the relay chain of the live network. They are
+/// removed so the forked chain accepts the mocked validation data of the development node.
+const PARACHAIN_SYSTEM_RELAY_ITEMS: &[&str] = &[
+    "LastRelayChainBlockNumber",
+    "LastDmqMqcHead",
+    "LastHrmpMqcHeads",
+    "ValidationData",
+    "RelayStateProof",
+    "RelevantMessagingState",
+    "HostConfiguration",
+    "UpgradeRestrictionSignal",
+    "PendingValidationCode",
+    "DidSetValidationCode",
+    "ProcessedDownwardMessages",
+    "HrmpWatermark",
+];
+
+/// Chains whose state can be forked into a development chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ForkChain {
+    /// Calamari Network
+    Calamari,
+
+    /// Dolphin Testnet
+    Dolphin,
+}
+
+/// Rewrites exported chain state into a development chain spec collated by `//Alice`.
+///
+/// The input is either a chain spec with raw genesis storage, as written by `export-state`, or a
+/// raw storage dump given as a JSON object or array of hex encoded key-value pairs. The session
+/// keys and collator candidates of the live network are replaced by the development keys of
+/// `//Alice`, who takes over the bond and delegations of the top staking candidate, and the relay
+/// chain data of `ParachainSystem` is reset. The written chain spec has a `localdev` id, so
+/// passing it to `--chain` starts the instant, manual or interval sealing development node.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ForkStateCmd {
+    /// Chain whose state is forked.
+    #[arg(long, value_enum)]
+    pub chain: ForkChain,
+
+    /// Exported chain spec or raw storage dump of the live chain.
+    #[arg(long)]
+    pub input: PathBuf,
+
+    /// Runtime wasm replacing the `:code` of the live chain.
+    #[arg(long)]
+    pub runtime: Option<PathBuf>,
+
+    /// Path of the written development chain spec.
+    #[arg(long)]
+    pub output: PathBuf,
+}
+
+impl ForkStateCmd {
+    /// Runs the command, writing the raw development chain spec to the output path.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let mut top = read_storage(&self.input)?;
+        let alice = unchecked_account_id::<sr25519::Public>("Alice");
+        let mut spec: Box<dyn ChainSpec> = match self.chain {
+            ForkChain::Calamari => {
+                let keys = calamari_runtime::opaque::SessionKeys::from_seed_unchecked("Alice");
+                let aura = keys.aura.clone();
+                replace_collators(&mut top, &alice, &keys, &aura);
+                Box::new(chain_specs::calamari_local_config(true))
+            }
+            ForkChain::Dolphin => {
+                let keys = dolphin_runtime::opaque::SessionKeys::from_seed_unchecked("Alice");
+                let aura = keys.aura.clone();
+                replace_collators(&mut top, &alice, &keys, &aura);
+                Box::new(chain_specs::dolphin_local_config(true))
+            }
+        };
+        reset_relay_data(&mut top);
+        if let Some(runtime) = &self.runtime {
+            let code = fs::read(runtime)
+                .map_err(|err| format!("Unable to read {}: {err}", runtime.display()))?;
+            top.insert(CODE_KEY.to_vec(), code);
+        }
+        if !top.contains_key(CODE_KEY) {
+            return Err("The forked state has no runtime code, pass it with `--runtime`".into());
+        }
+
+        spec.set_storage(Storage {
+            top,
+            children_default: Default::default(),
+        });
+        let json = spec.as_json(true)?;
+        fs::write(&self.output, json)
+            .map_err(|err| format!("Unable to write {}: {err}", self.output.display()))?;
+        println!(
+            "Forked state written to {}, start it with `--chain {} --alice`",
+            self.output.display(),
+            self.output.display()
+        );
+        Ok(())
+    }
+}
+
+/// Reads the top-level storage from an exported chain spec or a raw storage dump.
+fn read_storage(path: &Path) -> Result<TopStorage, String> {
+    let content =
+        fs::read(path).map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+    let json: Value = serde_json::from_slice(&content)
+        .map_err(|err| format!("Invalid JSON in {}: {err}", path.display()))?;
+    if json.get("genesis").is_some() {
+        let spec = chain_specs::ChainSpec::from_json_bytes(content)?;
+        return Ok(spec.build_storage()?.top);
+    }
+    let pairs: Vec<(String, String)> = match json {
+        Value::Object(map) => map
+            .into_iter()
+            .map(|(key, value)| match value {
+                Value::String(value) => Ok((key, value)),
+                _ => Err(format!("Storage value of {key} is not a hex string")),
+            })
+            .collect::<Result<_, _>>()?,
+        array @ Value::Array(_) => serde_json::from_value(array)
+            .map_err(|err| format!("Invalid storage pairs in {}: {err}", path.display()))?,
+        _ => return Err(format!("Unsupported storage dump in {}", path.display())),
+    };
+    pairs
+        .into_iter()
+        .map(|(key, value)| Ok((from_hex(&key)?, from_hex(&value)?)))
+        .collect()
+}
+
+/// Decodes a `0x` prefixed hex string.
+fn from_hex(value: &str) -> Result<Vec<u8>, String> {
+    sp_core::bytes::from_hex(value).map_err(|err| format!("Invalid hex {value}: {err}"))
+}
+
+/// Returns the storage key prefix of `item` in `pallet`.
+pub(crate) fn storage_prefix(pallet: &str, item: &str) -> Vec<u8> {
+    [twox_128(pallet.as_bytes()), twox_128(item.as_bytes())].concat()
+}
+
+/// Returns the storage key of `key` in the `Twox64Concat` map `item` of `pallet`.
+fn map_key<K: Encode>(pallet: &str, item: &str, key: &K) -> Vec<u8> {
+    let mut storage_key = storage_prefix(pallet, item);
+    storage_key.extend(twox_64_concat(&key.encode()));
+    storage_key
+}
+
+/// Hashes `key` with the `Twox64Concat` hasher.
+pub(crate) fn twox_64_concat(key: &[u8]) -> Vec<u8> {
+    [&twox_64(key)[..], key].concat()
+}
+
+/// Hashes `key` with the `Blake2_128Concat` hasher.
+fn blake2_128_concat(key: &[u8]) -> Vec<u8> {
+    [&blake2_128(key)[..], key].concat()
+}
+
+/// Returns the storage key of `key` in the `Blake2_128Concat` map `item` of `pallet`.
+fn blake2_map_key<K: Encode>(pallet: &str, item: &str, key: &K) -> Vec<u8> {
+    let mut storage_key = storage_prefix(pallet, item);
+    storage_key.extend(blake2_128_concat(&key.encode()));
+    storage_key
+}
+
+/// Moves every key starting with `from` under the prefix `to`.
+fn move_prefix(top: &mut TopStorage, from: &[u8], to: &[u8]) {
+    let keys = top
+        .range(from.to_vec()..)
+        .map(|(key, _)| key)
+        .take_while(|key| key.starts_with(from))
+        .cloned()
+        .collect::<Vec<_>>();
+    for key in keys {
+        if let Some(value) = top.remove(&key) {
+            top.insert([to, &key[from.len()..]].concat(), value);
+        }
+    }
+}
+
+/// Removes every key starting with `prefix`.
+fn remove_prefix(top: &mut TopStorage, prefix: &[u8]) {
+    top.retain(|key, _| !key.starts_with(prefix));
+}
+
+/// Decodes the value of a storage item if it is present.
+fn get<T: Decode>(top: &TopStorage, key: &[u8]) -> Option<T> {
+    top.get(key)
+        .and_then(|value| T::decode(&mut &value[..]).ok())
+}
+
+/// Replaces the collators of the forked chain by `account` with the session `keys`.
+fn replace_collators<K>(top: &mut TopStorage, account: &AccountId, keys: &K, aura: &AuraId)
+where
+    K: OpaqueKeys + Encode,
+{
+    remove_prefix(top, &storage_prefix("Session", "NextKeys"));
+    remove_prefix(top, &storage_prefix("Session", "KeyOwner"));
+    top.remove(&storage_prefix("Session", "DisabledValidators"));
+    top.insert(
+        storage_prefix("Session", "Validators"),
+        vec![account.clone()].encode(),
+    );
+    top.insert(
+        storage_prefix("Session", "QueuedKeys"),
+        vec![(account.clone(), keys)].encode(),
+    );
+    top.insert(storage_prefix("Session", "QueuedChanged"), false.encode());
+    top.insert(map_key("Session", "NextKeys", account), keys.encode());
+    for id in K::key_ids() {
+        top.insert(
+            map_key("Session", "KeyOwner", &(*id, keys.get_raw(*id))),
+            account.encode(),
+        );
+    }
+
+    top.insert(
+        storage_prefix("Aura", "Authorities"),
+        vec![aura.clone()].encode(),
+    );
+
+    top.insert(
+        storage_prefix("CollatorSelection", "Invulnerables"),
+        vec![account.clone()].encode(),
+    );
+    top.remove(&storage_prefix("CollatorSelection", "Candidates"));
+    top.insert(
+        storage_prefix("CollatorSelection", "SelectedCandidates"),
+        vec![account.clone()].encode(),
+    );
+
+    replace_staking_candidates(top, account);
+}
+
+/// Moves the bond and delegations of the top `ParachainStaking` candidate to `account` and makes
+/// it the only selected candidate. Chains without `ParachainStaking` are left untouched.
+fn replace_staking_candidates(top: &mut TopStorage, account: &AccountId) {
+    const PALLET: &str = "ParachainStaking";
+    let pool = get::<Vec<(AccountId, Balance)>>(top, &storage_prefix(PALLET, "CandidatePool"));
+    let (candidate, amount) =
+        match pool.and_then(|pool| pool.into_iter().max_by_key(|(_, amount)| *amount)) {
+            Some(top_candidate) => top_candidate,
+            None => return,
+        };
+
+    for item in [
+        "CandidateInfo",
+        "TopDelegations",
+        "BottomDelegations",
+        "CandidateCommission",
+        "CandidateCommissionRequests",
+    ] {
+        if let Some(value) = top.remove(&map_key(PALLET, item, &candidate)) {
+            top.insert(map_key(PALLET, item, account), value);
+        }
+    }
+    if let Some(value) = top.remove(&blake2_map_key(
+        PALLET,
+        "DelegationScheduledRequests",
+        &candidate,
+    )) {
+        top.insert(
+            blake2_map_key(PALLET, "DelegationScheduledRequests", account),
+            value,
+        );
+    }
+    move_prefix(
+        top,
+        &blake2_map_key(PALLET, "RedelegationTargets", &candidate),
+        &blake2_map_key(PALLET, "RedelegationTargets", account),
+    );
+    replace_delegation_owners(top, &candidate, account);
+    move_lock(top, COLLATOR_LOCK_ID, &candidate, account);
+
+    let round_key = storage_prefix(PALLET, "Round");
+    if let Some((current, _, length)) = get::<(u32, BlockNumber, u32)>(top, &round_key) {
+        for item in ["AtStake", "AtStakeCommission"] {
+            let round = map_key(PALLET, item, &current);
+            let old = [&round[..], &twox_64_concat(&candidate.encode())].concat();
+            if let Some(value) = top.remove(&old) {
+                top.insert(
+                    [&round[..], &twox_64_concat(&account.encode())].concat(),
+                    value,
+                );
+            }
+        }
+        // NOTE: The forked chain restarts at block one, so the round has to start at genesis.
+        top.insert(
+            round_key,
+            (current, BlockNumber::default(), length).encode(),
+        );
+    }
+
+    top.insert(
+        storage_prefix(PALLET, "CandidatePool"),
+        vec![(account.clone(), amount)].encode(),
+    );
+    top.insert(
+        storage_prefix(PALLET, "SelectedCandidates"),
+        vec![account.clone()].encode(),
+    );
+}
+
+/// Replaces `candidate` by `account` in the delegations of every `DelegatorState` and in the
+/// targets of the pending redelegations.
+fn replace_delegation_owners(top: &mut TopStorage, candidate: &AccountId, account: &AccountId) {
+    const PALLET: &str = "ParachainStaking";
+    let delegators = storage_prefix(PALLET, "DelegatorState");
+    let redelegations = storage_prefix(PALLET, "RedelegationTargets");
+    for (key, value) in top.iter_mut() {
+        if key.starts_with(&delegators) {
+            let mut delegator = match Delegator::<AccountId, Balance>::decode(&mut &value[..]) {
+                Ok(delegator) => delegator,
+                _ => continue,
+            };
+            let mut delegations = std::mem::take(&mut delegator.delegations.0);
+            let mut is_delegating = false;
+            for bond in delegations
+                .iter_mut()
+                .filter(|bond| &bond.owner == candidate)
+            {
+                bond.owner = account.clone();
+                is_delegating = true;
+            }
+            if !is_delegating {
+                continue;
+            }
+            delegator.delegations = delegations.into();
+            *value = delegator.encode();
+        } else if key.starts_with(&redelegations) && value[..] == candidate.encode()[..] {
+            *value = account.encode();
+        }
+    }
+}
+
+/// Moves the balance lock `id` of `from` to `to`, together with the free balance it locks.
+fn move_lock(top: &mut TopStorage, id: [u8; 8], from: &AccountId, to: &AccountId) {
+    let from_locks_key = blake2_map_key("Balances", "Locks", from);
+    let mut from_locks = get::<Vec<BalanceLock>>(top, &from_locks_key).unwrap_or_default();
+    let lock = match from_locks.iter().position(|(lock_id, _, _)| *lock_id == id) {
+        Some(index) => from_locks.remove(index),
+        None => return,
+    };
+    let to_locks_key = blake2_map_key("Balances", "Locks", to);
+    let mut to_locks = get::<Vec<BalanceLock>>(top, &to_locks_key).unwrap_or_default();
+    let to_had_locks = !to_locks.is_empty();
+    to_locks.retain(|(lock_id, _, _)| *lock_id != id);
+    to_locks.push(lock);
+
+    let from_account_key = blake2_map_key("System", "Account", from);
+    let to_account_key = blake2_map_key("System", "Account", to);
+    let mut from_account = get::<AccountInfo>(top, &from_account_key).unwrap_or_default();
+    let mut to_account =
+        get::<AccountInfo>(top, &to_account_key).unwrap_or((0, 0, 1, 0, 0, 0, 0, 0));
+    let amount = lock.1.min(from_account.4);
+    from_account.4 -= amount;
+    to_account.4 = to_account.4.saturating_add(amount);
+    if from_locks.is_empty() {
+        from_account.1 = from_account.1.saturating_sub(1);
+    }
+    if !to_had_locks {
+        to_account.1 = to_account.1.saturating_add(1);
+    }
+    (from_account.6, from_account.7) = frozen_balances(&from_locks);
+    (to_account.6, to_account.7) = frozen_balances(&to_locks);
+
+    if from_locks.is_empty() {
+        top.remove(&from_locks_key);
+    } else {
+        top.insert(from_locks_key, from_locks.encode());
+    }
+    top.insert(to_locks_key, to_locks.encode());
+    top.insert(from_account_key, from_account.encode());
+    top.insert(to_account_key, to_account.encode());
+}
+
+/// Returns the `misc_frozen` and `fee_frozen` balances of an account with `locks`.
+fn frozen_balances(locks: &[BalanceLock]) -> (Balance, Balance) {
+    let frozen = |unfrozen_reasons| {
+        locks
+            .iter()
+            .filter(|(_, _, reasons)| *reasons != unfrozen_reasons)
+            .map(|(_, amount, _)| *amount)
+            .max()
+            .unwrap_or_default()
+    };
+    (frozen(FEE_REASONS), frozen(MISC_REASONS))
+}
+
+/// Removes the relay chain data of the live network and the last slot seen by its collators.
+fn reset_relay_data(top: &mut TopStorage) {
+    for item in PARACHAIN_SYSTEM_RELAY_ITEMS {
+        top.remove(&storage_prefix("ParachainSystem", item));
+    }
+    top.remove(&storage_prefix("AuthorInherent", "HighestSlotSeen"));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pallet_parachain_staking::{Bond, DelegatorStatus};
+
+    const PALLET: &str = "ParachainStaking";
+
+    /// Returns the test account filled with `byte`.
+    fn account(byte: u8) -> AccountId {
+        AccountId::new([byte; 32])
+    }
+
+    /// Returns the encoded `DelegatorState` of `delegator` delegating `amount` to each collator.
+    fn delegator_state(delegator: &AccountId, collators: &[AccountId], amount: Balance) -> Vec<u8> {
+        Delegator {
+            id: delegator.clone(),
+            delegations: collators
+                .iter()
+                .map(|collator| Bond {
+                    owner: collator.clone(),
+                    amount,
+                })
+                .collect::<Vec<_>>()
+                .into(),
+            total: amount * collators.len() as Balance,
+            less_total: 0,
+            status: DelegatorStatus::Active,
+        }
+        .encode()
+    }
+
+    #[test]
+    fn storage_keys_should_match_frame_hashers() {
+        assert_eq!(
+            storage_prefix("System", "Account"),
+            from_hex("0x26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da9").unwrap()
+        );
+        let key = account(1).encode();
+        assert_eq!(twox_64_concat(&key)[..8], twox_64(&key));
+        assert_eq!(twox_64_concat(&key)[8..], key[..]);
+        assert_eq!(blake2_128_concat(&key)[..16], blake2_128(&key));
+        assert_eq!(blake2_128_concat(&key)[16..], key[..]);
+        assert_eq!(
+            map_key(PALLET, "CandidateInfo", &account(1)),
+            [
+                storage_prefix(PALLET, "CandidateInfo"),
+                twox_64_concat(&key)
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn move_prefix_should_only_move_matching_keys() {
+        let mut top = TopStorage::from([
+            (vec![1, 2, 3], vec![0]),
+            (vec![1, 2, 4, 5], vec![1]),
+            (vec![1, 3], vec![2]),
+        ]);
+        move_prefix(&mut top, &[1, 2], &[9]);
+        assert_eq!(
+            top,
+            TopStorage::from([
+                (vec![1, 3], vec![2]),
+                (vec![9, 3], vec![0]),
+                (vec![9, 4, 5], vec![1]),
+            ])
+        );
+    }
+
+    #[test]
+    fn frozen_balances_should_follow_lock_reasons() {
+        assert_eq!(frozen_balances(&[]), (0, 0));
+        assert_eq!(
+            frozen_balances(&[
+                (*b"feesonly", 30, 0),
+                (*b"misconly", 20, 1),
+                (*b"all_lock", 10, 2)
+            ]),
+            (20, 30)
+        );
+    }
+
+    #[test]
+    fn reset_relay_data_should_remove_relay_items() {
+        let other = storage_prefix("ParachainSystem", "LastUpgrade");
+        let mut top = PARACHAIN_SYSTEM_RELAY_ITEMS
+            .iter()
+            .map(|item| (storage_prefix("ParachainSystem", item), vec![1]))
+            .chain([
+                (storage_prefix("AuthorInherent", "HighestSlotSeen"), vec![1]),
+                (other.clone(), vec![1]),
+            ])
+            .collect::<TopStorage>();
+        reset_relay_data(&mut top);
+        assert_eq!(top, TopStorage::from([(other, vec![1])]));
+    }
+
+    #[test]
+    fn replace_staking_candidates_should_skip_chains_without_staking() {
+        let mut top = TopStorage::from([(storage_prefix("System", "Number"), 7u32.encode())]);
+        let expected = top.clone();
+        replace_staking_candidates(&mut top, &account(0));
+        assert_eq!(top, expected);
+    }
+
+    #[test]
+    fn replace_staking_candidates_should_move_top_candidate() {
+        let (alice, candidate, other, delegator) = (account(0), account(1), account(2), account(3));
+        let (round, stake, free): (u32, Balance, Balance) = (5, 1_000, 1_500);
+        let candidate_account: AccountInfo = (0, 1, 1, 0, free, 0, stake, stake);
+        let at_stake = |item, collator: &AccountId| {
+            [
+                map_key(PALLET, item, &round),
+                twox_64_concat(&collator.encode()),
+            ]
+            .concat()
+        };
+        let mut top = TopStorage::from([
+            (
+                storage_prefix(PALLET, "CandidatePool"),
+                vec![(other.clone(), 10), (candidate.clone(), stake)].encode(),
+            ),
+            (
+                storage_prefix(PALLET, "Round"),
+                (round, 3_000u32, 600u32).encode(),
+            ),
+            (map_key(PALLET, "CandidateInfo", &candidate), vec![1]),
+            (map_key(PALLET, "TopDelegations", &candidate), vec![2]),
+            (map_key(PALLET, "CandidateCommission", &candidate), vec![3]),
+            (
+                blake2_map_key(PALLET, "DelegationScheduledRequests", &candidate),
+                vec![4],
+            ),
+            (at_stake("AtStake", &candidate), vec![5]),
+            (at_stake("AtStakeCommission", &candidate), vec![6]),
+            (
+                map_key(PALLET, "DelegatorState", &delegator),
+                delegator_state(&delegator, &[candidate.clone(), other.clone()], 100),
+            ),
+            (
+                blake2_map_key("Balances", "Locks", &candidate),
+                vec![(COLLATOR_LOCK_ID, stake, 2u8)].encode(),
+            ),
+            (
+                blake2_map_key("System", "Account", &candidate),
+                candidate_account.encode(),
+            ),
+        ]);
+
+        replace_staking_candidates(&mut top, &alice);
+
+        assert_eq!(
+            get::<Vec<(AccountId, Balance)>>(&top, &storage_prefix(PALLET, "CandidatePool")),
+            Some(vec![(alice.clone(), stake)])
+        );
+        assert_eq!(
+            get::<Vec<AccountId>>(&top, &storage_prefix(PALLET, "SelectedCandidates")),
+            Some(vec![alice.clone()])
+        );
+        assert_eq!(
+            get::<(u32, BlockNumber, u32)>(&top, &storage_prefix(PALLET, "Round")),
+            Some((round, 0, 600))
+        );
+        for (key, value) in [
+            (map_key(PALLET, "CandidateInfo", &alice), vec![1]),
+            (map_key(PALLET, "TopDelegations", &alice), vec![2]),
+            (map_key(PALLET, "CandidateCommission", &alice), vec![3]),
+            (
+                blake2_map_key(PALLET, "DelegationScheduledRequests", &alice),
+                vec![4],
+            ),
+            (at_stake("AtStake", &alice), vec![5]),
+            (at_stake("AtStakeCommission", &alice), vec![6]),
+        ] {
+            assert_eq!(top.get(&key), Some(&value));
+        }
+        assert!(!top.keys().any(|key| key.ends_with(&candidate.encode())
+            && !key.starts_with(&storage_prefix("System", "Account"))));
+
+        assert_eq!(
+            top.get(&map_key(PALLET, "DelegatorState", &delegator)),
+            Some(&delegator_state(
+                &delegator,
+                &[alice.clone(), other.clone()],
+                100
+            ))
+        );
+
+        assert_eq!(
+            get::<Vec<BalanceLock>>(&top, &blake2_map_key("Balances", "Locks", &alice)),
+            Some(vec![(COLLATOR_LOCK_ID, stake, 2)])
+        );
+        assert_eq!(
+            get::<AccountInfo>(&top, &blake2_map_key("System", "Account", &candidate)),
+            Some((0, 0, 1, 0, free - stake, 0, 0, 0))
+        );
+        assert_eq!(
+            get::<AccountInfo>(&top, &blake2_map_key("System", "Account", &alice)),
+            Some((0, 1, 1, 0, stake, 0, stake, stake))
+        );
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ledger Snapshot Command
+//!
+//! Exports the shielded ledger of MantaPay or MantaSBT at a block into a snapshot file. Wallets
+//! download the file from a CDN, or in chunks from the `mantaPay_ledger_snapshot_*` RPCs of a node
+//! started with `--ledger-snapshot`, and continue from its checkpoint with `pull_ledger_diff`:
+//!
+//! ```text
+//! manta export-ledger-snapshot --chain calamari --ledger manta-pay --output manta-pay.snapshot
+//! manta --chain calamari --ledger-snapshot manta-pay.snapshot
+//! ```
+
+use crate::manta_pay_index::scan_ledger;
+use codec::Decode;
+use log::info;
+use manta_support::{
+    manta_pay::RawCheckpoint,
+    snapshot::{Ledger, LedgerSnapshot},
+};
+use pallet_manta_pay::rpc::LedgerSnapshots;
+use sc_cli::{BlockNumberOrHash, CliConfiguration, ImportParams, SharedParams};
+use sc_client_api::{Backend, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::StorageKey, H256};
+use sp_runtime::traits::{Block as BlockT, UniqueSaturatedInto};
+use std::{fs, path::PathBuf, sync::Arc};
+
+/// Shielded Ledgers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SnapshotLedger {
+    /// MantaPay
+    MantaPay,
+
+    /// MantaSBT
+    MantaSbt,
+}
+
+impl From<SnapshotLedger> for Ledger {
+    fn from(ledger: SnapshotLedger) -> Self {
+        match ledger {
+            SnapshotLedger::MantaPay => Self::MantaPay,
+            SnapshotLedger::MantaSbt => Self::MantaSbt,
+        }
+    }
+}
+
+/// Exports the receivers and senders of a shielded ledger at a block into a versioned and
+/// checksummed snapshot file, together with the checkpoint after pulling all of them.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct ExportLedgerSnapshotCmd {
+    /// Ledger to export.
+    #[arg(long, value_enum)]
+    pub ledger: SnapshotLedger,
+
+    /// Block hash or number of the snapshot, defaults to the last finalized block.
+    #[arg(long, value_name = "HASH or NUMBER")]
+    pub at: Option<BlockNumberOrHash>,
+
+    /// Path of the written snapshot file.
+    #[arg(long)]
+    pub output: PathBuf,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub import_params: ImportParams,
+}
+
+impl ExportLedgerSnapshotCmd {
+    /// Runs the command, writing the snapshot file to the output path.
+    pub fn run<B, BA, C>(&self, client: Arc<C>) -> sc_cli::Result<()>
+    where
+        B: BlockT<Hash = H256>,
+        BA: Backend<B>,
+        C: StorageProvider<B, BA> + HeaderBackend<B>,
+    {
+        let hash = match &self.at {
+            Some(at) => client
+                .block_hash_from_id(&at.parse::<B>()?)?
+                .ok_or_else(|| format!("Unknown block {at:?}"))?,
+            None => client.info().finalized_hash,
+        };
+        let block_number = client
+            .number(hash)?
+            .ok_or_else(|| format!("Unknown block {hash:?}"))?;
+
+        let ledger = Ledger::from(self.ledger);
+        let pallet = ledger.pallet_name();
+        // NOTE: MantaSBT has no nullifiers, so its snapshots have no senders.
+        let mut checkpoint = RawCheckpoint::default();
+        let mut receivers = Vec::new();
+        let mut senders = Vec::new();
+        scan_ledger(
+            |key| Ok(client.storage(hash, &StorageKey(key))?.map(|data| data.0)),
+            pallet,
+            &mut checkpoint,
+            u64::MAX,
+            |_, _, value| receivers.push(value),
+            |_, value| senders.push(value),
+        )?;
+        let receivers = receivers
+            .iter()
+            .map(|value| decode(pallet, value))
+            .collect::<Result<_, _>>()?;
+        let senders = senders
+            .iter()
+            .map(|value| decode(pallet, value))
+            .collect::<Result<_, _>>()?;
+
+        let snapshot = LedgerSnapshot {
+            ledger,
+            block_number: block_number.unique_saturated_into(),
+            block_hash: hash.0,
+            checkpoint,
+            receivers,
+            senders,
+        };
+        fs::write(&self.output, snapshot.to_bytes())
+            .map_err(|err| format!("Unable to write {}: {err}", self.output.display()))?;
+        println!(
+            "{pallet} snapshot of block #{block_number} ({hash:?}) with {} receivers and {} senders written to {}",
+            snapshot.receivers.len(),
+            snapshot.senders.len(),
+            self.output.display(),
+        );
+        Ok(())
+    }
+}
+
+/// Decodes a storage value of `pallet`.
+fn decode<T: Decode>(pallet: &str, value: &[u8]) -> Result<T, String> {
+    T::decode(&mut &value[..]).map_err(|err| format!("Invalid {pallet} storage value: {err}"))
+}
+
+impl CliConfiguration for ExportLedgerSnapshotCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn import_params(&self) -> Option<&ImportParams> {
+        Some(&self.import_params)
+    }
+}
+
+/// Loads the snapshot files served by the `mantaPay_ledger_snapshot_*` RPCs.
+pub fn load_ledger_snapshots(paths: &[PathBuf]) -> sc_cli::Result<LedgerSnapshots> {
+    let mut snapshots = LedgerSnapshots::default();
+    for path in paths {
+        let bytes =
+            fs::read(path).map_err(|err| format!("Unable to read {}: {err}", path.display()))?;
+        let ledger = snapshots
+            .insert(bytes)
+            .map_err(|err| format!("Invalid ledger snapshot {}: {err:?}", path.display()))?;
+        info!("Serving the {ledger:?} snapshot {}", path.display());
+    }
+    Ok(snapshots)
+}
@@ -26,6 +26,12 @@ pub mod chain_specs;
 pub mod cli;
 pub mod client;
 pub mod command;
+pub mod dev_timestamp;
+pub mod fork_state;
 mod instant_finalize;
+pub mod ledger_snapshot;
+pub mod local_spec;
+pub mod manta_pay_index;
 pub mod rpc;
 pub mod service;
+pub mod session_keys;
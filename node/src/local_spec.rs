@@ -0,0 +1,78 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Generate Local Spec Command
+
+use crate::chain_specs::{self, LocalTestnet};
+use sc_service::ChainSpec;
+use std::{fs, path::PathBuf};
+
+/// Chains with local testnets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum LocalChain {
+    /// Manta Network
+    Manta,
+
+    /// Calamari Network
+    Calamari,
+
+    /// Dolphin Testnet
+    Dolphin,
+}
+
+/// Generates the chain spec of a local testnet from a TOML or JSON description of its collators,
+/// endowments, assets, delegations, vesting accounts and council members.
+///
+/// See [`LocalTestnet`] for the format of the description.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct GenerateLocalSpecCmd {
+    /// Runtime of the local testnet.
+    #[arg(long, value_enum)]
+    pub chain: LocalChain,
+
+    /// TOML or JSON description of the local testnet.
+    #[arg(long)]
+    pub config: PathBuf,
+
+    /// Write the chain spec with raw genesis storage.
+    #[arg(long)]
+    pub raw: bool,
+
+    /// Path of the written chain spec, printed to stdout when omitted.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+impl GenerateLocalSpecCmd {
+    /// Runs the command, writing the chain spec to the output path or to stdout.
+    pub fn run(&self) -> sc_cli::Result<()> {
+        let testnet = LocalTestnet::load(&self.config)?;
+        let spec: Box<dyn ChainSpec> = match self.chain {
+            LocalChain::Manta => Box::new(chain_specs::manta_local_testnet_config(&testnet)?),
+            LocalChain::Calamari => {
+                Box::new(chain_specs::calamari_local_testnet_config(&testnet)?)
+            }
+            LocalChain::Dolphin => Box::new(chain_specs::dolphin_local_testnet_config(&testnet)?),
+        };
+        let json = spec.as_json(self.raw)?;
+        match &self.output {
+            Some(output) => fs::write(output, json)
+                .map_err(|err| format!("Unable to write {}: {err}", output.display()))?,
+            None => println!("{json}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,698 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! MantaPay Ledger Index
+//!
+//! Every `mantaPay_pull_ledger_diff` request executes a runtime API call which iterates the
+//! `Shards` and `NullifierSetInsertionOrder` storage of the last finalized block. With
+//! `--manta-pay-index`, the node follows its best chain instead, copies the receivers and senders
+//! added by every new best block into its auxiliary database, and records the checkpoint of the
+//! ledger at that block. Diffs at blocks with a recorded checkpoint are then served from the
+//! index, and diffs at other blocks, e.g. during the initial sync, still by the runtime.
+//!
+//! The ledger only grows, so a reorg is handled by dropping the checkpoints of the retracted blocks
+//! and rescanning the new best block from the checkpoint of the last indexed block which is still
+//! canonical, overwriting the entries of the retracted blocks. Checkpoints below the last finalized
+//! block are pruned.
+//!
+//! The new entries of a block are committed in batches, together with the progress of the scan,
+//! so that the first scan of the whole ledger is neither buffered in memory nor restarted from
+//! scratch when the node stops in the middle of it.
+
+use crate::fork_state::{storage_prefix, twox_64_concat};
+use codec::{Decode, Encode};
+use futures::StreamExt;
+use log::{debug, warn};
+use manta_primitives::types::{Block, BlockNumber, Hash};
+use manta_support::manta_pay::{asset_value_encode, PullResponse, RawCheckpoint};
+use pallet_manta_pay::{
+    rpc::LedgerIndex, PULL_MAX_RECEIVER_UPDATE_SIZE, PULL_MAX_SENDER_UPDATE_SIZE,
+};
+use sc_client_api::{AuxStore, Backend, BlockchainEvents, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::storage::StorageKey;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Name of the Indexed Pallet
+const PALLET: &str = "MantaPay";
+
+/// Prefix of the Auxiliary Database Keys of the Index
+const PREFIX: &[u8] = b"manta_pay_index";
+
+/// Maximum Number of Entries Scanned and Committed at Once
+const BATCH_SIZE: u64 = 4096;
+
+/// Returns the auxiliary database key of `item` in the index.
+fn aux_key<K: Encode>(item: &[u8], key: K) -> Vec<u8> {
+    (PREFIX, item, key).encode()
+}
+
+/// Removes the checkpoints of the indexed blocks which are not canonical anymore according to
+/// `canonical_hash`, and returns the checkpoint of the last remaining one to rescan from.
+///
+/// The indexed blocks are ancestors of the last indexed block, so the blocks which are still
+/// canonical are a prefix of `checkpoints`. The entries after the first retracted block may have
+/// been overwritten by the retracted chain, so every checkpoint after it is removed as well.
+fn retract_checkpoints(
+    checkpoints: &mut Vec<(BlockNumber, Hash, RawCheckpoint)>,
+    mut canonical_hash: impl FnMut(BlockNumber) -> sp_blockchain::Result<Option<Hash>>,
+) -> sp_blockchain::Result<RawCheckpoint> {
+    let mut canonical = 0;
+    for (number, hash, _) in checkpoints.iter() {
+        if canonical_hash(*number)? != Some(*hash) {
+            break;
+        }
+        canonical += 1;
+    }
+    checkpoints.truncate(canonical);
+    Ok(checkpoints
+        .last()
+        .map(|(_, _, checkpoint)| *checkpoint)
+        .unwrap_or_default())
+}
+
+/// Reads at most `limit` receivers and senders added to the ledger of `pallet` since
+/// `checkpoint`, passing their raw storage values to `receiver` with their shard and index, and
+/// to `sender` with their index, and advances `checkpoint` past them. The storage is read with
+/// `read`, and `true` is returned if the whole ledger was read.
+pub(crate) fn scan_ledger(
+    mut read: impl FnMut(Vec<u8>) -> sp_blockchain::Result<Option<Vec<u8>>>,
+    pallet: &str,
+    checkpoint: &mut RawCheckpoint,
+    limit: u64,
+    mut receiver: impl FnMut(u8, u64, Vec<u8>),
+    mut sender: impl FnMut(u64, Vec<u8>),
+) -> sp_blockchain::Result<bool> {
+    let mut remaining = limit;
+    let shards = storage_prefix(pallet, "Shards");
+    for (shard, index) in checkpoint.receiver_index.iter_mut().enumerate() {
+        let shard = shard as u8;
+        let shard_key = [&shards[..], &twox_64_concat(&shard.encode())].concat();
+        while remaining > 0 {
+            match read([&shard_key[..], &twox_64_concat(&index.encode())].concat())? {
+                Some(value) => receiver(shard, *index, value),
+                None => break,
+            }
+            *index += 1;
+            remaining -= 1;
+        }
+    }
+    let insertion_order = storage_prefix(pallet, "NullifierSetInsertionOrder");
+    while remaining > 0 {
+        match read(
+            [
+                &insertion_order[..],
+                &twox_64_concat(&checkpoint.sender_index.encode()),
+            ]
+            .concat(),
+        )? {
+            Some(value) => sender(checkpoint.sender_index, value),
+            None => return Ok(true),
+        }
+        checkpoint.sender_index += 1;
+        remaining -= 1;
+    }
+    Ok(false)
+}
+
+/// Chain Followed by the Index
+///
+/// Implemented by every client whose backend is `BA`.
+pub trait Chain<BA> {
+    /// Returns the number and hash of the best block, and the number of the last finalized block.
+    fn head(&self) -> (BlockNumber, Hash, BlockNumber);
+
+    /// Returns the hash of the canonical block at `number`.
+    fn canonical_hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>>;
+
+    /// Reads the value at `key` from the storage of block `at`.
+    fn read_storage(&self, at: Hash, key: Vec<u8>) -> sp_blockchain::Result<Option<Vec<u8>>>;
+
+    /// Reads the value at `key` from the auxiliary database.
+    fn read_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>>;
+
+    /// Atomically writes `insert` to and removes `delete` from the auxiliary database.
+    fn write_aux(
+        &self,
+        insert: &[(Vec<u8>, Vec<u8>)],
+        delete: &[Vec<u8>],
+    ) -> sp_blockchain::Result<()>;
+}
+
+impl<BA, C> Chain<BA> for C
+where
+    BA: Backend<Block>,
+    C: HeaderBackend<Block> + StorageProvider<Block, BA> + AuxStore,
+{
+    #[inline]
+    fn head(&self) -> (BlockNumber, Hash, BlockNumber) {
+        let info = self.info();
+        (info.best_number, info.best_hash, info.finalized_number)
+    }
+
+    #[inline]
+    fn canonical_hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+        self.hash(number)
+    }
+
+    #[inline]
+    fn read_storage(&self, at: Hash, key: Vec<u8>) -> sp_blockchain::Result<Option<Vec<u8>>> {
+        Ok(self.storage(at, &StorageKey(key))?.map(|data| data.0))
+    }
+
+    #[inline]
+    fn read_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+        self.get_aux(key)
+    }
+
+    #[inline]
+    fn write_aux(
+        &self,
+        insert: &[(Vec<u8>, Vec<u8>)],
+        delete: &[Vec<u8>],
+    ) -> sp_blockchain::Result<()> {
+        self.insert_aux(
+            insert
+                .iter()
+                .map(|(key, value)| (&key[..], &value[..]))
+                .collect::<Vec<_>>()
+                .iter(),
+            delete.iter().map(|key| &key[..]).collect::<Vec<_>>().iter(),
+        )
+    }
+}
+
+/// MantaPay Ledger Index
+pub struct MantaPayIndex<BA, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Maximum Number of Entries Scanned and Committed at Once
+    batch_size: u64,
+
+    /// Type Parameter Marker
+    __: PhantomData<BA>,
+}
+
+impl<BA, C> MantaPayIndex<BA, C>
+where
+    C: Chain<BA>,
+{
+    /// Builds a new [`MantaPayIndex`] stored in the auxiliary database of `client`.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            batch_size: BATCH_SIZE,
+            __: PhantomData,
+        }
+    }
+
+    /// Reads the value at `key` from the auxiliary database.
+    #[inline]
+    fn get<T: Decode>(&self, key: &[u8]) -> sp_blockchain::Result<Option<T>> {
+        match self.client.read_aux(key)? {
+            Some(value) => T::decode(&mut &value[..]).map(Some).map_err(|err| {
+                sp_blockchain::Error::Backend(format!("Invalid index entry: {err}"))
+            }),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the indexed blocks which are not pruned yet, with their checkpoints.
+    #[inline]
+    fn checkpoints(&self) -> sp_blockchain::Result<Vec<(BlockNumber, Hash, RawCheckpoint)>> {
+        Ok(self.get(&aux_key(b"checkpoints", ()))?.unwrap_or_default())
+    }
+
+    /// Indexes the ledger at the best block of the client.
+    pub fn index_best_block(&self) -> sp_blockchain::Result<()> {
+        let (best_number, best_hash, finalized_number) = self.client.head();
+        let mut checkpoints = self.checkpoints()?;
+        if checkpoints.iter().any(|(_, hash, _)| *hash == best_hash) {
+            return Ok(());
+        }
+
+        // NOTE: After a reorg, the last indexed blocks are not canonical anymore. The blocks of
+        //       the new best chain after the last canonical indexed block are rescanned.
+        let mut checkpoint = retract_checkpoints(&mut checkpoints, |number| {
+            self.client.canonical_hash(number)
+        })?;
+
+        // NOTE: The ledger of a block is a prefix of the ledger of its descendants, so the scan of
+        //       a block which was interrupted is resumed if the block is still canonical and newer
+        //       than the last canonical indexed block.
+        let progress_key = aux_key(b"progress", ());
+        if let Some((number, hash, progress)) =
+            self.get::<(BlockNumber, Hash, RawCheckpoint)>(&progress_key)?
+        {
+            if Some(number) > checkpoints.last().map(|(number, _, _)| *number)
+                && self.client.canonical_hash(number)? == Some(hash)
+            {
+                checkpoint = progress;
+            }
+        }
+
+        let mut indexed = 0;
+        loop {
+            let mut entries = Vec::new();
+            let mut senders = Vec::new();
+            let done = scan_ledger(
+                |key| self.client.read_storage(best_hash, key),
+                PALLET,
+                &mut checkpoint,
+                self.batch_size,
+                |shard, index, value| entries.push((aux_key(b"receiver", (shard, index)), value)),
+                |index, value| senders.push((aux_key(b"sender", index), value)),
+            )?;
+            entries.append(&mut senders);
+            indexed += entries.len();
+            if done {
+                checkpoints.retain(|(number, _, _)| *number >= finalized_number);
+                checkpoints.push((best_number, best_hash, checkpoint));
+                entries.push((aux_key(b"checkpoints", ()), checkpoints.encode()));
+                debug!(
+                    target: "manta-pay-index",
+                    "Indexed block #{} ({:?}) with {} new entries",
+                    best_number,
+                    best_hash,
+                    indexed,
+                );
+                return self.client.write_aux(&entries, &[progress_key]);
+            }
+            entries.push((
+                progress_key.clone(),
+                (best_number, best_hash, checkpoint).encode(),
+            ));
+            self.client.write_aux(&entries, &[])?;
+        }
+    }
+
+    /// Returns the diff of the ledger at the indexed block `at` since `checkpoint`.
+    fn try_pull_ledger_diff(
+        &self,
+        at: Hash,
+        checkpoint: RawCheckpoint,
+        max_receivers: u64,
+        max_senders: u64,
+    ) -> sp_blockchain::Result<Option<PullResponse>> {
+        let indexed = match self
+            .checkpoints()?
+            .into_iter()
+            .find(|(_, hash, _)| *hash == at)
+        {
+            Some((_, _, indexed)) => indexed,
+            None => return Ok(None),
+        };
+
+        // NOTE: Receivers are pulled greedily shard by shard, like the runtime does.
+        let max_receivers = max_receivers.min(PULL_MAX_RECEIVER_UPDATE_SIZE);
+        let mut more_receivers = false;
+        let mut receivers = Vec::new();
+        for (shard, (start, end)) in checkpoint
+            .receiver_index
+            .into_iter()
+            .zip(indexed.receiver_index)
+            .enumerate()
+        {
+            let mut index = start;
+            while index < end && (receivers.len() as u64) < max_receivers {
+                receivers.push(self.entry(aux_key(b"receiver", (shard as u8, index)))?);
+                index += 1;
+            }
+            more_receivers |= index < end;
+            if receivers.len() as u64 == max_receivers && more_receivers {
+                break;
+            }
+        }
+
+        let end = indexed.sender_index;
+        let stop = end.min(
+            checkpoint
+                .sender_index
+                .saturating_add(max_senders.min(PULL_MAX_SENDER_UPDATE_SIZE)),
+        );
+        let senders = (checkpoint.sender_index..stop)
+            .map(|index| self.entry(aux_key(b"sender", index)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // NOTE: The runtime counts the leaf index of the last receiver of every shard, which is
+        //       one less than the number of its receivers.
+        let senders_receivers_total = indexed
+            .receiver_index
+            .iter()
+            .map(|count| count.saturating_sub(1) as u128)
+            .sum::<u128>()
+            + end as u128;
+        Ok(Some(PullResponse {
+            should_continue: more_receivers || stop < end,
+            receivers,
+            senders,
+            senders_receivers_total: asset_value_encode(senders_receivers_total),
+        }))
+    }
+
+    /// Reads the indexed entry at `key`, which has to exist.
+    #[inline]
+    fn entry<T: Decode>(&self, key: Vec<u8>) -> sp_blockchain::Result<T> {
+        self.get(&key)?
+            .ok_or_else(|| sp_blockchain::Error::Backend("Missing index entry".into()))
+    }
+}
+
+impl<BA, C> MantaPayIndex<BA, C>
+where
+    C: Chain<BA> + BlockchainEvents<Block>,
+{
+    /// Indexes the best block of the client whenever a new best block is imported.
+    ///
+    /// The storage reads of the index are blocking, so this has to run on a blocking task.
+    pub async fn run(self: Arc<Self>) {
+        if let Err(err) = self.index_best_block() {
+            warn!(target: "manta-pay-index", "Unable to index the best block: {err}");
+        }
+        let mut imports = self.client.import_notification_stream();
+        while let Some(notification) = imports.next().await {
+            if !notification.is_new_best {
+                continue;
+            }
+            if let Err(err) = self.index_best_block() {
+                warn!(target: "manta-pay-index", "Unable to index the best block: {err}");
+            }
+        }
+    }
+}
+
+impl<BA, C> LedgerIndex<Block> for MantaPayIndex<BA, C>
+where
+    BA: Send + Sync,
+    C: Chain<BA> + Send + Sync,
+{
+    #[inline]
+    fn pull_ledger_diff(
+        &self,
+        at: Hash,
+        checkpoint: RawCheckpoint,
+        max_receivers: u64,
+        max_senders: u64,
+    ) -> Option<PullResponse> {
+        self.try_pull_ledger_diff(at, checkpoint, max_receivers, max_senders)
+            .unwrap_or_else(|err| {
+                warn!(target: "manta-pay-index", "Unable to pull from the index: {err}");
+                None
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use manta_support::manta_pay::{FullIncomingNote, OutgoingNote, Utxo, UtxoMerkleTreePath};
+    use sp_core::storage::Storage;
+    use std::{collections::BTreeMap, sync::Mutex};
+
+    /// Backend Marker of [`TestChain`]
+    struct TestBackend;
+
+    /// In-Memory Chain
+    #[derive(Default)]
+    struct TestChain {
+        /// Canonical Blocks with their Hash and whole Storage
+        blocks: Mutex<Vec<(Hash, BTreeMap<Vec<u8>, Vec<u8>>)>>,
+
+        /// Auxiliary Database
+        aux: Mutex<BTreeMap<Vec<u8>, Vec<u8>>>,
+    }
+
+    impl TestChain {
+        /// Retracts the last `retracted` blocks and imports a new best block whose MantaPay ledger
+        /// has `receivers[shard]` receivers in each shard, and one sender for every tag in
+        /// `senders`. The first byte of the commitment of every receiver is its shard.
+        fn import(&self, retracted: usize, receivers: &[u64], senders: &[u8]) -> Hash {
+            let mut storage = BTreeMap::new();
+            let shards = storage_prefix(PALLET, "Shards");
+            let shard_trees = storage_prefix(PALLET, "ShardTrees");
+            for (shard, count) in receivers.iter().enumerate() {
+                let shard = shard as u8;
+                let shard_key = [&shards[..], &twox_64_concat(&shard.encode())].concat();
+                for index in 0..*count {
+                    let mut utxo = Utxo::default();
+                    utxo.commitment[0] = shard;
+                    utxo.commitment[1..9].copy_from_slice(&index.to_le_bytes());
+                    storage.insert(
+                        [&shard_key[..], &twox_64_concat(&index.encode())].concat(),
+                        (utxo, FullIncomingNote::default()).encode(),
+                    );
+                }
+                let mut tree = UtxoMerkleTreePath::default();
+                tree.current_path.leaf_index = count.saturating_sub(1) as u32;
+                storage.insert(
+                    [&shard_trees[..], &twox_64_concat(&shard.encode())].concat(),
+                    tree.encode(),
+                );
+            }
+            let insertion_order = storage_prefix(PALLET, "NullifierSetInsertionOrder");
+            for (index, tag) in (0u64..).zip(senders) {
+                let mut nullifier = [0; 32];
+                nullifier[..8].copy_from_slice(&index.to_le_bytes());
+                nullifier[8] = *tag;
+                storage.insert(
+                    [&insertion_order[..], &twox_64_concat(&index.encode())].concat(),
+                    (nullifier, OutgoingNote::default()).encode(),
+                );
+            }
+            storage.insert(
+                storage_prefix(PALLET, "NullifierSetSize"),
+                (senders.len() as u64).encode(),
+            );
+            let hash = Hash::from(sp_core::blake2_256(&storage.encode()));
+            let mut blocks = self.blocks.lock().expect("The lock is never poisoned.");
+            let canonical = blocks.len() - retracted;
+            blocks.truncate(canonical);
+            blocks.push((hash, storage));
+            hash
+        }
+
+        /// Returns the diff of the ledger at the best block since `checkpoint`, as computed by
+        /// the Calamari runtime.
+        fn runtime_pull_ledger_diff(
+            &self,
+            checkpoint: RawCheckpoint,
+            max_receivers: u64,
+            max_senders: u64,
+        ) -> PullResponse {
+            let top = self
+                .blocks
+                .lock()
+                .expect("The lock is never poisoned.")
+                .last()
+                .expect("The chain has a best block.")
+                .1
+                .clone();
+            sp_io::TestExternalities::new(Storage {
+                top,
+                children_default: Default::default(),
+            })
+            .execute_with(|| {
+                pallet_manta_pay::Pallet::<calamari_runtime::Runtime>::pull_ledger_diff(
+                    checkpoint.into(),
+                    max_receivers,
+                    max_senders,
+                )
+            })
+        }
+    }
+
+    impl Chain<TestBackend> for TestChain {
+        #[inline]
+        fn head(&self) -> (BlockNumber, Hash, BlockNumber) {
+            let blocks = self.blocks.lock().expect("The lock is never poisoned.");
+            let (hash, _) = blocks.last().expect("The chain has a best block.");
+            ((blocks.len() - 1) as BlockNumber, *hash, 0)
+        }
+
+        #[inline]
+        fn canonical_hash(&self, number: BlockNumber) -> sp_blockchain::Result<Option<Hash>> {
+            Ok(self
+                .blocks
+                .lock()
+                .expect("The lock is never poisoned.")
+                .get(number as usize)
+                .map(|(hash, _)| *hash))
+        }
+
+        #[inline]
+        fn read_storage(&self, at: Hash, key: Vec<u8>) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(self
+                .blocks
+                .lock()
+                .expect("The lock is never poisoned.")
+                .iter()
+                .find(|(hash, _)| *hash == at)
+                .and_then(|(_, storage)| storage.get(&key).cloned()))
+        }
+
+        #[inline]
+        fn read_aux(&self, key: &[u8]) -> sp_blockchain::Result<Option<Vec<u8>>> {
+            Ok(self
+                .aux
+                .lock()
+                .expect("The lock is never poisoned.")
+                .get(key)
+                .cloned())
+        }
+
+        #[inline]
+        fn write_aux(
+            &self,
+            insert: &[(Vec<u8>, Vec<u8>)],
+            delete: &[Vec<u8>],
+        ) -> sp_blockchain::Result<()> {
+            let mut aux = self.aux.lock().expect("The lock is never poisoned.");
+            aux.extend(insert.iter().cloned());
+            for key in delete {
+                aux.remove(key);
+            }
+            Ok(())
+        }
+    }
+
+    /// Checks that reorging away from a chain and back to it rescans the entries overwritten by
+    /// the retracted chain.
+    #[test]
+    fn index_best_block_should_rescan_after_reorging_twice() {
+        let chain = Arc::new(TestChain::default());
+        let index = MantaPayIndex::<TestBackend, _> {
+            client: chain.clone(),
+            batch_size: 3,
+            __: PhantomData,
+        };
+        let index_best_block = |retracted, senders: &[u8]| {
+            let best = chain.import(retracted, &[1], senders);
+            index
+                .index_best_block()
+                .expect("Indexing the test chain is infallible.");
+            assert_eq!(
+                index
+                    .try_pull_ledger_diff(best, RawCheckpoint::default(), u64::MAX, u64::MAX)
+                    .unwrap(),
+                Some(chain.runtime_pull_ledger_diff(RawCheckpoint::default(), u64::MAX, u64::MAX)),
+                "The index must serve the ledger of the best block."
+            );
+            best
+        };
+        let indexed = || {
+            index
+                .checkpoints()
+                .unwrap()
+                .into_iter()
+                .map(|(_, hash, _)| hash)
+                .collect::<Vec<_>>()
+        };
+        let genesis = index_best_block(0, &[]);
+        let a = index_best_block(0, &[1]);
+        index_best_block(0, &[1, 2]);
+        let x = index_best_block(1, &[1, 3]);
+        assert_eq!(
+            indexed(),
+            vec![genesis, a, x],
+            "The checkpoint of the retracted block must be removed."
+        );
+        chain.import(1, &[1], &[1, 2]);
+        let g = index_best_block(0, &[1, 2, 4]);
+        assert_eq!(
+            indexed(),
+            vec![genesis, a, g],
+            "The checkpoints of the retracted blocks must be removed."
+        );
+    }
+
+    /// Checks that the index, scanning the ledger in small batches, serves the same diffs as the
+    /// runtime, including when they are pulled page by page until `should_continue` is unset.
+    #[test]
+    fn pull_ledger_diff_should_match_the_runtime() {
+        let chain = Arc::new(TestChain::default());
+        let index = MantaPayIndex::<TestBackend, _> {
+            client: chain.clone(),
+            batch_size: 3,
+            __: PhantomData,
+        };
+        for (receivers, senders) in [
+            (&[2, 0, 5][..], &[0; 4][..]),
+            (&[3, 1, 5, 2][..], &[0; 7][..]),
+        ] {
+            let best = chain.import(0, receivers, senders);
+            index
+                .index_best_block()
+                .expect("Indexing the test chain is infallible.");
+            assert_eq!(
+                chain.read_aux(&aux_key(b"progress", ())).unwrap(),
+                None,
+                "The progress of a finished scan must be removed."
+            );
+
+            let mut partial = RawCheckpoint::default();
+            partial.receiver_index[2] = 3;
+            partial.sender_index = 2;
+            for checkpoint in [RawCheckpoint::default(), partial] {
+                for (max_receivers, max_senders) in [
+                    (0, 0),
+                    (1, 1),
+                    (2, 3),
+                    (4, 2),
+                    (1024, 1024),
+                    (u64::MAX, u64::MAX),
+                ] {
+                    assert_eq!(
+                        index
+                            .try_pull_ledger_diff(best, checkpoint, max_receivers, max_senders)
+                            .unwrap(),
+                        Some(chain.runtime_pull_ledger_diff(
+                            checkpoint,
+                            max_receivers,
+                            max_senders
+                        )),
+                        "Diffs differ since {checkpoint:?} with at most {max_receivers} receivers \
+                         and {max_senders} senders."
+                    );
+                }
+            }
+
+            let mut checkpoint = RawCheckpoint::default();
+            loop {
+                let response = chain.runtime_pull_ledger_diff(checkpoint, 2, 3);
+                assert_eq!(
+                    index.try_pull_ledger_diff(best, checkpoint, 2, 3).unwrap(),
+                    Some(response.clone()),
+                    "Diffs differ since {checkpoint:?}."
+                );
+                for (utxo, _) in &response.receivers {
+                    checkpoint.receiver_index[utxo.commitment[0] as usize] += 1;
+                }
+                checkpoint.sender_index += response.senders.len() as u64;
+                if !response.should_continue {
+                    break;
+                }
+            }
+            assert_eq!(
+                checkpoint.receiver_index[..receivers.len()],
+                *receivers,
+                "Every receiver must be pulled."
+            );
+            assert_eq!(checkpoint.sender_index, senders.len() as u64);
+        }
+    }
+}
@@ -17,6 +17,14 @@
 //! Calamari RPC Extensions
 
 use super::*;
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
+    types::CalamariAssetId,
+};
+use pallet_asset_manager::{
+    rpc::{AssetManager, AssetManagerRpcApiServer},
+    runtime::AssetManagerApi,
+};
 use pallet_manta_pay::{
     rpc::{Pull, PullApiServer},
     runtime::PullLedgerDiffApi,
@@ -25,6 +33,10 @@ use pallet_manta_sbt::{
     rpc::{SBTPull, SBTPullApiServer},
     runtime::SBTPullLedgerDiffApi,
 };
+use pallet_parachain_staking::{
+    rpc::{Staking, StakingApiServer},
+    runtime::ParachainStakingApi,
+};
 
 /// Instantiate all RPC extensions for calamari.
 pub fn create_calamari_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, sc_service::Error>
@@ -39,8 +51,10 @@ where
     C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: AssetManagerApi<Block, CalamariAssetId, AssetLocation, AssetRegistryMetadata<Balance>>,
     C::Api: PullLedgerDiffApi<Block>,
     C::Api: SBTPullLedgerDiffApi<Block>,
+    C::Api: ParachainStakingApi<Block, AccountId, Balance>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use frame_rpc_system::{System, SystemApiServer};
@@ -51,6 +65,7 @@ where
         client,
         pool,
         deny_unsafe,
+        manta_pay_index,
     } = deps;
 
     module
@@ -60,15 +75,31 @@ where
         .merge(TransactionPayment::new(client.clone()).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
-    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = Pull::new(client.clone()).into_rpc();
+    let asset_manager_rpc: jsonrpsee::RpcModule<AssetManager<Block, C>> =
+        AssetManager::new(client.clone()).into_rpc();
+    module
+        .merge(asset_manager_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    let manta_pay = match manta_pay_index {
+        Some(index) => Pull::new(client.clone()).with_index(index),
+        None => Pull::new(client.clone()),
+    };
+    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = manta_pay.into_rpc();
     module
         .merge(manta_pay_rpc)
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
-    let manta_sbt_rpc: jsonrpsee::RpcModule<SBTPull<Block, C>> = SBTPull::new(client).into_rpc();
+    let manta_sbt_rpc: jsonrpsee::RpcModule<SBTPull<Block, C>> =
+        SBTPull::new(client.clone()).into_rpc();
     module
         .merge(manta_sbt_rpc)
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
+    let staking_rpc: jsonrpsee::RpcModule<Staking<Block, C>> = Staking::new(client).into_rpc();
+    module
+        .merge(staking_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
     Ok(module)
 }
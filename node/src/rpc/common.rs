@@ -17,6 +17,18 @@
 //! Common RPC Extensions
 
 use super::*;
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
+    types::MantaAssetId,
+};
+use pallet_asset_manager::{
+    rpc::{AssetManager, AssetManagerRpcApiServer},
+    runtime::AssetManagerApi,
+};
+use pallet_parachain_staking::{
+    rpc::{Staking, StakingApiServer},
+    runtime::ParachainStakingApi,
+};
 
 /// Instantiate all RPC extensions for common nodes like calamari/manta.
 pub fn create_common_full<C, P>(deps: FullDeps<C, P>) -> Result<RpcExtension, sc_service::Error>
@@ -31,6 +43,8 @@ where
     C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: AssetManagerApi<Block, MantaAssetId, AssetLocation, AssetRegistryMetadata<Balance>>,
+    C::Api: ParachainStakingApi<Block, AccountId, Balance>,
     P: TransactionPool + Sync + Send + 'static,
 {
     use frame_rpc_system::{System, SystemApiServer};
@@ -41,13 +55,25 @@ where
         client,
         pool,
         deny_unsafe,
+        ..
     } = deps;
 
     module
         .merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
     module
-        .merge(TransactionPayment::new(client).into_rpc())
+        .merge(TransactionPayment::new(client.clone()).into_rpc())
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    let asset_manager_rpc: jsonrpsee::RpcModule<AssetManager<Block, C>> =
+        AssetManager::new(client.clone()).into_rpc();
+    module
+        .merge(asset_manager_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    let staking_rpc: jsonrpsee::RpcModule<Staking<Block, C>> = Staking::new(client).into_rpc();
+    module
+        .merge(staking_rpc)
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
     Ok(module)
@@ -0,0 +1,50 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Dev Standalone Mode RPC Extensions
+
+use crate::dev_timestamp::DevTimestamp;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+
+/// Dev API
+#[rpc(server)]
+pub trait DevApi {
+    /// Advances the timestamp of the next sealed blocks by `millis`, returning the total offset
+    /// from the system time.
+    #[method(name = "dev_advanceTimestamp")]
+    fn advance_timestamp(&self, millis: u64) -> RpcResult<u64>;
+}
+
+/// Dev RPC API Implementation
+pub struct Dev {
+    /// Timestamp of the Sealed Blocks
+    timestamp: DevTimestamp,
+}
+
+impl Dev {
+    /// Builds a new [`Dev`] RPC API implementation.
+    #[inline]
+    pub fn new(timestamp: DevTimestamp) -> Self {
+        Self { timestamp }
+    }
+}
+
+impl DevApiServer for Dev {
+    #[inline]
+    fn advance_timestamp(&self, millis: u64) -> RpcResult<u64> {
+        Ok(self.timestamp.advance(millis))
+    }
+}
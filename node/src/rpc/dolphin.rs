@@ -17,6 +17,14 @@
 //! Dolphin RPC Extensions
 
 use super::*;
+use manta_primitives::{
+    assets::{AssetLocation, AssetRegistryMetadata},
+    types::DolphinAssetId,
+};
+use pallet_asset_manager::{
+    rpc::{AssetManager, AssetManagerRpcApiServer},
+    runtime::AssetManagerApi,
+};
 use pallet_manta_pay::{
     rpc::{Pull, PullApiServer},
     runtime::PullLedgerDiffApi,
@@ -39,6 +47,7 @@ where
     C::Api: frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
     C::Api: BlockBuilder<Block>,
+    C::Api: AssetManagerApi<Block, DolphinAssetId, AssetLocation, AssetRegistryMetadata<Balance>>,
     C::Api: PullLedgerDiffApi<Block>,
     C::Api: SBTPullLedgerDiffApi<Block>,
     P: TransactionPool + Sync + Send + 'static,
@@ -51,6 +60,7 @@ where
         client,
         pool,
         deny_unsafe,
+        manta_pay_index,
     } = deps;
 
     module
@@ -60,7 +70,17 @@ where
         .merge(TransactionPayment::new(client.clone()).into_rpc())
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
 
-    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = Pull::new(client.clone()).into_rpc();
+    let asset_manager_rpc: jsonrpsee::RpcModule<AssetManager<Block, C>> =
+        AssetManager::new(client.clone()).into_rpc();
+    module
+        .merge(asset_manager_rpc)
+        .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+
+    let manta_pay = match manta_pay_index {
+        Some(index) => Pull::new(client.clone()).with_index(index),
+        None => Pull::new(client.clone()),
+    };
+    let manta_pay_rpc: jsonrpsee::RpcModule<Pull<Block, C>> = manta_pay.into_rpc();
     module
         .merge(manta_pay_rpc)
         .map_err(|e| sc_service::Error::Other(e.to_string()))?;
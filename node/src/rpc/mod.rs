@@ -17,6 +17,7 @@
 //! Parachain-specific RPCs implementation.
 
 use manta_primitives::types::{AccountId, Balance, Block, Index as Nonce};
+use pallet_manta_pay::rpc::{LedgerIndex, LedgerSnapshotApiServer, LedgerSnapshots};
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
 use sc_transaction_pool_api::TransactionPool;
@@ -27,15 +28,35 @@ use std::sync::Arc;
 
 mod calamari;
 mod common;
+mod dev;
 mod dolphin;
 
 pub use calamari::create_calamari_full;
 pub use common::create_common_full;
+pub use dev::{Dev, DevApiServer};
 pub use dolphin::create_dolphin_full;
 
 /// A type representing all RPC extensions.
 pub type RpcExtension = jsonrpsee::RpcModule<()>;
 
+/// Adds the `mantaPay_ledger_snapshot_*` RPCs serving `snapshots` to the RPC extensions built by
+/// `full_rpc`.
+pub fn with_ledger_snapshots<C, P, F>(
+    full_rpc: F,
+    snapshots: LedgerSnapshots,
+) -> impl Fn(FullDeps<C, P>) -> Result<RpcExtension, sc_service::Error>
+where
+    F: Fn(FullDeps<C, P>) -> Result<RpcExtension, sc_service::Error>,
+{
+    move |deps| {
+        let mut module = full_rpc(deps)?;
+        module
+            .merge(snapshots.clone().into_rpc())
+            .map_err(|e| sc_service::Error::Other(e.to_string()))?;
+        Ok(module)
+    }
+}
+
 /// Full client dependencies
 pub struct FullDeps<C, P> {
     /// The client instance to use.
@@ -44,4 +65,6 @@ pub struct FullDeps<C, P> {
     pub pool: Arc<P>,
     /// Whether to deny unsafe calls
     pub deny_unsafe: DenyUnsafe,
+    /// MantaPay ledger index, if the node runs with `--manta-pay-index`.
+    pub manta_pay_index: Option<Arc<dyn LedgerIndex<Block>>>,
 }
@@ -17,7 +17,10 @@
 //! Nimbus-based Parachain Node Service
 
 use crate::{
+    cli::Sealing,
     client::{RuntimeApiCommon, RuntimeApiNimbus},
+    dev_timestamp::DevTimestamp,
+    manta_pay_index::MantaPayIndex,
     rpc,
 };
 use cumulus_client_cli::CollatorOptions;
@@ -30,6 +33,7 @@ use cumulus_primitives_core::ParaId;
 use cumulus_relay_chain_interface::{RelayChainError, RelayChainInterface};
 use jsonrpsee::RpcModule;
 pub use manta_primitives::types::{AccountId, Balance, Block, Hash, Header, Index as Nonce};
+use pallet_manta_pay::rpc::LedgerIndex;
 
 use sc_consensus::ImportQueue;
 use sc_executor::WasmExecutor;
@@ -193,6 +197,26 @@ where
     })
 }
 
+/// Spawns the MantaPay ledger index of `client` if `enabled`, returning it for the RPCs.
+fn spawn_manta_pay_index<RuntimeApi>(
+    client: &Arc<Client<RuntimeApi>>,
+    task_manager: &TaskManager,
+    enabled: bool,
+) -> Option<Arc<dyn LedgerIndex<Block>>>
+where
+    RuntimeApi: ConstructRuntimeApi<Block, Client<RuntimeApi>> + Send + Sync + 'static,
+{
+    if !enabled {
+        return None;
+    }
+    let index = Arc::new(MantaPayIndex::<TFullBackend<Block>, _>::new(client.clone()));
+    // NOTE: The index reads the storage synchronously, e.g. the whole ledger on first start.
+    task_manager
+        .spawn_handle()
+        .spawn_blocking("manta-pay-index", None, index.clone().run());
+    Some(index)
+}
+
 /// Start a node with the given parachain `Configuration` and relay chain `Configuration`.
 ///
 /// This is the actual implementation that is abstract over the executor and the runtime api.
@@ -205,6 +229,7 @@ async fn start_node_impl<RuntimeApi, BIC, FullRpc>(
     full_rpc: FullRpc,
     build_consensus: BIC,
     hwbench: Option<sc_sysinfo::HwBench>,
+    manta_pay_index: bool,
 ) -> sc_service::error::Result<(TaskManager, Arc<Client<RuntimeApi>>)>
 where
     RuntimeApi: ConstructRuntimeApi<Block, Client<RuntimeApi>> + Send + Sync + 'static,
@@ -272,6 +297,7 @@ where
             warp_sync: None,
         })?;
 
+    let manta_pay_index = spawn_manta_pay_index(&client, &task_manager, manta_pay_index);
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
@@ -281,6 +307,7 @@ where
                 client: client.clone(),
                 pool: transaction_pool.clone(),
                 deny_unsafe,
+                manta_pay_index: manta_pay_index.clone(),
             };
 
             full_rpc(deps)
@@ -359,6 +386,7 @@ pub async fn start_parachain_node<RuntimeApi, FullRpc>(
     collator_options: CollatorOptions,
     id: ParaId,
     hwbench: Option<sc_sysinfo::HwBench>,
+    manta_pay_index: bool,
     full_rpc: FullRpc,
 ) -> sc_service::error::Result<(TaskManager, Arc<Client<RuntimeApi>>)>
 where
@@ -379,13 +407,19 @@ where
         full_rpc,
         crate::builder::build_nimbus_consensus,
         hwbench,
+        manta_pay_index,
     )
     .await
 }
 
-/// Start a dev node using nimbus instant-sealing consensus without relaychain attached.
+/// Start a dev node using nimbus manual-sealing consensus without relaychain attached.
+///
+/// Blocks are sealed according to `sealing`, and the manual seal `engine_*` RPCs, as well as the
+/// `dev_advanceTimestamp` RPC, are added to the ones of `full_rpc`.
 pub async fn start_dev_nimbus_node<RuntimeApi, FullRpc>(
     config: Configuration,
+    sealing: Sealing,
+    manta_pay_index: bool,
     full_rpc: FullRpc,
 ) -> sc_service::error::Result<TaskManager>
 where
@@ -398,7 +432,9 @@ where
         ) -> Result<RpcModule<()>, Error>
         + 'static,
 {
+    use crate::rpc::{Dev, DevApiServer};
     use sc_consensus::LongestChain;
+    use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
 
     let sc_service::PartialComponents {
         client,
@@ -424,6 +460,8 @@ where
 
     let role = config.role.clone();
     let select_chain = LongestChain::new(backend.clone());
+    let (command_sink, rpc_commands) = futures::channel::mpsc::channel(1000);
+    let timestamp = DevTimestamp::default();
 
     if role.is_authority() {
         let dev_consensus = crate::builder::build_dev_nimbus_consensus(
@@ -432,6 +470,9 @@ where
             &keystore_container,
             select_chain,
             &task_manager,
+            sealing,
+            rpc_commands,
+            timestamp.clone(),
         )?;
 
         task_manager.spawn_essential_handle().spawn_blocking(
@@ -441,6 +482,7 @@ where
         );
     }
 
+    let manta_pay_index = spawn_manta_pay_index(&client, &task_manager, manta_pay_index);
     let rpc_builder = {
         let client = client.clone();
         let transaction_pool = transaction_pool.clone();
@@ -450,9 +492,17 @@ where
                 client: client.clone(),
                 pool: transaction_pool.clone(),
                 deny_unsafe,
+                manta_pay_index: manta_pay_index.clone(),
             };
 
-            full_rpc(deps)
+            let mut module = full_rpc(deps)?;
+            module
+                .merge(ManualSeal::new(command_sink.clone()).into_rpc())
+                .map_err(|e| Error::Other(e.to_string()))?;
+            module
+                .merge(Dev::new(timestamp.clone()).into_rpc())
+                .map_err(|e| Error::Other(e.to_string()))?;
+            Ok(module)
         })
     };
 
@@ -0,0 +1,118 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Session Keys Check Command
+
+use manta_primitives::types::AccountId;
+use sc_cli::{CliConfiguration, ImportParams, KeystoreParams, SharedParams};
+use session_key_primitives::runtime::SessionKeysRegistryApi;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{crypto::Ss58Codec, hexdisplay::HexDisplay};
+use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+use std::sync::Arc;
+
+/// Checks the session keys of a collator in the local keystore against the keys registered
+/// on-chain at the best block.
+#[derive(Debug, Clone, clap::Parser)]
+pub struct CheckSessionKeysCmd {
+    /// SS58 address of the collator account whose session keys are checked.
+    #[arg(long)]
+    pub account: String,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub shared_params: SharedParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub import_params: ImportParams,
+
+    #[allow(missing_docs)]
+    #[command(flatten)]
+    pub keystore_params: KeystoreParams,
+}
+
+impl CheckSessionKeysCmd {
+    /// Prints the status of every session key of the collator and fails if a key is not
+    /// registered on-chain or missing from the local keystore.
+    pub fn run<B, C>(&self, client: Arc<C>, keystore: SyncCryptoStorePtr) -> sc_cli::Result<()>
+    where
+        B: BlockT,
+        C: ProvideRuntimeApi<B> + HeaderBackend<B>,
+        C::Api: SessionKeysRegistryApi<B, AccountId>,
+    {
+        let account = AccountId::from_ss58check(&self.account)
+            .map_err(|err| format!("Invalid account {}: {err:?}", self.account))?;
+        let at = BlockId::hash(client.info().best_hash);
+        let status = client
+            .runtime_api()
+            .session_key_status(&at, account)
+            .map_err(|err| format!("Unable to query session key status: {err}"))?;
+
+        let mut problems = 0;
+        for key in &status.keys {
+            let key_type = String::from_utf8_lossy(&key.key_type.0);
+            match &key.registered {
+                Some(public) => {
+                    let in_keystore =
+                        SyncCryptoStore::has_keys(&*keystore, &[(public.clone(), key.key_type)]);
+                    if !in_keystore {
+                        problems += 1;
+                    }
+                    println!(
+                        "{key_type}: 0x{} registered, in keystore: {in_keystore}, active: {}, queued: {}",
+                        HexDisplay::from(public),
+                        key.active,
+                        key.queued,
+                    );
+                }
+                None => {
+                    problems += 1;
+                    println!("{key_type}: not registered");
+                }
+            }
+        }
+        println!("Validator of the current session: {}", status.is_validator);
+        println!(
+            "Queued for the next session with all keys: {}",
+            status.queued_complete
+        );
+
+        if problems > 0 {
+            return Err(format!(
+                "{problems} session key(s) are not registered on-chain or missing from the keystore"
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl CliConfiguration for CheckSessionKeysCmd {
+    fn shared_params(&self) -> &SharedParams {
+        &self.shared_params
+    }
+
+    fn import_params(&self) -> Option<&ImportParams> {
+        Some(&self.import_params)
+    }
+
+    fn keystore_params(&self) -> Option<&KeystoreParams> {
+        Some(&self.keystore_params)
+    }
+}
@@ -17,10 +17,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use crate::{Call, Config, Pallet};
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::{
+    benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError,
+};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
 use frame_system::{EventRecord, RawOrigin};
 use manta_primitives::assets::{AssetConfig, TestingDefault, UnitsPerSecond};
+use sp_runtime::{traits::Saturating, FixedU128, Perbill};
 use xcm::latest::prelude::*;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
@@ -139,6 +142,189 @@ benchmarks! {
     verify {
         assert_eq!(Pallet::<T>::get_min_xcm_fee(location), Some(min_xcm_fee));
     }
+
+    freeze_asset {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Root, some_valid_asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetFrozen { asset_id: some_valid_asset_id }.into());
+    }
+
+    thaw_asset {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), some_valid_asset_id)?;
+    }: _(RawOrigin::Root, some_valid_asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetThawed { asset_id: some_valid_asset_id }.into());
+    }
+
+    force_burn_asset {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        let who: T::AccountId = whitelisted_caller();
+        let amount = <T as Config>::Balance::from(100);
+        Pallet::<T>::mint_asset(RawOrigin::Root.into(), some_valid_asset_id, who.clone(), amount.clone())?;
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), some_valid_asset_id)?;
+    }: _(RawOrigin::Root, some_valid_asset_id, who.clone())
+    verify {
+        assert_last_event::<T>(crate::Event::AssetForceBurned { asset_id: some_valid_asset_id, who, amount }.into());
+    }
+
+    deregister_asset {
+        let a in 0 .. T::MaxAliasLocations::get();
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location.clone(), metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        Pallet::<T>::set_units_per_second(RawOrigin::Root.into(), some_valid_asset_id, 10)?;
+        for i in 0..a {
+            let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(i))));
+            Pallet::<T>::add_alias_location(RawOrigin::Root.into(), some_valid_asset_id, alias)?;
+        }
+        Pallet::<T>::freeze_asset(RawOrigin::Root.into(), some_valid_asset_id)?;
+    }: _(RawOrigin::Root, some_valid_asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetDeregistered { asset_id: some_valid_asset_id, location }.into());
+    }
+
+    add_alias_location {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        let alias_count = T::MaxAliasLocations::get().saturating_sub(1);
+        for i in 0..alias_count {
+            let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(i))));
+            Pallet::<T>::add_alias_location(RawOrigin::Root.into(), some_valid_asset_id, alias)?;
+        }
+        let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(alias_count))));
+    }: _(RawOrigin::Root, some_valid_asset_id, alias.clone())
+    verify {
+        assert_last_event::<T>(crate::Event::AliasLocationAdded { asset_id: some_valid_asset_id, location: alias }.into());
+    }
+
+    remove_alias_location {
+        let location = T::Location::default();
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        for i in 0..T::MaxAliasLocations::get() {
+            let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(i))));
+            Pallet::<T>::add_alias_location(RawOrigin::Root.into(), some_valid_asset_id, alias)?;
+        }
+        let alias = T::Location::from(MultiLocation::new(1, X1(Parachain(0))));
+    }: _(RawOrigin::Root, some_valid_asset_id, alias.clone())
+    verify {
+        assert_last_event::<T>(crate::Event::AliasLocationRemoved { asset_id: some_valid_asset_id, location: alias }.into());
+    }
+
+    propose_asset_registration {
+        let proposer: T::AccountId = whitelisted_caller();
+        let deposit = T::RegistrationDeposit::get();
+        T::Currency::make_free_balance_be(&proposer, deposit.saturating_mul(2u32.into()));
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default_insufficient();
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Signed(proposer.clone()), location, metadata)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetRegistrationProposed { asset_id: some_valid_asset_id, proposer, deposit }.into());
+    }
+
+    approve_asset_registration {
+        let proposer: T::AccountId = whitelisted_caller();
+        let deposit = T::RegistrationDeposit::get();
+        T::Currency::make_free_balance_be(&proposer, deposit.saturating_mul(2u32.into()));
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default_insufficient();
+        Pallet::<T>::propose_asset_registration(RawOrigin::Signed(proposer).into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Root, some_valid_asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetRegistrationApproved { asset_id: some_valid_asset_id }.into());
+    }
+
+    reject_asset_registration {
+        let proposer: T::AccountId = whitelisted_caller();
+        let deposit = T::RegistrationDeposit::get();
+        T::Currency::make_free_balance_be(&proposer, deposit.saturating_mul(2u32.into()));
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default_insufficient();
+        Pallet::<T>::propose_asset_registration(RawOrigin::Signed(proposer).into(), location, metadata)?;
+        let some_valid_asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+    }: _(RawOrigin::Root, some_valid_asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::AssetRegistrationRejected { asset_id: some_valid_asset_id, slashed: deposit }.into());
+    }
+
+    set_outbound_rate_limit {
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get();
+        let limit = <T as Config>::Balance::from(1_000u32);
+        let period = T::BlockNumber::from(100u32);
+        Pallet::<T>::set_outbound_rate_limit(RawOrigin::Root.into(), asset_id, limit, period)?;
+    }: _(RawOrigin::Root, asset_id, limit, period)
+    verify {
+        assert_last_event::<T>(crate::Event::OutboundRateLimitSet { asset_id, limit, period }.into());
+    }
+
+    remove_outbound_rate_limit {
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get();
+        let limit = <T as Config>::Balance::from(1_000u32);
+        let period = T::BlockNumber::from(100u32);
+        Pallet::<T>::set_outbound_rate_limit(RawOrigin::Root.into(), asset_id, limit, period)?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::OutboundRateLimitRemoved { asset_id }.into());
+    }
+    set_relative_price_bounds {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        // Worst case: the fed price is outside of the new bounds and gets removed.
+        Pallet::<T>::set_relative_price_bounds(RawOrigin::Root.into(), asset_id, FixedU128::from_rational(1, 4), FixedU128::from(4), Perbill::from_percent(10))?;
+        let origin = T::PriceFeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Pallet::<T>::feed_relative_price(origin, asset_id, FixedU128::from(3))?;
+        let min = FixedU128::from_rational(1, 2);
+        let max = FixedU128::from(2);
+        let max_change = Perbill::from_percent(10);
+    }: _(RawOrigin::Root, asset_id, min, max, max_change)
+    verify {
+        assert_last_event::<T>(crate::Event::RelativePriceBoundsSet { asset_id, min, max, max_change }.into());
+        assert_eq!(Pallet::<T>::relative_price(asset_id), None);
+    }
+
+    remove_relative_price_bounds {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        Pallet::<T>::set_relative_price_bounds(RawOrigin::Root.into(), asset_id, FixedU128::from_rational(1, 2), FixedU128::from(2), Perbill::from_percent(10))?;
+    }: _(RawOrigin::Root, asset_id)
+    verify {
+        assert_last_event::<T>(crate::Event::RelativePriceBoundsRemoved { asset_id }.into());
+    }
+
+    feed_relative_price {
+        let location = T::Location::from(MultiLocation::new(1, X1(Parachain(1000))));
+        let metadata = <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata::testing_default();
+        Pallet::<T>::register_asset(RawOrigin::Root.into(), location, metadata)?;
+        let asset_id = <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get();
+        Pallet::<T>::set_relative_price_bounds(RawOrigin::Root.into(), asset_id, FixedU128::from_rational(1, 2), FixedU128::from(2), Perbill::from_percent(10))?;
+        let origin = T::PriceFeederOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        Pallet::<T>::feed_relative_price(origin.clone(), asset_id, FixedU128::from(1))?;
+        let price = FixedU128::from_rational(21, 20);
+    }: _<T::RuntimeOrigin>(origin, asset_id, price)
+    verify {
+        assert_eq!(Pallet::<T>::relative_price(asset_id).map(|relative_price| relative_price.price), Some(price));
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
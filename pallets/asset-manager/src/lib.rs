@@ -29,8 +29,15 @@
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migrations;
+mod queries;
 pub mod weights;
 
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
 #[cfg(test)]
 mod mock;
 
@@ -39,6 +46,7 @@ mod tests;
 
 pub use crate::weights::WeightInfo;
 pub use pallet::*;
+pub use queries::{AssetInfoOf, AssetRegistryMetadataOf};
 
 /// Asset Manager Pallet
 #[frame_support::pallet]
@@ -46,23 +54,28 @@ pub mod pallet {
     use crate::weights::WeightInfo;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Contains, StorageVersion},
+        traits::{Contains, Currency, Imbalance, OnUnbalanced, ReservableCurrency, StorageVersion},
         transactional, PalletId,
     };
     use frame_system::pallet_prelude::*;
     use manta_primitives::assets::{
         self, AssetConfig, AssetIdLocationMap, AssetIdType, AssetMetadata, AssetRegistry,
-        FungibleLedger, LocationType,
+        BalanceType, FungibleLedger, LocationType,
     };
     use orml_traits::GetByKey;
     use sp_runtime::{
         traits::{
             AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, MaybeSerializeDeserialize, One,
+            Saturating, Zero,
         },
-        ArithmeticError,
+        ArithmeticError, FixedPointNumber, FixedU128, Perbill,
     };
+    use sp_std::vec::Vec;
     use xcm::latest::prelude::*;
 
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
     /// Storage Version
     pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
@@ -72,6 +85,122 @@ pub mod pallet {
     /// Asset Count Type
     pub(crate) type AssetCount = u32;
 
+    /// Balance of the currency used for asset registration deposits.
+    pub type DepositBalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Negative imbalance of the currency used for asset registration deposits.
+    pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+        <T as frame_system::Config>::AccountId,
+    >>::NegativeImbalance;
+
+    /// Permissionless Asset Registration Proposal
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct AssetRegistrationProposal<AccountId, Balance, Location, Metadata> {
+        /// Account which proposed the registration
+        pub proposer: AccountId,
+
+        /// Deposit reserved from the proposer
+        pub deposit: Balance,
+
+        /// Location of the proposed asset
+        pub location: Location,
+
+        /// Metadata of the proposed asset
+        pub metadata: Metadata,
+    }
+
+    /// Outbound XCM Transfer Limit
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct OutboundRateLimit<Balance, BlockNumber> {
+        /// Maximum amount that can be withdrawn over any window of `period` blocks
+        pub limit: Balance,
+
+        /// Length of the sliding window in blocks
+        pub period: BlockNumber,
+    }
+
+    /// Number of Buckets the Window of an [`OutboundRateLimit`] is Split Into
+    pub const OUTBOUND_FLOW_BUCKETS: u32 = 10;
+
+    /// Outbound XCM Transfer Flow
+    ///
+    /// Withdrawals are summed per bucket of `ceil(period / OUTBOUND_FLOW_BUCKETS)` blocks. One
+    /// bucket more than the window spans is kept, so that the flow covers every block of the
+    /// last `period` and no window of `period` blocks can exceed the limit. A withdrawal keeps
+    /// counting for at most one bucket longer than `period`.
+    #[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct OutboundFlow<Balance, BlockNumber> {
+        /// Amount withdrawn in each bucket, indexed by bucket number modulo the bucket count
+        pub buckets: [Balance; OUTBOUND_FLOW_BUCKETS as usize + 1],
+
+        /// Number of the latest bucket, that is the block number divided by the bucket length
+        pub bucket: BlockNumber,
+    }
+
+    impl<Balance, BlockNumber> OutboundFlow<Balance, BlockNumber>
+    where
+        Balance: Copy + Saturating + Zero,
+    {
+        /// Returns the amount withdrawn over all buckets.
+        #[inline]
+        pub fn amount(&self) -> Balance {
+            self.buckets
+                .iter()
+                .fold(Zero::zero(), |total: Balance, amount| {
+                    total.saturating_add(*amount)
+                })
+        }
+    }
+
+    /// Bounds on the Relative Price of an Asset
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct RelativePriceBounds {
+        /// Lowest price that can be fed
+        pub min: FixedU128,
+
+        /// Highest price that can be fed
+        pub max: FixedU128,
+
+        /// Largest change of the price allowed in a single update, relative to the previous price
+        pub max_change: Perbill,
+    }
+
+    /// Relative Price of an Asset
+    ///
+    /// The price is the number of units of the asset worth one unit of the native token.
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct RelativePrice<BlockNumber> {
+        /// Units of the asset per unit of the native token
+        pub price: FixedU128,
+
+        /// Block at which the price was last fed
+        pub updated_at: BlockNumber,
+    }
+
+    /// Registered Asset as seen by the `AssetManagerApi`
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+    pub struct AssetInfo<AssetId, Location, Metadata> {
+        /// Asset id
+        pub asset_id: AssetId,
+
+        /// Canonical location of the asset
+        pub location: Location,
+
+        /// Alias locations accepted for inbound transfers
+        pub alias_locations: Vec<Location>,
+
+        /// Registry metadata of the asset
+        pub metadata: Metadata,
+
+        /// Units of the asset charged per second of XCM execution, if the asset can pay fees
+        pub units_per_second: Option<u128>,
+
+        /// Whether the asset is frozen
+        pub is_frozen: bool,
+    }
+
     /// Pallet Configuration
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -87,7 +216,7 @@ pub mod pallet {
             + Copy;
 
         /// Balance Type
-        type Balance: Default + Member + Parameter + TypeInfo;
+        type Balance: AtLeast32BitUnsigned + Copy + Default + Member + Parameter + TypeInfo;
 
         /// Location Type
         type Location: Default
@@ -111,6 +240,31 @@ pub mod pallet {
         /// Pallet ID
         type PalletId: Get<PalletId>;
 
+        /// Maximum number of alias locations that can be accepted for a single asset.
+        #[pallet::constant]
+        type MaxAliasLocations: Get<u32>;
+
+        /// The currency used to reserve asset registration deposits.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The deposit reserved from the proposer of a permissionless asset registration.
+        #[pallet::constant]
+        type RegistrationDeposit: Get<DepositBalanceOf<Self>>;
+
+        /// Handler for the deposits slashed from rejected asset registrations.
+        type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+        /// The origin which may feed the relative prices of assets.
+        type PriceFeederOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Units of the native token charged per second of XCM execution, from which the
+        /// units-per-second of assets with a relative price are derived.
+        type NativeUnitsPerSecond: Get<u128>;
+
+        /// Number of blocks after which a relative price that was not fed again is stale.
+        #[pallet::constant]
+        type PriceStalenessThreshold: Get<Self::BlockNumber>;
+
         /// Weight information for the extrinsics in this pallet.
         type WeightInfo: crate::weights::WeightInfo;
     }
@@ -144,19 +298,92 @@ pub mod pallet {
             AssetIdLocation::<T>::get(asset_id)
         }
 
+        /// Resolves both canonical and alias locations, so inbound transfers using an alias are
+        /// accepted.
         #[inline]
         fn asset_id(location: &Self::Location) -> Option<Self::AssetId> {
-            LocationAssetId::<T>::get(location)
+            LocationAssetId::<T>::get(location).or_else(|| AliasLocationAssetId::<T>::get(location))
         }
     }
 
+    /// Assets with [`RelativePriceBounds`] are priced from their relative price, and cannot pay
+    /// for XCM execution while that price is missing or stale. Other assets use the static
+    /// [`UnitsPerSecond`].
     impl<T> assets::UnitsPerSecond for Pallet<T>
     where
         T: Config,
     {
         #[inline]
         fn units_per_second(id: &Self::AssetId) -> Option<u128> {
-            UnitsPerSecond::<T>::get(id)
+            if PriceBounds::<T>::contains_key(id) {
+                Self::relative_units_per_second(id)
+            } else {
+                UnitsPerSecond::<T>::get(id)
+            }
+        }
+    }
+
+    impl<T> assets::FrozenAssets for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn is_frozen(asset_id: &Self::AssetId) -> bool {
+            FrozenAssets::<T>::contains_key(asset_id)
+        }
+    }
+
+    impl<T> BalanceType for Pallet<T>
+    where
+        T: Config,
+    {
+        type Balance = T::Balance;
+    }
+
+    /// Withdrawals that would push the outbound flow of an asset above its limit are rejected.
+    impl<T> assets::OutboundRateLimits for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn is_within_limit(asset_id: &Self::AssetId, amount: &Self::Balance) -> bool {
+            match OutboundRateLimits::<T>::get(asset_id) {
+                Some(rate_limit) => Self::current_outbound_flow(asset_id, &rate_limit)
+                    .amount()
+                    .checked_add(amount)
+                    .map(|flow| flow <= rate_limit.limit)
+                    .unwrap_or(false),
+                None => true,
+            }
+        }
+
+        #[inline]
+        fn note_outbound(asset_id: &Self::AssetId, amount: &Self::Balance) {
+            if let Some(rate_limit) = OutboundRateLimits::<T>::get(asset_id) {
+                let mut flow = Self::current_outbound_flow(asset_id, &rate_limit);
+                let slot = Self::outbound_flow_slot(flow.bucket);
+                flow.buckets[slot] = flow.buckets[slot].saturating_add(*amount);
+                OutboundFlows::<T>::insert(asset_id, flow);
+            }
+        }
+
+        #[inline]
+        fn note_rejected(asset_id: &Self::AssetId, amount: &Self::Balance) {
+            Self::deposit_event(Event::<T>::OutboundTransferRejected {
+                asset_id: *asset_id,
+                amount: *amount,
+            });
+        }
+    }
+
+    /// Assets stay restricted while their permissionless registration is pending.
+    impl<T> assets::RestrictedAssets for Pallet<T>
+    where
+        T: Config,
+    {
+        #[inline]
+        fn is_restricted(asset_id: &Self::AssetId) -> bool {
+            PendingAssetRegistrations::<T>::contains_key(asset_id)
         }
     }
 
@@ -164,6 +391,13 @@ pub mod pallet {
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub start_id: T::AssetId,
+
+        /// Assets registered at genesis with the next asset ids.
+        ///
+        /// XCM locations have no `serde` support, so the assets are serialized as the hex encoded
+        /// SCALE encoding of the list.
+        #[serde(with = "manta_primitives::scale_hex")]
+        pub assets: Vec<(T::Location, crate::AssetRegistryMetadataOf<T>)>,
     }
 
     #[cfg(feature = "std")]
@@ -172,6 +406,7 @@ pub mod pallet {
         fn default() -> Self {
             Self {
                 start_id: <T::AssetConfig as AssetConfig<T>>::StartNonNativeAssetId::get(),
+                assets: Vec::new(),
             }
         }
     }
@@ -187,6 +422,10 @@ pub mod pallet {
             AssetIdLocation::<T>::insert(asset_id, &location);
             AssetIdMetadata::<T>::insert(asset_id, &metadata);
             LocationAssetId::<T>::insert(&location, asset_id);
+            for (location, metadata) in &self.assets {
+                Pallet::<T>::do_register_asset(location.clone(), metadata.clone())
+                    .expect("Unable to register genesis asset.");
+            }
         }
     }
 
@@ -253,6 +492,147 @@ pub mod pallet {
             /// Updated Minimum XCM Fee
             min_xcm_fee: u128,
         },
+
+        /// An asset was frozen
+        AssetFrozen {
+            /// Asset Id of the frozen Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An asset was thawed
+        AssetThawed {
+            /// Asset Id of the thawed Asset
+            asset_id: T::AssetId,
+        },
+
+        /// The balance of an account in a frozen asset was burned
+        AssetForceBurned {
+            /// Asset Id of the burned Asset
+            asset_id: T::AssetId,
+
+            /// Account whose balance was burned
+            who: T::AccountId,
+
+            /// Amount Burned
+            amount: T::Balance,
+        },
+
+        /// An asset was deregistered
+        AssetDeregistered {
+            /// Asset Id of the deregistered Asset
+            asset_id: T::AssetId,
+
+            /// Location the Asset was registered at
+            location: T::Location,
+        },
+
+        /// An alias location was added to an asset
+        AliasLocationAdded {
+            /// Asset Id of the updated Asset
+            asset_id: T::AssetId,
+
+            /// Accepted Alias Location
+            location: T::Location,
+        },
+
+        /// An alias location was removed from an asset
+        AliasLocationRemoved {
+            /// Asset Id of the updated Asset
+            asset_id: T::AssetId,
+
+            /// Removed Alias Location
+            location: T::Location,
+        },
+
+        /// A permissionless asset registration was proposed
+        AssetRegistrationProposed {
+            /// Asset Id of the proposed Asset
+            asset_id: T::AssetId,
+
+            /// Account which proposed the registration
+            proposer: T::AccountId,
+
+            /// Deposit reserved from the proposer
+            deposit: DepositBalanceOf<T>,
+        },
+
+        /// A proposed asset registration was approved and its deposit refunded
+        AssetRegistrationApproved {
+            /// Asset Id of the approved Asset
+            asset_id: T::AssetId,
+        },
+
+        /// A proposed asset registration was rejected and its deposit slashed
+        AssetRegistrationRejected {
+            /// Asset Id of the rejected Asset
+            asset_id: T::AssetId,
+
+            /// Amount Slashed
+            slashed: DepositBalanceOf<T>,
+        },
+
+        /// The outbound XCM transfer limit of an asset was set
+        OutboundRateLimitSet {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+
+            /// Maximum amount that can leave the chain within `period`
+            limit: T::Balance,
+
+            /// Length of the sliding window in blocks
+            period: T::BlockNumber,
+        },
+
+        /// The outbound XCM transfer limit of an asset was removed
+        OutboundRateLimitRemoved {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+        },
+
+        /// An XCM withdrawal of an asset was rejected because it exceeds its outbound limit
+        ///
+        /// Only emitted for XCM executed from incoming messages, since a failing local transfer
+        /// extrinsic reverts this event together with the rest of its changes.
+        OutboundTransferRejected {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+
+            /// Amount Rejected
+            amount: T::Balance,
+        },
+
+        /// The relative price bounds of an asset were set
+        RelativePriceBoundsSet {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+
+            /// Lowest price that can be fed
+            min: FixedU128,
+
+            /// Highest price that can be fed
+            max: FixedU128,
+
+            /// Largest change of the price allowed in a single update
+            max_change: Perbill,
+        },
+
+        /// The relative price bounds of an asset were removed
+        RelativePriceBoundsRemoved {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+        },
+
+        /// The relative price of an asset was fed
+        RelativePriceFed {
+            /// Asset Id of the Asset
+            asset_id: T::AssetId,
+
+            /// Units of the asset per unit of the native token
+            price: FixedU128,
+
+            /// Units-per-second derived from the price
+            units_per_second: u128,
+        },
     }
 
     /// Asset Manager Error
@@ -278,6 +658,54 @@ pub mod pallet {
 
         /// An error occurred while updating the parachain id.
         UpdateParaIdError,
+
+        /// Cannot Freeze Native Asset
+        CannotFreezeNativeAsset,
+
+        /// Asset Already Frozen
+        AssetAlreadyFrozen,
+
+        /// The asset needs to be frozen for this operation.
+        AssetNotFrozen,
+
+        /// An error occurred while burning an asset.
+        BurnError,
+
+        /// The asset still has a non-zero supply and cannot be deregistered.
+        AssetSupplyNotZero,
+
+        /// The asset already has the maximum number of alias locations.
+        TooManyAliasLocations,
+
+        /// The location is not an alias of the asset.
+        AliasLocationNotFound,
+
+        /// Permissionlessly proposed assets cannot be sufficient.
+        SufficientAssetProposal,
+
+        /// There is no pending registration for the asset.
+        NoPendingRegistration,
+
+        /// The period of an outbound rate limit must not be zero.
+        ZeroRateLimitPeriod,
+
+        /// There is no outbound rate limit for the asset.
+        NoOutboundRateLimit,
+
+        /// Cannot Price Native Asset
+        CannotPriceNativeAsset,
+
+        /// The minimum of the relative price bounds must be positive and not above the maximum.
+        InvalidPriceBounds,
+
+        /// There are no relative price bounds for the asset.
+        NoRelativePriceBounds,
+
+        /// The relative price is outside of the bounds of the asset.
+        PriceOutOfBounds,
+
+        /// The relative price changed by more than allowed in a single update.
+        PriceChangeTooLarge,
     }
 
     /// [`AssetId`](AssetConfig::AssetId) to [`MultiLocation`] Map
@@ -325,6 +753,84 @@ pub mod pallet {
     #[pallet::getter(fn get_para_id)]
     pub type AllowedDestParaIds<T: Config> = StorageMap<_, Blake2_128Concat, ParaId, AssetCount>;
 
+    /// Assets that are frozen and cannot be deposited, withdrawn or transferred.
+    ///
+    /// Deregistered assets stay frozen so that the leftover asset can never be used again.
+    #[pallet::storage]
+    pub type FrozenAssets<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, ()>;
+
+    /// Alias locations accepted for each [`AssetId`](AssetConfig::AssetId) on top of its
+    /// canonical location in [`AssetIdLocation`].
+    #[pallet::storage]
+    #[pallet::getter(fn alias_locations)]
+    pub type AliasLocations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        BoundedVec<T::Location, T::MaxAliasLocations>,
+        ValueQuery,
+    >;
+
+    /// Pending permissionless registrations for each [`AssetId`](AssetConfig::AssetId)
+    ///
+    /// Proposed assets are only created and mapped to their location once governance approves
+    /// them, so a pending proposal can neither receive assets over XCM nor claim its location.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_asset_registration)]
+    pub type PendingAssetRegistrations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        AssetRegistrationProposal<
+            T::AccountId,
+            DepositBalanceOf<T>,
+            T::Location,
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        >,
+    >;
+
+    /// Alias [`MultiLocation`] to [`AssetId`](AssetConfig::AssetId) Map
+    ///
+    /// This is only used when receiving an asset from a foreign location.
+    #[pallet::storage]
+    #[pallet::getter(fn alias_location_asset_id)]
+    pub type AliasLocationAssetId<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::Location, T::AssetId>;
+
+    /// Outbound XCM transfer limit for each [`AssetId`](AssetConfig::AssetId)
+    ///
+    /// Assets without a limit can be transferred out of the chain without restriction.
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_rate_limit)]
+    pub type OutboundRateLimits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, OutboundRateLimit<T::Balance, T::BlockNumber>>;
+
+    /// Outbound XCM transfer flow for each rate-limited [`AssetId`](AssetConfig::AssetId)
+    #[pallet::storage]
+    #[pallet::getter(fn outbound_flow)]
+    pub type OutboundFlows<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AssetId,
+        OutboundFlow<T::Balance, T::BlockNumber>,
+        ValueQuery,
+    >;
+
+    /// Relative price bounds for each [`AssetId`](AssetConfig::AssetId) priced relative to the
+    /// native token
+    ///
+    /// The [`UnitsPerSecond`] of assets with bounds is ignored.
+    #[pallet::storage]
+    #[pallet::getter(fn relative_price_bounds)]
+    pub type PriceBounds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, RelativePriceBounds>;
+
+    /// Last fed relative price for each [`AssetId`](AssetConfig::AssetId)
+    #[pallet::storage]
+    #[pallet::getter(fn relative_price)]
+    pub type RelativePrices<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, RelativePrice<T::BlockNumber>>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Register a new asset in the asset manager.
@@ -344,35 +850,7 @@ pub mod pallet {
             metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
         ) -> DispatchResult {
             T::ModifierOrigin::ensure_origin(origin)?;
-            ensure!(
-                !LocationAssetId::<T>::contains_key(&location),
-                Error::<T>::LocationAlreadyExists
-            );
-            let asset_id = Self::next_asset_id_and_increment()?;
-            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::create_asset(
-                asset_id,
-                metadata.clone().into(),
-                metadata.min_balance().clone(),
-                metadata.is_sufficient(),
-            )
-            .map_err(|_| Error::<T>::ErrorCreatingAsset)?;
-            AssetIdLocation::<T>::insert(asset_id, &location);
-            AssetIdMetadata::<T>::insert(asset_id, &metadata);
-            LocationAssetId::<T>::insert(&location, asset_id);
-
-            // If it's a new para id, which will be inserted with AssetCount as 1.
-            // If not, AssetCount will increased by 1.
-            if let Some(para_id) =
-                Self::para_id_from_multilocation(location.clone().into().as_ref())
-            {
-                Self::increase_count_of_associated_assets(*para_id)?;
-            }
-
-            Self::deposit_event(Event::<T>::AssetRegistered {
-                asset_id,
-                location,
-                metadata,
-            });
+            Self::do_register_asset(location, metadata)?;
             Ok(())
         }
 
@@ -399,6 +877,14 @@ pub mod pallet {
                 !LocationAssetId::<T>::contains_key(&location),
                 Error::<T>::LocationAlreadyExists
             );
+            // an alias of this asset can be promoted to its canonical location.
+            match AliasLocationAssetId::<T>::get(&location) {
+                Some(alias_asset_id) if alias_asset_id == asset_id => {
+                    Self::remove_alias(asset_id, &location)?;
+                }
+                Some(_) => return Err(Error::<T>::LocationAlreadyExists.into()),
+                None => {}
+            }
             // change the ledger state.
             let old_location =
                 AssetIdLocation::<T>::get(asset_id).ok_or(Error::<T>::UpdateNonExistentAsset)?;
@@ -412,17 +898,7 @@ pub mod pallet {
             if let Some(old_para_id) =
                 Self::para_id_from_multilocation(old_location.into().as_ref())
             {
-                if AllowedDestParaIds::<T>::get(old_para_id) <= Some(<AssetCount as One>::one()) {
-                    AllowedDestParaIds::<T>::remove(old_para_id);
-                } else {
-                    AllowedDestParaIds::<T>::try_mutate(old_para_id, |cnt| -> DispatchResult {
-                        let new_cnt = cnt
-                            .map(|c| c - <AssetCount as One>::one())
-                            .ok_or(Error::<T>::UpdateParaIdError)?;
-                        *cnt = Some(new_cnt);
-                        Ok(())
-                    })?;
-                }
+                Self::decrease_count_of_associated_assets(*old_para_id)?;
             }
 
             // If it's a new para id, which will be inserted with AssetCount as 1.
@@ -551,25 +1027,617 @@ pub mod pallet {
             });
             Ok(())
         }
-    }
-
-    impl<T> Pallet<T>
-    where
-        T: Config,
-    {
-        /// Returns and increments the [`NextAssetId`] by one.
-        #[inline]
-        fn next_asset_id_and_increment() -> Result<T::AssetId, DispatchError> {
-            NextAssetId::<T>::try_mutate(|current| {
-                let id = *current;
-                *current = current
-                    .checked_add(&One::one())
-                    .ok_or(ArithmeticError::Overflow)?;
-                Ok(id)
-            })
-        }
 
-        /// Returns the account identifier of the [`AssetManager`] pallet.
+        /// Freeze an asset so that it can no longer be transferred, deposited or withdrawn,
+        /// including over XCM and into or out of MantaPay.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be frozen.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::freeze_asset())]
+        #[transactional]
+        pub fn freeze_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                asset_id != <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                Error::<T>::CannotFreezeNativeAsset
+            );
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                !FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetAlreadyFrozen
+            );
+            FrozenAssets::<T>::insert(asset_id, ());
+            Self::deposit_event(Event::<T>::AssetFrozen { asset_id });
+            Ok(())
+        }
+
+        /// Thaw a frozen asset so that it can be used again.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be thawed.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::thaw_asset())]
+        #[transactional]
+        pub fn thaw_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+            FrozenAssets::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::AssetThawed { asset_id });
+            Ok(())
+        }
+
+        /// Burn the entire balance of `who` in a frozen asset, so that the asset supply can be
+        /// brought down to zero before it is deregistered.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: Frozen AssetId to be burned.
+        /// * `who`: Account whose balance is burned.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::force_burn_asset())]
+        #[transactional]
+        pub fn force_burn_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            who: T::AccountId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+            let amount =
+                <T::AssetConfig as AssetConfig<T>>::FungibleLedger::force_burn(asset_id, &who)
+                    .map_err(|_| Error::<T>::BurnError)?;
+            Self::deposit_event(Event::<T>::AssetForceBurned {
+                asset_id,
+                who,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// Deregister a frozen asset whose supply is zero, removing its location and
+        /// units-per-second. The asset stays frozen so it can never be used again.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be deregistered.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::deregister_asset(T::MaxAliasLocations::get()))]
+        #[transactional]
+        pub fn deregister_asset(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let location =
+                AssetIdLocation::<T>::get(asset_id).ok_or(Error::<T>::UpdateNonExistentAsset)?;
+            ensure!(
+                FrozenAssets::<T>::contains_key(asset_id),
+                Error::<T>::AssetNotFrozen
+            );
+            ensure!(
+                <T::AssetConfig as AssetConfig<T>>::FungibleLedger::supply(asset_id)
+                    == Default::default(),
+                Error::<T>::AssetSupplyNotZero
+            );
+            Self::remove_asset_locations(asset_id, &location)?;
+            Self::deposit_event(Event::<T>::AssetDeregistered { asset_id, location });
+            Ok(())
+        }
+
+        /// Accept `location` as an alias of `asset_id` for inbound transfers. Outbound transfers
+        /// keep using the canonical location of the asset.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        /// * `location`: Alias location to accept for `asset_id`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::add_alias_location())]
+        #[transactional]
+        pub fn add_alias_location(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            location: T::Location,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                !LocationAssetId::<T>::contains_key(&location)
+                    && !AliasLocationAssetId::<T>::contains_key(&location),
+                Error::<T>::LocationAlreadyExists
+            );
+            AliasLocations::<T>::try_mutate(asset_id, |aliases| {
+                aliases
+                    .try_push(location.clone())
+                    .map_err(|_| Error::<T>::TooManyAliasLocations)
+            })?;
+            AliasLocationAssetId::<T>::insert(&location, asset_id);
+            Self::deposit_event(Event::<T>::AliasLocationAdded { asset_id, location });
+            Ok(())
+        }
+
+        /// Stop accepting `location` as an alias of `asset_id`.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        /// * `location`: Alias location to remove from `asset_id`.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_alias_location())]
+        #[transactional]
+        pub fn remove_alias_location(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            location: T::Location,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            Self::remove_alias(asset_id, &location)?;
+            Self::deposit_event(Event::<T>::AliasLocationRemoved { asset_id, location });
+            Ok(())
+        }
+
+        /// Permissionlessly propose a new asset, reserving [`Config::RegistrationDeposit`] from
+        /// the caller. The proposal only reserves an asset id: the asset is created and mapped to
+        /// `location` once governance approves it, and it can never be sufficient.
+        ///
+        /// * `origin`: Caller of this extrinsic, who pays the registration deposit.
+        /// * `location`: Location of the asset.
+        /// * `metadata`: Asset metadata, which must not be sufficient.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::propose_asset_registration())]
+        #[transactional]
+        pub fn propose_asset_registration(
+            origin: OriginFor<T>,
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> DispatchResult {
+            let proposer = ensure_signed(origin)?;
+            ensure!(
+                !metadata.is_sufficient(),
+                Error::<T>::SufficientAssetProposal
+            );
+            Self::ensure_location_is_free(&location)?;
+            let deposit = T::RegistrationDeposit::get();
+            T::Currency::reserve(&proposer, deposit)?;
+            let asset_id = Self::next_asset_id_and_increment()?;
+            PendingAssetRegistrations::<T>::insert(
+                asset_id,
+                AssetRegistrationProposal {
+                    proposer: proposer.clone(),
+                    deposit,
+                    location,
+                    metadata,
+                },
+            );
+            Self::deposit_event(Event::<T>::AssetRegistrationProposed {
+                asset_id,
+                proposer,
+                deposit,
+            });
+            Ok(())
+        }
+
+        /// Approve a pending asset registration, creating the asset at its proposed location and
+        /// refunding the deposit to the proposer. Fails if the location was registered since.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId of the pending registration.
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::approve_asset_registration())]
+        #[transactional]
+        pub fn approve_asset_registration(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let proposal = PendingAssetRegistrations::<T>::take(asset_id)
+                .ok_or(Error::<T>::NoPendingRegistration)?;
+            Self::do_create_asset(asset_id, proposal.location, proposal.metadata)?;
+            T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+            Self::deposit_event(Event::<T>::AssetRegistrationApproved { asset_id });
+            Ok(())
+        }
+
+        /// Reject a pending asset registration, slashing the deposit of the proposer. The asset
+        /// is never created and its id is never reused.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId of the pending registration.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::reject_asset_registration())]
+        #[transactional]
+        pub fn reject_asset_registration(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            let proposal = PendingAssetRegistrations::<T>::take(asset_id)
+                .ok_or(Error::<T>::NoPendingRegistration)?;
+            let (imbalance, _) = T::Currency::slash_reserved(&proposal.proposer, proposal.deposit);
+            let slashed = imbalance.peek();
+            T::Slash::on_unbalanced(imbalance);
+            Self::deposit_event(Event::<T>::AssetRegistrationRejected { asset_id, slashed });
+            Ok(())
+        }
+
+        /// Limit the amount of an asset that can leave the chain through XCM over a sliding
+        /// window of `period` blocks. Withdrawals above the limit are rejected by the XCM asset
+        /// transactor, which fails the whole transfer.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be limited.
+        /// * `limit`: Maximum amount that can leave the chain within `period`.
+        /// * `period`: Length of the sliding window in blocks.
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::set_outbound_rate_limit())]
+        #[transactional]
+        pub fn set_outbound_rate_limit(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            limit: T::Balance,
+            period: T::BlockNumber,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(!period.is_zero(), Error::<T>::ZeroRateLimitPeriod);
+            // Keep the flow accrued under the previous limit. The buckets only line up with the
+            // same period, otherwise the whole flow moves into the current bucket of the new one.
+            if let Some(rate_limit) = OutboundRateLimits::<T>::get(asset_id) {
+                if rate_limit.period != period {
+                    let mut flow = OutboundFlow {
+                        bucket: frame_system::Pallet::<T>::block_number()
+                            / Self::outbound_flow_bucket_length(period),
+                        ..Default::default()
+                    };
+                    flow.buckets[Self::outbound_flow_slot(flow.bucket)] =
+                        Self::current_outbound_flow(&asset_id, &rate_limit).amount();
+                    OutboundFlows::<T>::insert(asset_id, flow);
+                }
+            }
+            OutboundRateLimits::<T>::insert(asset_id, OutboundRateLimit { limit, period });
+            Self::deposit_event(Event::<T>::OutboundRateLimitSet {
+                asset_id,
+                limit,
+                period,
+            });
+            Ok(())
+        }
+
+        /// Remove the outbound XCM transfer limit of an asset.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be unlimited.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::remove_outbound_rate_limit())]
+        #[transactional]
+        pub fn remove_outbound_rate_limit(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                OutboundRateLimits::<T>::take(asset_id).is_some(),
+                Error::<T>::NoOutboundRateLimit
+            );
+            OutboundFlows::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::OutboundRateLimitRemoved { asset_id });
+            Ok(())
+        }
+
+        /// Price an asset relative to the native token, within bounds. From then on its
+        /// units-per-second is derived from the price fed by [`Config::PriceFeederOrigin`]
+        /// instead of the static [`UnitsPerSecond`]. A previously fed price outside of the new
+        /// bounds is discarded.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be priced.
+        /// * `min`: Lowest price that can be fed.
+        /// * `max`: Highest price that can be fed.
+        /// * `max_change`: Largest change of the price allowed in a single update.
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::set_relative_price_bounds())]
+        #[transactional]
+        pub fn set_relative_price_bounds(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            min: FixedU128,
+            max: FixedU128,
+            max_change: Perbill,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                asset_id != <T::AssetConfig as AssetConfig<T>>::NativeAssetId::get(),
+                Error::<T>::CannotPriceNativeAsset
+            );
+            ensure!(
+                AssetIdLocation::<T>::contains_key(asset_id),
+                Error::<T>::UpdateNonExistentAsset
+            );
+            ensure!(
+                min.into_inner() > 0 && min <= max,
+                Error::<T>::InvalidPriceBounds
+            );
+            if let Some(relative_price) = RelativePrices::<T>::get(asset_id) {
+                if relative_price.price < min || relative_price.price > max {
+                    RelativePrices::<T>::remove(asset_id);
+                }
+            }
+            PriceBounds::<T>::insert(
+                asset_id,
+                RelativePriceBounds {
+                    min,
+                    max,
+                    max_change,
+                },
+            );
+            Self::deposit_event(Event::<T>::RelativePriceBoundsSet {
+                asset_id,
+                min,
+                max,
+                max_change,
+            });
+            Ok(())
+        }
+
+        /// Stop pricing an asset relative to the native token, falling back to its static
+        /// [`UnitsPerSecond`].
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by `ForceOrigin`.
+        /// * `asset_id`: AssetId to be updated.
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::remove_relative_price_bounds())]
+        #[transactional]
+        pub fn remove_relative_price_bounds(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+        ) -> DispatchResult {
+            T::ModifierOrigin::ensure_origin(origin)?;
+            ensure!(
+                PriceBounds::<T>::take(asset_id).is_some(),
+                Error::<T>::NoRelativePriceBounds
+            );
+            RelativePrices::<T>::remove(asset_id);
+            Self::deposit_event(Event::<T>::RelativePriceBoundsRemoved { asset_id });
+            Ok(())
+        }
+
+        /// Feed the price of an asset relative to the native token. The price must be within the
+        /// bounds of the asset and, unless it is the first price or the previous price is stale,
+        /// must not move away from the previous price by more than the allowed change.
+        ///
+        /// * `origin`: Caller of this extrinsic, the access control is specified by
+        ///   `PriceFeederOrigin`.
+        /// * `asset_id`: AssetId to be priced.
+        /// * `price`: Units of the asset per unit of the native token.
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::feed_relative_price())]
+        #[transactional]
+        pub fn feed_relative_price(
+            origin: OriginFor<T>,
+            #[pallet::compact] asset_id: T::AssetId,
+            price: FixedU128,
+        ) -> DispatchResult {
+            T::PriceFeederOrigin::ensure_origin(origin)?;
+            let bounds =
+                PriceBounds::<T>::get(asset_id).ok_or(Error::<T>::NoRelativePriceBounds)?;
+            ensure!(
+                bounds.min <= price && price <= bounds.max,
+                Error::<T>::PriceOutOfBounds
+            );
+            // NOTE: A stale price is not used for fees anymore, so the price may have moved
+            //       arbitrarily far from it in the meantime.
+            if let Some(previous) =
+                RelativePrices::<T>::get(asset_id).filter(|previous| !Self::is_stale(previous))
+            {
+                let change = if price > previous.price {
+                    price.saturating_sub(previous.price)
+                } else {
+                    previous.price.saturating_sub(price)
+                };
+                ensure!(
+                    change <= previous.price.saturating_mul(bounds.max_change.into()),
+                    Error::<T>::PriceChangeTooLarge
+                );
+            }
+            RelativePrices::<T>::insert(
+                asset_id,
+                RelativePrice {
+                    price,
+                    updated_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::<T>::RelativePriceFed {
+                asset_id,
+                price,
+                units_per_second: price.saturating_mul_int(T::NativeUnitsPerSecond::get()),
+            });
+            Ok(())
+        }
+    }
+
+    impl<T> Pallet<T>
+    where
+        T: Config,
+    {
+        /// Returns and increments the [`NextAssetId`] by one.
+        #[inline]
+        fn next_asset_id_and_increment() -> Result<T::AssetId, DispatchError> {
+            NextAssetId::<T>::try_mutate(|current| {
+                let id = *current;
+                *current = current
+                    .checked_add(&One::one())
+                    .ok_or(ArithmeticError::Overflow)?;
+                Ok(id)
+            })
+        }
+
+        /// Registers a new asset at `location` with `metadata`, returning its asset id.
+        fn do_register_asset(
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> Result<T::AssetId, DispatchError> {
+            Self::ensure_location_is_free(&location)?;
+            let asset_id = Self::next_asset_id_and_increment()?;
+            Self::do_create_asset(asset_id, location, metadata)?;
+            Ok(asset_id)
+        }
+
+        /// Fails if `location` is already the canonical or an alias location of an asset.
+        fn ensure_location_is_free(location: &T::Location) -> DispatchResult {
+            ensure!(
+                !LocationAssetId::<T>::contains_key(location)
+                    && !AliasLocationAssetId::<T>::contains_key(location),
+                Error::<T>::LocationAlreadyExists
+            );
+            Ok(())
+        }
+
+        /// Creates `asset_id` in the asset registry and maps it to `location`.
+        fn do_create_asset(
+            asset_id: T::AssetId,
+            location: T::Location,
+            metadata: <T::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata,
+        ) -> DispatchResult {
+            Self::ensure_location_is_free(&location)?;
+            <T::AssetConfig as AssetConfig<T>>::AssetRegistry::create_asset(
+                asset_id,
+                metadata.clone().into(),
+                metadata.min_balance().clone(),
+                metadata.is_sufficient(),
+            )
+            .map_err(|_| Error::<T>::ErrorCreatingAsset)?;
+            AssetIdLocation::<T>::insert(asset_id, &location);
+            AssetIdMetadata::<T>::insert(asset_id, &metadata);
+            LocationAssetId::<T>::insert(&location, asset_id);
+
+            // If it's a new para id, which will be inserted with AssetCount as 1.
+            // If not, AssetCount will increased by 1.
+            if let Some(para_id) =
+                Self::para_id_from_multilocation(location.clone().into().as_ref())
+            {
+                Self::increase_count_of_associated_assets(*para_id)?;
+            }
+
+            Self::deposit_event(Event::<T>::AssetRegistered {
+                asset_id,
+                location,
+                metadata,
+            });
+            Ok(())
+        }
+
+        /// Removes the canonical `location`, the alias locations and the units-per-second of
+        /// `asset_id`, so that it can no longer be sent or received.
+        fn remove_asset_locations(asset_id: T::AssetId, location: &T::Location) -> DispatchResult {
+            AssetIdLocation::<T>::remove(asset_id);
+            LocationAssetId::<T>::remove(location);
+            UnitsPerSecond::<T>::remove(asset_id);
+            PriceBounds::<T>::remove(asset_id);
+            RelativePrices::<T>::remove(asset_id);
+            for alias in AliasLocations::<T>::take(asset_id) {
+                AliasLocationAssetId::<T>::remove(&alias);
+            }
+            if let Some(para_id) =
+                Self::para_id_from_multilocation(location.clone().into().as_ref())
+            {
+                Self::decrease_count_of_associated_assets(*para_id)?;
+            }
+            Ok(())
+        }
+
+        /// Returns the length in blocks of each of the [`OUTBOUND_FLOW_BUCKETS`] buckets of a
+        /// window of `period` blocks, rounded up so that the buckets span the whole window.
+        #[inline]
+        fn outbound_flow_bucket_length(period: T::BlockNumber) -> T::BlockNumber {
+            let buckets = T::BlockNumber::from(OUTBOUND_FLOW_BUCKETS);
+            let length = period / buckets;
+            if (period % buckets).is_zero() {
+                length.max(One::one())
+            } else {
+                length.saturating_add(One::one())
+            }
+        }
+
+        /// Returns the index of `bucket` in [`OutboundFlow::buckets`].
+        #[inline]
+        fn outbound_flow_slot(bucket: T::BlockNumber) -> usize {
+            (bucket % T::BlockNumber::from(OUTBOUND_FLOW_BUCKETS + 1))
+                .try_into()
+                .unwrap_or_default()
+        }
+
+        /// Returns the outbound flow of `asset_id` moved to the bucket of the current block, with
+        /// the buckets that left the window of `rate_limit` cleared.
+        fn current_outbound_flow(
+            asset_id: &T::AssetId,
+            rate_limit: &OutboundRateLimit<T::Balance, T::BlockNumber>,
+        ) -> OutboundFlow<T::Balance, T::BlockNumber> {
+            let mut flow = OutboundFlows::<T>::get(asset_id);
+            let bucket = frame_system::Pallet::<T>::block_number()
+                / Self::outbound_flow_bucket_length(rate_limit.period);
+            let slots = flow.buckets.len();
+            let elapsed = bucket
+                .saturating_sub(flow.bucket)
+                .try_into()
+                .unwrap_or(slots)
+                .min(slots);
+            let latest = Self::outbound_flow_slot(flow.bucket);
+            for offset in 1..=elapsed {
+                flow.buckets[(latest + offset) % slots] = Zero::zero();
+            }
+            flow.bucket = flow.bucket.max(bucket);
+            flow
+        }
+
+        /// Returns `true` if `relative_price` was fed more than
+        /// [`Config::PriceStalenessThreshold`] blocks ago.
+        #[inline]
+        fn is_stale(relative_price: &RelativePrice<T::BlockNumber>) -> bool {
+            frame_system::Pallet::<T>::block_number().saturating_sub(relative_price.updated_at)
+                > T::PriceStalenessThreshold::get()
+        }
+
+        /// Returns the units-per-second derived from the relative price of `asset_id`, or `None`
+        /// if no price was fed within [`Config::PriceStalenessThreshold`] blocks.
+        fn relative_units_per_second(asset_id: &T::AssetId) -> Option<u128> {
+            let relative_price = RelativePrices::<T>::get(asset_id)?;
+            if Self::is_stale(&relative_price) {
+                return None;
+            }
+            Some(
+                relative_price
+                    .price
+                    .saturating_mul_int(T::NativeUnitsPerSecond::get()),
+            )
+        }
+
+        /// Returns the account identifier of the [`AssetManager`] pallet.
         #[inline]
         pub fn account_id() -> T::AccountId {
             T::PalletId::get().into_account_truncating()
@@ -603,6 +1671,36 @@ pub mod pallet {
                 Ok(())
             }
         }
+
+        /// Removes `location` from the alias locations of `asset_id`.
+        fn remove_alias(asset_id: T::AssetId, location: &T::Location) -> DispatchResult {
+            ensure!(
+                AliasLocationAssetId::<T>::get(location) == Some(asset_id),
+                Error::<T>::AliasLocationNotFound
+            );
+            AliasLocations::<T>::mutate(asset_id, |aliases| {
+                aliases.retain(|alias| alias != location);
+            });
+            AliasLocationAssetId::<T>::remove(location);
+            Ok(())
+        }
+
+        /// Decreases the count of associated assets for the para id.
+        pub fn decrease_count_of_associated_assets(para_id: ParaId) -> DispatchResult {
+            // The para id will be deleted if AssetCount <= 1, or decreased by 1.
+            if AllowedDestParaIds::<T>::get(para_id) <= Some(<AssetCount as One>::one()) {
+                AllowedDestParaIds::<T>::remove(para_id);
+                Ok(())
+            } else {
+                AllowedDestParaIds::<T>::try_mutate(para_id, |count| -> DispatchResult {
+                    let new_count = count
+                        .map(|c| c - <AssetCount as One>::one())
+                        .ok_or(Error::<T>::UpdateParaIdError)?;
+                    *count = Some(new_count);
+                    Ok(())
+                })
+            }
+        }
     }
 
     /// Check the multilocation destination is supported by calamari/manta.
@@ -25,7 +25,7 @@ use frame_support::{
     construct_runtime,
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU32},
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32},
     PalletId,
 };
 use frame_system as system;
@@ -216,6 +216,9 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
     type FungibleLedger = NativeAndNonNative<Runtime, MantaAssetConfig, Balances, Assets>;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -226,6 +229,13 @@ impl pallet_asset_manager::Config for Runtime {
     type AssetConfig = MantaAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = ConstU128<1_000>;
+    type Slash = ();
+    type PriceFeederOrigin = EnsureRoot<AccountId>;
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000_000>;
+    type PriceStalenessThreshold = ConstU32<10>;
     type WeightInfo = ();
 }
 
@@ -253,6 +263,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         .unwrap();
     pallet_asset_manager::GenesisConfig::<Runtime> {
         start_id: <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get(),
+        assets: vec![],
     }
     .assimilate_storage(&mut t)
     .unwrap();
@@ -0,0 +1,96 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only views of the asset registry backing the `AssetManagerApi`
+
+use crate::pallet::{
+    AliasLocations, AssetIdLocation, AssetIdMetadata, AssetInfo, Config, FrozenAssets, MinXcmFee,
+    Pallet,
+};
+use manta_primitives::{
+    assets::{AssetConfig, UnitsPerSecond},
+    xcm::units_per_second_to_fee,
+};
+use orml_traits::location::Parse;
+use sp_std::vec::Vec;
+use xcm::latest::MultiLocation;
+
+/// Registry Metadata Type
+pub type AssetRegistryMetadataOf<T> =
+    <<T as Config>::AssetConfig as AssetConfig<T>>::AssetRegistryMetadata;
+
+/// Registered Asset Information Type
+pub type AssetInfoOf<T> =
+    AssetInfo<<T as Config>::AssetId, <T as Config>::Location, AssetRegistryMetadataOf<T>>;
+
+impl<T: Config> Pallet<T> {
+    /// Returns the registry information of `asset_id` if it has a registered location.
+    pub fn asset_info(asset_id: T::AssetId) -> Option<AssetInfoOf<T>> {
+        let location = AssetIdLocation::<T>::get(asset_id)?;
+        let metadata = AssetIdMetadata::<T>::get(asset_id)?;
+        Some(AssetInfo {
+            asset_id,
+            location,
+            alias_locations: AliasLocations::<T>::get(asset_id).into_inner(),
+            metadata,
+            units_per_second: Self::units_per_second(&asset_id),
+            is_frozen: FrozenAssets::<T>::contains_key(asset_id),
+        })
+    }
+
+    /// Returns the registry information of all assets with a registered location.
+    pub fn asset_infos() -> Vec<AssetInfoOf<T>> {
+        let mut assets = AssetIdLocation::<T>::iter_keys()
+            .filter_map(Self::asset_info)
+            .collect::<Vec<_>>();
+        assets.sort_by(|lhs, rhs| lhs.asset_id.cmp(&rhs.asset_id));
+        assets
+    }
+
+    /// Returns the fee charged in `asset_id` for executing `weight` of XCM, computed the same way
+    /// as the XCM trader. Returns `None` if `asset_id` cannot pay for XCM execution.
+    pub fn quote_weight_fee(asset_id: T::AssetId, weight: u64) -> Option<u128> {
+        if FrozenAssets::<T>::contains_key(asset_id) {
+            return None;
+        }
+        Self::units_per_second(&asset_id)
+            .map(|units_per_second| units_per_second_to_fee(units_per_second, weight))
+    }
+
+    /// Returns the fee in `asset_id` for an `orml_xtokens` transfer to `dest` which buys
+    /// `dest_weight` on the destination chain.
+    ///
+    /// When `dest` is not on the reserve chain of the asset, the transfer is routed through the
+    /// reserve chain and the [`MinXcmFee`] of the reserve is added on top of the weight fee.
+    /// Returns `None` if the asset cannot pay for XCM execution or has no [`MinXcmFee`] for
+    /// such a transfer.
+    pub fn quote_transfer_fee(
+        asset_id: T::AssetId,
+        dest: T::Location,
+        dest_weight: u64,
+    ) -> Option<u128> {
+        let fee = Self::quote_weight_fee(asset_id, dest_weight)?;
+        let reserve = Into::<Option<MultiLocation>>::into(AssetIdLocation::<T>::get(asset_id)?)?
+            .chain_part()?;
+        let dest = Into::<Option<MultiLocation>>::into(dest)?.chain_part()?;
+        if reserve == dest {
+            Some(fee)
+        } else {
+            MinXcmFee::<T>::get(T::Location::from(reserve))
+                .map(|min_xcm_fee| fee.saturating_add(min_xcm_fee))
+        }
+    }
+}
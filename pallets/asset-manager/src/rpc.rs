@@ -0,0 +1,187 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset Manager RPC Interfaces
+
+use crate::{runtime::AssetManagerApi, AssetInfo};
+use codec::{Codec, Decode, Encode};
+use core::marker::PhantomData;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::{generic::BlockId, traits::Block};
+use std::sync::Arc;
+
+/// Asset Manager Runtime API Error Code
+pub const ASSET_MANAGER_RUNTIME_API_ERROR: i32 = 1;
+
+/// Asset Manager Invalid Location Error Code
+pub const ASSET_MANAGER_INVALID_LOCATION_ERROR: i32 = 2;
+
+/// Registered Asset with SCALE-encoded Location and Metadata
+///
+/// XCM locations and registry metadata have no JSON representation, so they are returned in
+/// their SCALE encoding.
+pub type EncodedAssetInfo<AssetId> = AssetInfo<AssetId, Bytes, Bytes>;
+
+/// Asset Manager API
+///
+/// The `Location` and `Metadata` types are only used to decode the runtime API responses.
+#[rpc(server)]
+pub trait AssetManagerRpcApi<AssetId, Location, Metadata> {
+    /// Returns the registry information of `asset_id`.
+    #[method(name = "assetManager_asset", blocking)]
+    fn asset(&self, asset_id: AssetId) -> RpcResult<Option<EncodedAssetInfo<AssetId>>>;
+
+    /// Returns the registry information of all registered assets.
+    #[method(name = "assetManager_assets", blocking)]
+    fn assets(&self) -> RpcResult<Vec<EncodedAssetInfo<AssetId>>>;
+
+    /// Returns the fee charged in `asset_id` for executing `weight` of XCM.
+    #[method(name = "assetManager_quote_weight_fee", blocking)]
+    fn quote_weight_fee(&self, asset_id: AssetId, weight: u64) -> RpcResult<Option<u128>>;
+
+    /// Returns the fee in `asset_id` for an `orml_xtokens` transfer to the SCALE-encoded location
+    /// `dest` which buys `dest_weight` on the destination chain.
+    #[method(name = "assetManager_quote_transfer_fee", blocking)]
+    fn quote_transfer_fee(
+        &self,
+        asset_id: AssetId,
+        dest: Bytes,
+        dest_weight: u64,
+    ) -> RpcResult<Option<u128>>;
+}
+
+/// Asset Manager RPC API Implementation
+pub struct AssetManager<B, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Type Parameter Marker
+    __: PhantomData<B>,
+}
+
+impl<B, C> AssetManager<B, C> {
+    /// Builds a new [`AssetManager`] RPC API implementation.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            __: PhantomData,
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+#[inline]
+fn runtime_error<E>(message: &'static str, err: E) -> jsonrpsee::core::Error
+where
+    E: core::fmt::Debug,
+{
+    CallError::Custom(ErrorObject::owned(
+        ASSET_MANAGER_RUNTIME_API_ERROR,
+        message,
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
+
+/// Encodes the location and metadata of `info`.
+#[inline]
+fn encode_asset_info<AssetId, Location, Metadata>(
+    info: AssetInfo<AssetId, Location, Metadata>,
+) -> EncodedAssetInfo<AssetId>
+where
+    Location: Encode,
+    Metadata: Encode,
+{
+    AssetInfo {
+        asset_id: info.asset_id,
+        location: info.location.encode().into(),
+        alias_locations: info
+            .alias_locations
+            .into_iter()
+            .map(|location| location.encode().into())
+            .collect(),
+        metadata: info.metadata.encode().into(),
+        units_per_second: info.units_per_second,
+        is_frozen: info.is_frozen,
+    }
+}
+
+#[async_trait]
+impl<B, C, AssetId, Location, Metadata> AssetManagerRpcApiServer<AssetId, Location, Metadata>
+    for AssetManager<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: AssetManagerApi<B, AssetId, Location, Metadata>,
+    AssetId: 'static + Codec + DeserializeOwned + Send + Sync + Serialize,
+    Location: 'static + Codec + Send + Sync,
+    Metadata: 'static + Codec + Send + Sync,
+{
+    #[inline]
+    fn asset(&self, asset_id: AssetId) -> RpcResult<Option<EncodedAssetInfo<AssetId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.asset(&at, asset_id)
+            .map(|info| info.map(encode_asset_info))
+            .map_err(|err| runtime_error("Unable to query asset", err))
+    }
+
+    #[inline]
+    fn assets(&self) -> RpcResult<Vec<EncodedAssetInfo<AssetId>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.assets(&at)
+            .map(|infos| infos.into_iter().map(encode_asset_info).collect())
+            .map_err(|err| runtime_error("Unable to query assets", err))
+    }
+
+    #[inline]
+    fn quote_weight_fee(&self, asset_id: AssetId, weight: u64) -> RpcResult<Option<u128>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.quote_weight_fee(&at, asset_id, weight)
+            .map_err(|err| runtime_error("Unable to quote weight fee", err))
+    }
+
+    #[inline]
+    fn quote_transfer_fee(
+        &self,
+        asset_id: AssetId,
+        dest: Bytes,
+        dest_weight: u64,
+    ) -> RpcResult<Option<u128>> {
+        let dest = Location::decode(&mut &dest[..]).map_err(|err| {
+            CallError::Custom(ErrorObject::owned(
+                ASSET_MANAGER_INVALID_LOCATION_ERROR,
+                "Unable to decode destination location",
+                Some(format!("{err:?}")),
+            ))
+        })?;
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.quote_transfer_fee(&at, asset_id, dest, dest_weight)
+            .map_err(|err| runtime_error("Unable to quote transfer fee", err))
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Asset Manager Runtime APIs
+
+use crate::AssetInfo;
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait AssetManagerApi<AssetId, Location, Metadata>
+    where
+        AssetId: Codec,
+        Location: Codec,
+        Metadata: Codec,
+    {
+        fn asset(asset_id: AssetId) -> Option<AssetInfo<AssetId, Location, Metadata>>;
+        fn assets() -> Vec<AssetInfo<AssetId, Location, Metadata>>;
+        fn quote_weight_fee(asset_id: AssetId, weight: u64) -> Option<u128>;
+        fn quote_transfer_fee(asset_id: AssetId, dest: Location, dest_weight: u64) -> Option<u128>;
+    }
+}
@@ -22,12 +22,21 @@ use crate::{
 use asset_manager::mock::*;
 use frame_support::{
     assert_noop, assert_ok,
-    traits::{fungibles::InspectMetadata, Contains},
+    traits::{
+        fungibles::InspectMetadata, Contains, Currency, ExistenceRequirement, Get,
+        ReservableCurrency,
+    },
     WeakBoundedVec,
 };
-use manta_primitives::assets::{AssetConfig, AssetLocation, FungibleLedger};
+use manta_primitives::assets::{
+    AssetConfig, AssetIdLocationMap, AssetLocation, FungibleLedger, FungibleLedgerError,
+    OutboundRateLimits, RestrictedAssets, UnitsPerSecond as _,
+};
 use orml_traits::GetByKey;
-use sp_runtime::traits::BadOrigin;
+use sp_runtime::{
+    traits::{BadOrigin, One},
+    FixedU128, Perbill,
+};
 use xcm::{latest::prelude::*, VersionedMultiLocation};
 
 pub const ALICE: sp_runtime::AccountId32 = sp_runtime::AccountId32::new([0u8; 32]);
@@ -53,6 +62,36 @@ fn basic_setup_should_work() {
     });
 }
 
+#[test]
+fn genesis_assets_should_be_registered() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let genesis = asset_manager::GenesisConfig::<Runtime> {
+        start_id: <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get(),
+        assets: vec![(location.clone(), asset_metadata.clone())],
+    };
+    let genesis: asset_manager::GenesisConfig<Runtime> =
+        serde_json::from_str(&serde_json::to_string(&genesis).unwrap()).unwrap();
+    let mut storage = frame_system::GenesisConfig::default()
+        .build_storage::<Runtime>()
+        .unwrap();
+    frame_support::traits::GenesisBuild::<Runtime>::assimilate_storage(&genesis, &mut storage)
+        .unwrap();
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(location.clone())
+        );
+        assert_eq!(
+            AssetIdMetadata::<Runtime>::get(asset_id),
+            Some(asset_metadata)
+        );
+        assert_eq!(LocationAssetId::<Runtime>::get(location), Some(asset_id));
+        assert_eq!(crate::NextAssetId::<Runtime>::get(), asset_id + 1);
+    });
+}
+
 #[test]
 fn wrong_modifier_origin_should_not_work() {
     new_test_ext().execute_with(|| {
@@ -547,3 +586,892 @@ fn set_min_xcm_fee_should_work() {
         );
     })
 }
+
+#[test]
+fn freeze_and_thaw_asset_should_work() {
+    let asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    new_test_ext().execute_with(|| {
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            source_location,
+            asset_metadata
+        ));
+        assert_ok!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::deposit_minting(
+                asset_id, &ALICE, 1_000_000
+            )
+        );
+
+        // only the modifier origin can freeze, and the native asset can never be frozen.
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::signed([2u8; 32].into()), asset_id),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::root(), native_asset_id),
+            Error::<Runtime>::CannotFreezeNativeAsset
+        );
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id + 1),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::thaw_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetNotFrozen
+        );
+
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+        assert!(crate::FrozenAssets::<Runtime>::contains_key(asset_id));
+        assert_noop!(
+            AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetAlreadyFrozen
+        );
+
+        // a frozen asset cannot be minted, transferred or burned through the ledger.
+        assert_noop!(
+            AssetManager::mint_asset(RuntimeOrigin::root(), asset_id, ALICE, 100),
+            Error::<Runtime>::MintError
+        );
+        assert_eq!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::transfer(
+                asset_id,
+                &ALICE,
+                &[1u8; 32].into(),
+                100,
+                ExistenceRequirement::AllowDeath
+            ),
+            Err(FungibleLedgerError::FrozenAsset(asset_id))
+        );
+        assert_eq!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::withdraw_burning(
+                asset_id,
+                &ALICE,
+                100,
+                ExistenceRequirement::AllowDeath
+            ),
+            Err(FungibleLedgerError::FrozenAsset(asset_id))
+        );
+
+        assert_ok!(AssetManager::thaw_asset(RuntimeOrigin::root(), asset_id));
+        assert!(!crate::FrozenAssets::<Runtime>::contains_key(asset_id));
+        assert_ok!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::transfer(
+                asset_id,
+                &ALICE,
+                &[1u8; 32].into(),
+                100,
+                ExistenceRequirement::AllowDeath
+            )
+        );
+    });
+}
+
+#[test]
+fn deregister_asset_should_work() {
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(2023)),
+    )));
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            source_location.clone(),
+            asset_metadata
+        ));
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            125
+        ));
+        assert_ok!(AssetManager::mint_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            ALICE,
+            1_000_000
+        ));
+        assert_eq!(AssetManager::get_para_id(2023), Some(1));
+
+        // the asset must be frozen before it can be burned or deregistered.
+        assert_noop!(
+            AssetManager::force_burn_asset(RuntimeOrigin::root(), asset_id, ALICE),
+            Error::<Runtime>::AssetNotFrozen
+        );
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetNotFrozen
+        );
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+
+        // outstanding supply blocks deregistration until governance burns it.
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::AssetSupplyNotZero
+        );
+        assert_noop!(
+            AssetManager::force_burn_asset(
+                RuntimeOrigin::signed([2u8; 32].into()),
+                asset_id,
+                ALICE
+            ),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::force_burn_asset(
+            RuntimeOrigin::root(),
+            asset_id,
+            ALICE
+        ));
+        assert_eq!(
+            <MantaAssetConfig as AssetConfig<Runtime>>::FungibleLedger::supply(asset_id),
+            0
+        );
+
+        assert_ok!(AssetManager::deregister_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), None);
+        assert_eq!(LocationAssetId::<Runtime>::get(&source_location), None);
+        assert_eq!(UnitsPerSecond::<Runtime>::get(asset_id), None);
+        assert_eq!(AssetManager::get_para_id(2023), None);
+        assert!(crate::FrozenAssets::<Runtime>::contains_key(asset_id));
+
+        // a deregistered asset is gone for good.
+        assert_noop!(
+            AssetManager::thaw_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::deregister_asset(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+    });
+}
+
+#[test]
+fn alias_locations_should_work() {
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, true);
+    let general_key_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(
+            Parachain(2023),
+            GeneralKey(WeakBoundedVec::force_from(b"MOVR".to_vec(), None)),
+        ),
+    )));
+    let general_index_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(Parachain(2023), GeneralIndex(0)),
+    )));
+    new_test_ext().execute_with(|| {
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            general_key_location.clone(),
+            asset_metadata.clone()
+        ));
+
+        assert_noop!(
+            AssetManager::add_alias_location(
+                RuntimeOrigin::signed([2u8; 32].into()),
+                asset_id,
+                general_index_location.clone()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::add_alias_location(
+                RuntimeOrigin::root(),
+                asset_id + 1,
+                general_index_location.clone()
+            ),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::add_alias_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                general_key_location.clone()
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        assert_ok!(AssetManager::add_alias_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            general_index_location.clone()
+        ));
+        assert_noop!(
+            AssetManager::add_alias_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                general_index_location.clone()
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+
+        // both the canonical location and the alias resolve to the asset, but the asset is only
+        // sent out with its canonical location.
+        assert_eq!(
+            <AssetManager as AssetIdLocationMap>::asset_id(&general_key_location),
+            Some(asset_id)
+        );
+        assert_eq!(
+            <AssetManager as AssetIdLocationMap>::asset_id(&general_index_location),
+            Some(asset_id)
+        );
+        assert_eq!(
+            <AssetManager as AssetIdLocationMap>::location(&asset_id),
+            Some(general_key_location.clone())
+        );
+
+        // an alias cannot be registered as a new asset.
+        assert_noop!(
+            AssetManager::register_asset(
+                RuntimeOrigin::root(),
+                general_index_location.clone(),
+                asset_metadata
+            ),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+
+        // the number of aliases is bounded.
+        for i in 1..4 {
+            assert_ok!(AssetManager::add_alias_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+                    1,
+                    X2(Parachain(2023), GeneralIndex(i)),
+                )))
+            ));
+        }
+        assert_noop!(
+            AssetManager::add_alias_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+                    1,
+                    X2(Parachain(2023), GeneralIndex(4)),
+                )))
+            ),
+            Error::<Runtime>::TooManyAliasLocations
+        );
+
+        // promoting an alias to the canonical location removes it from the aliases.
+        assert_ok!(AssetManager::update_asset_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            general_index_location.clone()
+        ));
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(general_index_location.clone())
+        );
+        assert_eq!(AssetManager::alias_locations(asset_id).len(), 3);
+        assert_eq!(
+            AssetManager::alias_location_asset_id(&general_index_location),
+            None
+        );
+        assert_eq!(
+            <AssetManager as AssetIdLocationMap>::asset_id(&general_key_location),
+            None
+        );
+
+        let alias = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+            1,
+            X2(Parachain(2023), GeneralIndex(1)),
+        )));
+        assert_noop!(
+            AssetManager::remove_alias_location(
+                RuntimeOrigin::root(),
+                asset_id,
+                general_key_location
+            ),
+            Error::<Runtime>::AliasLocationNotFound
+        );
+        assert_ok!(AssetManager::remove_alias_location(
+            RuntimeOrigin::root(),
+            asset_id,
+            alias.clone()
+        ));
+        assert_eq!(<AssetManager as AssetIdLocationMap>::asset_id(&alias), None);
+        assert_eq!(AssetManager::alias_locations(asset_id).len(), 2);
+
+        // deregistering the asset drops all of its aliases.
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+        assert_ok!(AssetManager::deregister_asset(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert!(AssetManager::alias_locations(asset_id).is_empty());
+        assert_eq!(crate::AliasLocationAssetId::<Runtime>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn propose_asset_registration_should_work() {
+    let proposer: sp_runtime::AccountId32 = [1u8; 32].into();
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, false);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(2023)),
+    )));
+    new_test_ext().execute_with(|| {
+        let deposit = <Runtime as crate::Config>::RegistrationDeposit::get();
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+
+        // the proposer needs to be able to pay the deposit.
+        assert!(AssetManager::propose_asset_registration(
+            RuntimeOrigin::signed(proposer.clone()),
+            source_location.clone(),
+            asset_metadata.clone()
+        )
+        .is_err());
+        Balances::make_free_balance_be(&proposer, 10 * deposit);
+
+        // permissionlessly proposed assets can never be sufficient.
+        assert_noop!(
+            AssetManager::propose_asset_registration(
+                RuntimeOrigin::signed(proposer.clone()),
+                source_location.clone(),
+                create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, true)
+            ),
+            Error::<Runtime>::SufficientAssetProposal
+        );
+
+        assert_ok!(AssetManager::propose_asset_registration(
+            RuntimeOrigin::signed(proposer.clone()),
+            source_location.clone(),
+            asset_metadata.clone()
+        ));
+        assert_eq!(Balances::reserved_balance(&proposer), deposit);
+        assert!(<AssetManager as RestrictedAssets>::is_restricted(&asset_id));
+
+        // the asset is neither created nor reachable over XCM until it is approved.
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), None);
+        assert_eq!(LocationAssetId::<Runtime>::get(&source_location), None);
+        assert_eq!(AssetManager::get_para_id(2023), None);
+        assert_noop!(
+            AssetManager::set_units_per_second(RuntimeOrigin::root(), asset_id, 125),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::approve_asset_registration(
+                RuntimeOrigin::signed(proposer.clone()),
+                asset_id
+            ),
+            BadOrigin
+        );
+        assert_ok!(AssetManager::approve_asset_registration(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(Balances::reserved_balance(&proposer), 0);
+        assert!(!<AssetManager as RestrictedAssets>::is_restricted(
+            &asset_id
+        ));
+        assert_eq!(
+            AssetIdLocation::<Runtime>::get(asset_id),
+            Some(source_location.clone())
+        );
+        assert_eq!(
+            LocationAssetId::<Runtime>::get(&source_location),
+            Some(asset_id)
+        );
+        assert_eq!(AssetManager::get_para_id(2023), Some(1));
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            125
+        ));
+        assert_noop!(
+            AssetManager::approve_asset_registration(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::NoPendingRegistration
+        );
+    });
+}
+
+#[test]
+fn reject_asset_registration_should_slash_deposit() {
+    let proposer: sp_runtime::AccountId32 = [1u8; 32].into();
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, false);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(2023)),
+    )));
+    new_test_ext().execute_with(|| {
+        let deposit = <Runtime as crate::Config>::RegistrationDeposit::get();
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        Balances::make_free_balance_be(&proposer, 10 * deposit);
+        assert_ok!(AssetManager::propose_asset_registration(
+            RuntimeOrigin::signed(proposer.clone()),
+            source_location.clone(),
+            asset_metadata
+        ));
+
+        assert_ok!(AssetManager::reject_asset_registration(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        assert_eq!(Balances::reserved_balance(&proposer), 0);
+        assert_eq!(Balances::free_balance(&proposer), 9 * deposit);
+        assert!(!<AssetManager as RestrictedAssets>::is_restricted(
+            &asset_id
+        ));
+        assert_eq!(AssetIdLocation::<Runtime>::get(asset_id), None);
+        assert_eq!(AssetIdMetadata::<Runtime>::get(asset_id), None);
+        assert_eq!(LocationAssetId::<Runtime>::get(&source_location), None);
+        assert_eq!(AssetManager::get_para_id(2023), None);
+        assert_noop!(
+            AssetManager::reject_asset_registration(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::NoPendingRegistration
+        );
+    });
+}
+
+#[test]
+fn pending_asset_registration_should_not_claim_its_location() {
+    let proposer: sp_runtime::AccountId32 = [1u8; 32].into();
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, false);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X1(Parachain(2023)),
+    )));
+    new_test_ext().execute_with(|| {
+        let deposit = <Runtime as crate::Config>::RegistrationDeposit::get();
+        let proposed_asset_id =
+            <MantaAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get();
+        Balances::make_free_balance_be(&proposer, 10 * deposit);
+        assert_ok!(AssetManager::propose_asset_registration(
+            RuntimeOrigin::signed(proposer.clone()),
+            source_location.clone(),
+            asset_metadata.clone()
+        ));
+
+        // governance can still register the location while the proposal is pending.
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            source_location.clone(),
+            asset_metadata
+        ));
+        let registered_asset_id = crate::NextAssetId::<Runtime>::get() - 1;
+        assert_eq!(
+            LocationAssetId::<Runtime>::get(&source_location),
+            Some(registered_asset_id)
+        );
+
+        // the proposal can then only be rejected.
+        assert_noop!(
+            AssetManager::approve_asset_registration(RuntimeOrigin::root(), proposed_asset_id),
+            Error::<Runtime>::LocationAlreadyExists
+        );
+        assert_ok!(AssetManager::reject_asset_registration(
+            RuntimeOrigin::root(),
+            proposed_asset_id
+        ));
+        assert_eq!(AssetManager::get_para_id(2023), Some(1));
+    });
+}
+
+#[test]
+fn asset_manager_api_queries_should_work() {
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, true);
+    let reserve_location = MultiLocation::new(1, X1(Parachain(2023)));
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(Parachain(2023), PalletInstance(10)),
+    )));
+    let reserve_dest = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(
+            Parachain(2023),
+            AccountKey20 {
+                network: NetworkId::Any,
+                key: [1u8; 20],
+            },
+        ),
+    )));
+    let non_reserve_dest = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(
+            Parachain(2084),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: [1u8; 32],
+            },
+        ),
+    )));
+    new_test_ext().execute_with(|| {
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            source_location.clone(),
+            asset_metadata.clone()
+        ));
+        let asset_id = crate::NextAssetId::<Runtime>::get() - 1;
+
+        // Both assets are listed in asset id order, without units-per-second yet.
+        let assets = AssetManager::asset_infos();
+        assert_eq!(
+            assets.iter().map(|info| info.asset_id).collect::<Vec<_>>(),
+            vec![native_asset_id, asset_id]
+        );
+        let info = AssetManager::asset_info(asset_id).expect("The asset was just registered.");
+        assert_eq!(info.location, source_location);
+        assert_eq!(info.metadata, asset_metadata);
+        assert_eq!(info.units_per_second, None);
+        assert!(!info.is_frozen);
+        assert_eq!(AssetManager::quote_weight_fee(asset_id, 1_000_000), None);
+
+        // The weight fee is priced like the XCM trader.
+        let units_per_second = 2_000_000_000_000u128;
+        let weight = 5_000_000_000u64;
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            units_per_second
+        ));
+        let fee = manta_primitives::xcm::units_per_second_to_fee(units_per_second, weight);
+        assert_eq!(fee, 10_000_000_000);
+        assert_eq!(AssetManager::quote_weight_fee(asset_id, weight), Some(fee));
+
+        // Transfers to the reserve chain only pay the weight fee, the others also pay the minimum
+        // xcm fee of the reserve chain.
+        assert_eq!(
+            AssetManager::quote_transfer_fee(asset_id, reserve_dest.clone(), weight),
+            Some(fee)
+        );
+        assert_eq!(
+            AssetManager::quote_transfer_fee(asset_id, non_reserve_dest.clone(), weight),
+            None
+        );
+        assert_ok!(AssetManager::set_min_xcm_fee(
+            RuntimeOrigin::root(),
+            reserve_location.into(),
+            100
+        ));
+        assert_eq!(
+            AssetManager::quote_transfer_fee(asset_id, non_reserve_dest, weight),
+            Some(fee + 100)
+        );
+
+        // Frozen assets cannot pay fees.
+        assert_ok!(AssetManager::freeze_asset(RuntimeOrigin::root(), asset_id));
+        assert!(AssetManager::asset_info(asset_id).unwrap().is_frozen);
+        assert_eq!(AssetManager::quote_weight_fee(asset_id, weight), None);
+        assert_eq!(
+            AssetManager::quote_transfer_fee(asset_id, reserve_dest, weight),
+            None
+        );
+    });
+}
+
+#[test]
+fn outbound_rate_limit_should_work() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        let unregistered_asset_id = crate::NextAssetId::<Runtime>::get();
+
+        // Only sudo can set the limit of registered assets with a non-zero period.
+        assert_noop!(
+            AssetManager::set_outbound_rate_limit(
+                RuntimeOrigin::signed([2u8; 32].into()),
+                asset_id,
+                1_000,
+                10
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::set_outbound_rate_limit(
+                RuntimeOrigin::root(),
+                unregistered_asset_id,
+                1_000,
+                10
+            ),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::set_outbound_rate_limit(RuntimeOrigin::root(), asset_id, 1_000, 0),
+            Error::<Runtime>::ZeroRateLimitPeriod
+        );
+
+        // Unlimited assets always pass.
+        assert!(AssetManager::is_within_limit(&asset_id, &u128::MAX));
+        AssetManager::note_outbound(&asset_id, &u128::MAX);
+        assert_eq!(AssetManager::outbound_flow(asset_id).amount(), 0);
+
+        assert_ok!(AssetManager::set_outbound_rate_limit(
+            RuntimeOrigin::root(),
+            asset_id,
+            1_000,
+            10
+        ));
+        System::assert_last_event(
+            crate::Event::OutboundRateLimitSet {
+                asset_id,
+                limit: 1_000,
+                period: 10,
+            }
+            .into(),
+        );
+
+        // The limit can be used up in a single block.
+        assert!(AssetManager::is_within_limit(&asset_id, &1_000));
+        assert!(!AssetManager::is_within_limit(&asset_id, &1_001));
+        AssetManager::note_outbound(&asset_id, &800);
+        assert!(AssetManager::is_within_limit(&asset_id, &200));
+        assert!(!AssetManager::is_within_limit(&asset_id, &201));
+
+        // Withdrawals count against the limit for the whole window.
+        System::set_block_number(4);
+        assert!(AssetManager::is_within_limit(&asset_id, &200));
+        assert!(!AssetManager::is_within_limit(&asset_id, &201));
+        AssetManager::note_outbound(&asset_id, &200);
+        assert_eq!(AssetManager::outbound_flow(asset_id).amount(), 1_000);
+        System::set_block_number(11);
+        assert!(!AssetManager::is_within_limit(&asset_id, &1));
+
+        // The first withdrawal leaves the window once its bucket is older than a period.
+        System::set_block_number(12);
+        assert!(AssetManager::is_within_limit(&asset_id, &800));
+        assert!(!AssetManager::is_within_limit(&asset_id, &801));
+
+        // Lowering the limit keeps the accrued flow.
+        assert_ok!(AssetManager::set_outbound_rate_limit(
+            RuntimeOrigin::root(),
+            asset_id,
+            500,
+            10
+        ));
+        assert!(AssetManager::is_within_limit(&asset_id, &300));
+        assert!(!AssetManager::is_within_limit(&asset_id, &301));
+        AssetManager::note_outbound(&asset_id, &300);
+        assert!(!AssetManager::is_within_limit(&asset_id, &1));
+        AssetManager::note_rejected(&asset_id, &1);
+        System::assert_last_event(
+            crate::Event::OutboundTransferRejected {
+                asset_id,
+                amount: 1,
+            }
+            .into(),
+        );
+
+        // Changing the period moves the accrued flow into the current bucket of the new window.
+        System::set_block_number(14);
+        assert_ok!(AssetManager::set_outbound_rate_limit(
+            RuntimeOrigin::root(),
+            asset_id,
+            500,
+            20
+        ));
+        assert_eq!(AssetManager::outbound_flow(asset_id).amount(), 500);
+        System::set_block_number(35);
+        assert!(!AssetManager::is_within_limit(&asset_id, &1));
+
+        // The whole limit is available again once the window passed.
+        System::set_block_number(36);
+        assert!(AssetManager::is_within_limit(&asset_id, &500));
+        assert!(!AssetManager::is_within_limit(&asset_id, &501));
+
+        assert_ok!(AssetManager::remove_outbound_rate_limit(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(crate::Event::OutboundRateLimitRemoved { asset_id }.into());
+        assert_eq!(AssetManager::outbound_flow(asset_id).amount(), 0);
+        assert!(AssetManager::is_within_limit(&asset_id, &u128::MAX));
+        assert_noop!(
+            AssetManager::remove_outbound_rate_limit(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::NoOutboundRateLimit
+        );
+    });
+}
+
+#[test]
+fn relative_price_should_derive_units_per_second() {
+    let asset_metadata = create_asset_metadata("Moonriver", "MOVR", 18, 1u128, false, true);
+    let source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::new(
+        1,
+        X2(Parachain(2023), PalletInstance(10)),
+    )));
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let native_asset_id = <MantaAssetConfig as AssetConfig<Runtime>>::NativeAssetId::get();
+        let native_units_per_second = <Runtime as crate::Config>::NativeUnitsPerSecond::get();
+        assert_ok!(AssetManager::register_asset(
+            RuntimeOrigin::root(),
+            source_location,
+            asset_metadata
+        ));
+        let asset_id = crate::NextAssetId::<Runtime>::get() - 1;
+        assert_ok!(AssetManager::set_units_per_second(
+            RuntimeOrigin::root(),
+            asset_id,
+            125
+        ));
+        let (min, max) = (FixedU128::from_rational(1, 2), FixedU128::from(2));
+
+        // Only sudo can set valid bounds on registered non-native assets.
+        assert_noop!(
+            AssetManager::set_relative_price_bounds(
+                RuntimeOrigin::signed([2u8; 32].into()),
+                asset_id,
+                min,
+                max,
+                Perbill::from_percent(10)
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::set_relative_price_bounds(
+                RuntimeOrigin::root(),
+                native_asset_id,
+                min,
+                max,
+                Perbill::from_percent(10)
+            ),
+            Error::<Runtime>::CannotPriceNativeAsset
+        );
+        assert_noop!(
+            AssetManager::set_relative_price_bounds(
+                RuntimeOrigin::root(),
+                asset_id + 1,
+                min,
+                max,
+                Perbill::from_percent(10)
+            ),
+            Error::<Runtime>::UpdateNonExistentAsset
+        );
+        assert_noop!(
+            AssetManager::set_relative_price_bounds(
+                RuntimeOrigin::root(),
+                asset_id,
+                max,
+                min,
+                Perbill::from_percent(10)
+            ),
+            Error::<Runtime>::InvalidPriceBounds
+        );
+        assert_noop!(
+            AssetManager::feed_relative_price(RuntimeOrigin::root(), asset_id, FixedU128::one()),
+            Error::<Runtime>::NoRelativePriceBounds
+        );
+
+        // Without a price, an asset with bounds cannot pay for XCM execution.
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(125));
+        assert_ok!(AssetManager::set_relative_price_bounds(
+            RuntimeOrigin::root(),
+            asset_id,
+            min,
+            max,
+            Perbill::from_percent(10)
+        ));
+        System::assert_last_event(
+            crate::Event::RelativePriceBoundsSet {
+                asset_id,
+                min,
+                max,
+                max_change: Perbill::from_percent(10),
+            }
+            .into(),
+        );
+        assert_eq!(AssetManager::units_per_second(&asset_id), None);
+
+        // The first price only has to be within the bounds.
+        assert_noop!(
+            AssetManager::feed_relative_price(
+                RuntimeOrigin::signed([2u8; 32].into()),
+                asset_id,
+                FixedU128::one()
+            ),
+            BadOrigin
+        );
+        assert_noop!(
+            AssetManager::feed_relative_price(RuntimeOrigin::root(), asset_id, FixedU128::from(3)),
+            Error::<Runtime>::PriceOutOfBounds
+        );
+        assert_ok!(AssetManager::feed_relative_price(
+            RuntimeOrigin::root(),
+            asset_id,
+            FixedU128::one()
+        ));
+        System::assert_last_event(
+            crate::Event::RelativePriceFed {
+                asset_id,
+                price: FixedU128::one(),
+                units_per_second: native_units_per_second,
+            }
+            .into(),
+        );
+        assert_eq!(
+            AssetManager::units_per_second(&asset_id),
+            Some(native_units_per_second)
+        );
+
+        // Later prices cannot move by more than the maximum change.
+        assert_noop!(
+            AssetManager::feed_relative_price(
+                RuntimeOrigin::root(),
+                asset_id,
+                FixedU128::from_rational(111, 100)
+            ),
+            Error::<Runtime>::PriceChangeTooLarge
+        );
+        System::set_block_number(5);
+        assert_ok!(AssetManager::feed_relative_price(
+            RuntimeOrigin::root(),
+            asset_id,
+            FixedU128::from_rational(9, 10)
+        ));
+        assert_eq!(
+            AssetManager::units_per_second(&asset_id),
+            Some(native_units_per_second / 10 * 9)
+        );
+
+        // Stale prices cannot pay for XCM execution.
+        let staleness_threshold = <Runtime as crate::Config>::PriceStalenessThreshold::get();
+        System::set_block_number(5 + staleness_threshold);
+        assert!(AssetManager::units_per_second(&asset_id).is_some());
+        System::set_block_number(6 + staleness_threshold);
+        assert_eq!(AssetManager::units_per_second(&asset_id), None);
+        assert_eq!(AssetManager::quote_weight_fee(asset_id, 1_000), None);
+
+        // A stale price does not limit the change of the next one.
+        assert_ok!(AssetManager::feed_relative_price(
+            RuntimeOrigin::root(),
+            asset_id,
+            FixedU128::from_rational(6, 10)
+        ));
+        assert_eq!(
+            AssetManager::units_per_second(&asset_id),
+            Some(native_units_per_second / 10 * 6)
+        );
+
+        // Narrowing the bounds past the last price discards it.
+        assert_ok!(AssetManager::set_relative_price_bounds(
+            RuntimeOrigin::root(),
+            asset_id,
+            FixedU128::one(),
+            max,
+            Perbill::from_percent(10)
+        ));
+        assert_eq!(AssetManager::relative_price(asset_id), None);
+
+        // Without bounds, the static units-per-second applies again.
+        assert_ok!(AssetManager::remove_relative_price_bounds(
+            RuntimeOrigin::root(),
+            asset_id
+        ));
+        System::assert_last_event(crate::Event::RelativePriceBoundsRemoved { asset_id }.into());
+        assert_eq!(AssetManager::units_per_second(&asset_id), Some(125));
+        assert_noop!(
+            AssetManager::remove_relative_price_bounds(RuntimeOrigin::root(), asset_id),
+            Error::<Runtime>::NoRelativePriceBounds
+        );
+    });
+}
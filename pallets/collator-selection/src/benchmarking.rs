@@ -173,6 +173,29 @@ benchmarks! {
         assert_last_event::<T>(Event::NewEvictionTolerance(percentage).into());
     }
 
+    set_reputation_selection {
+        let origin = T::UpdateOrigin::successful_origin();
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_reputation_selection(origin, true)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewReputationSelection(true).into());
+    }
+
+    set_eviction_cooldown {
+        let sessions = 4;
+        let origin = T::UpdateOrigin::successful_origin();
+    }: {
+        assert_ok!(
+            <CollatorSelection<T>>::set_eviction_cooldown(origin, sessions)
+        );
+    }
+    verify {
+        assert_last_event::<T>(Event::NewEvictionCooldown(sessions).into());
+    }
+
     // worse case is when we have all the max-candidate slots filled except one, and we fill that
     // one.
     register_as_candidate {
@@ -267,6 +290,25 @@ benchmarks! {
         assert_last_event::<T>(Event::CandidateAdded(caller, bond / 2u32.into()).into());
     }
 
+    // worse case is the last candidate outbidding everyone else.
+    update_bond {
+        let c in 1 .. T::MaxCandidates::get();
+        <CandidacyBond<T>>::put(T::Currency::minimum_balance());
+        <DesiredCandidates<T>>::put(c);
+
+        register_validators::<T>(c);
+        register_candidates::<T>(c);
+
+        let bidder = <Candidates<T>>::get().last().unwrap().who.clone();
+        let bond: BalanceOf<T> = T::Currency::minimum_balance() * 2u32.into();
+        T::Currency::make_free_balance_be(&bidder, bond * 2u32.into());
+        whitelist!(bidder);
+    }: _(RawOrigin::Signed(bidder.clone()), bond)
+    verify {
+        assert_last_event::<T>(Event::CandidateBondUpdated(bidder.clone(), bond).into());
+        assert_eq!(<Candidates<T>>::get()[0].who, bidder);
+    }
+
     // worse case is paying a non-existing candidate account.
     note_author {
         <CandidacyBond<T>>::put(T::Currency::minimum_balance());
@@ -43,8 +43,20 @@
 //! 2. [`Candidates`]: these are *candidates to the collation task* and may or may not be elected as
 //!    a final collator.
 //!
-//! The current implementation resolves congestion of [`Candidates`] in a first-come-first-serve
-//! manner.
+//! [`Candidates`] are ordered by bond, highest first, with ties going to the earliest to reach that
+//! bond. Candidates may bond more than the [`CandidacyBond`] with [`Pallet::update_bond`] to move up.
+//! At every session boundary the first [`DesiredCandidates`] are selected, unless
+//! [`ReputationSelection`] is enabled, and the rest wait in the queue. Waiting candidates that
+//! outbid a selected candidate displace it at the next session boundary.
+//!
+//! ### Performance
+//!
+//! The blocks authored by each collator are recorded for the last
+//! [`Config::PerformanceHistoryLength`] sessions in [`PerformanceHistory`], from which a reputation
+//! score is derived. With [`ReputationSelection`] enabled, collators are evicted based on their
+//! reputation instead of the last session only, and the candidates with the best reputation are
+//! preferred when there are more than [`DesiredCandidates`]. Evicted collators may register again
+//! once [`EvictionCooldown`] sessions have passed.
 //!
 //! ### Rewards
 //!
@@ -73,6 +85,8 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 pub mod migrations;
+#[cfg(feature = "runtime")]
+pub mod runtime;
 pub mod weights;
 
 #[frame_support::pallet]
@@ -116,7 +130,7 @@ pub mod pallet {
         }
     }
 
-    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
     /// Configure the pallet by specifying the parameters and types on which it depends.
     #[pallet::config]
@@ -166,6 +180,10 @@ pub mod pallet {
         /// If the pallet that implements this trait depends on an inherent, that inherent **must**
         /// be included before this one.
         type CanAuthor: CanAuthor<Self::AccountId>;
+
+        /// Number of past sessions kept in the performance record of each collator.
+        #[pallet::constant]
+        type PerformanceHistoryLength: Get<u32>;
     }
 
     /// Basic information about a collation candidate.
@@ -177,6 +195,17 @@ pub mod pallet {
         pub deposit: Balance,
     }
 
+    /// Blocks authored by a collator in a session.
+    #[derive(
+        PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, scale_info::TypeInfo,
+    )]
+    pub struct SessionPerformance {
+        /// Blocks authored by the collator.
+        pub authored: BlockCount,
+        /// Blocks the collator was expected to author given an even split of the session.
+        pub expected: BlockCount,
+    }
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::storage_version(STORAGE_VERSION)]
@@ -188,13 +217,13 @@ pub mod pallet {
     #[pallet::getter(fn invulnerables)]
     pub type Invulnerables<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
 
-    /// The (community, limited) collation candidates.
+    /// The (community, limited) collation candidates, ordered by bond from highest to lowest.
     #[pallet::storage]
     #[pallet::getter(fn candidates)]
     pub type Candidates<T: Config> =
         StorageValue<_, Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>, ValueQuery>;
 
-    pub(super) type BlockCount = u32;
+    pub type BlockCount = u32;
     #[pallet::type_value]
     pub(super) fn StartingBlockCount() -> BlockCount {
         Zero::zero()
@@ -227,6 +256,39 @@ pub mod pallet {
     #[pallet::getter(fn candidacy_bond)]
     pub type CandidacyBond<T> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+    /// Performance of each collator in its last [`Config::PerformanceHistoryLength`] sessions,
+    /// oldest first.
+    #[pallet::storage]
+    #[pallet::getter(fn performance_history)]
+    pub type PerformanceHistory<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<SessionPerformance>, ValueQuery>;
+
+    /// Whether eviction and selection of candidates use the reputation of collators.
+    #[pallet::storage]
+    #[pallet::getter(fn reputation_selection)]
+    pub type ReputationSelection<T> = StorageValue<_, bool, ValueQuery>;
+
+    /// Number of sessions an evicted collator must wait before registering as candidate again.
+    #[pallet::storage]
+    #[pallet::getter(fn eviction_cooldown)]
+    pub type EvictionCooldown<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
+    /// Evicted collators and the session from which they may register as candidate again.
+    #[pallet::storage]
+    #[pallet::getter(fn evicted_until)]
+    pub type EvictedCollators<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, SessionIndex, OptionQuery>;
+
+    /// Candidates selected as collators in the last session planned by this pallet.
+    #[pallet::storage]
+    #[pallet::getter(fn selected_candidates)]
+    pub type SelectedCandidates<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    /// Index of the last session planned by this pallet.
+    #[pallet::storage]
+    #[pallet::getter(fn last_session_index)]
+    pub type LastSessionIndex<T> = StorageValue<_, SessionIndex, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub invulnerables: Vec<T::AccountId>,
@@ -295,6 +357,10 @@ pub mod pallet {
         CandidateRemoved(T::AccountId),
         NewEvictionBaseline(Percent),
         NewEvictionTolerance(Percent),
+        NewReputationSelection(bool),
+        NewEvictionCooldown(SessionIndex),
+        CandidateBondUpdated(T::AccountId, BalanceOf<T>),
+        CandidateDisplaced(T::AccountId),
     }
 
     // Errors inform users that something went wrong.
@@ -318,6 +384,10 @@ pub mod pallet {
         ValidatorNotRegistered,
         /// Removing invulnerable collators is not allowed
         NotAllowRemoveInvulnerable,
+        /// Evicted collator cannot register as candidate before the eviction cooldown passed
+        EvictionCooldownActive,
+        /// Candidate bond cannot be lower than the candidacy bond
+        BondBelowCandidacyBond,
     }
 
     #[pallet::hooks]
@@ -346,9 +416,9 @@ pub mod pallet {
             Ok(().into())
         }
 
-        /// Set how many candidate collator are allowed.
+        /// Set how many candidate collator are selected each session.
         ///
-        /// `max`: The max number of candidates.
+        /// `max`: The max number of selected candidates.
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::set_desired_candidates())]
         pub fn set_desired_candidates(
@@ -389,13 +459,14 @@ pub mod pallet {
             // ensure we are below limit.
             let length = <Candidates<T>>::decode_len().unwrap_or_default();
             ensure!(
-                (length as u32) < Self::desired_candidates(),
+                (length as u32) < T::MaxCandidates::get(),
                 Error::<T>::TooManyCandidates
             );
             ensure!(
                 !Self::invulnerables().contains(&who),
                 Error::<T>::AlreadyInvulnerable
             );
+            Self::ensure_eviction_cooldown_passed(&who)?;
 
             let validator_key = T::ValidatorIdOf::convert(who.clone())
                 .ok_or(Error::<T>::NoAssociatedValidatorId)?;
@@ -417,7 +488,7 @@ pub mod pallet {
                         Err(Error::<T>::AlreadyCandidate.into())
                     } else {
                         T::Currency::reserve(&who, deposit)?;
-                        candidates.push(incoming);
+                        Self::insert_candidate(candidates, incoming);
                         Ok(candidates.len())
                     }
                 })?;
@@ -440,13 +511,14 @@ pub mod pallet {
             // ensure we are below limit.
             let length = <Candidates<T>>::decode_len().unwrap_or_default();
             ensure!(
-                (length as u32) < Self::desired_candidates(),
+                (length as u32) < T::MaxCandidates::get(),
                 Error::<T>::TooManyCandidates
             );
             ensure!(
                 !Self::invulnerables().contains(&new_candidate),
                 Error::<T>::AlreadyInvulnerable
             );
+            Self::ensure_eviction_cooldown_passed(&new_candidate)?;
 
             let validator_key = T::ValidatorIdOf::convert(new_candidate.clone())
                 .ok_or(Error::<T>::NoAssociatedValidatorId)?;
@@ -470,7 +542,7 @@ pub mod pallet {
                         Err(Error::<T>::AlreadyCandidate.into())
                     } else {
                         T::Currency::reserve(&new_candidate, deposit)?;
-                        candidates.push(incoming);
+                        Self::insert_candidate(candidates, incoming);
                         Ok(candidates.len())
                     }
                 })?;
@@ -540,6 +612,73 @@ pub mod pallet {
             Self::deposit_event(Event::NewEvictionTolerance(percentage));
             Ok(().into())
         }
+
+        /// Set whether eviction and selection of candidates use the reputation of collators
+        ///
+        /// `enabled`: evict by reputation and prefer reputable candidates over DesiredCandidates
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_reputation_selection())]
+        pub fn set_reputation_selection(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <ReputationSelection<T>>::put(enabled);
+            Self::deposit_event(Event::NewReputationSelection(enabled));
+            Ok(().into())
+        }
+
+        /// Set the number of sessions evicted collators must wait before registering again
+        ///
+        /// `sessions`: cooldown in sessions, 0 allows registering again immediately
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::set_eviction_cooldown())]
+        pub fn set_eviction_cooldown(
+            origin: OriginFor<T>,
+            sessions: SessionIndex,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            <EvictionCooldown<T>>::put(sessions);
+            Self::deposit_event(Event::NewEvictionCooldown(sessions));
+            Ok(().into())
+        }
+
+        /// Change the amount held on reserve for the calling candidate
+        ///
+        /// `new_deposit`: the new bond, at least the candidacy bond. Candidates are re-ordered by
+        /// bond and the selection takes effect at the next session boundary.
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::update_bond(T::MaxCandidates::get()))]
+        pub fn update_bond(
+            origin: OriginFor<T>,
+            new_deposit: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(
+                new_deposit >= Self::candidacy_bond(),
+                Error::<T>::BondBelowCandidacyBond
+            );
+
+            let current_count =
+                <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
+                    let index = candidates
+                        .iter()
+                        .position(|candidate| candidate.who == who)
+                        .ok_or(Error::<T>::NotCandidate)?;
+                    let mut candidate = candidates.remove(index);
+                    if new_deposit > candidate.deposit {
+                        T::Currency::reserve(&who, new_deposit - candidate.deposit)?;
+                    } else {
+                        T::Currency::unreserve(&who, candidate.deposit - new_deposit);
+                    }
+                    candidate.deposit = new_deposit;
+                    Self::insert_candidate(candidates, candidate);
+                    Ok(candidates.len())
+                })?;
+
+            Self::deposit_event(Event::CandidateBondUpdated(who, new_deposit));
+            Ok(Some(T::WeightInfo::update_bond(current_count as u32)).into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -548,7 +687,8 @@ pub mod pallet {
             T::PotId::get().into_account_truncating()
         }
 
-        /// Removes a candidate if they exist and sends them back their deposit
+        /// Removes a candidate if they exist, sends them back their deposit and drops their
+        /// performance history
         fn try_remove_candidate(who: &T::AccountId) -> Result<usize, DispatchError> {
             let current_count =
                 <Candidates<T>>::try_mutate(|candidates| -> Result<usize, DispatchError> {
@@ -560,19 +700,140 @@ pub mod pallet {
                     candidates.remove(index);
                     Ok(candidates.len())
                 })?;
+            <PerformanceHistory<T>>::remove(who);
             Self::deposit_event(Event::CandidateRemoved(who.clone()));
             Ok(current_count)
         }
 
+        /// Insert `incoming` behind all candidates with at least the same bond
+        fn insert_candidate(
+            candidates: &mut Vec<CandidateInfo<T::AccountId, BalanceOf<T>>>,
+            incoming: CandidateInfo<T::AccountId, BalanceOf<T>>,
+        ) {
+            let index =
+                candidates.partition_point(|candidate| candidate.deposit >= incoming.deposit);
+            candidates.insert(index, incoming);
+        }
+
+        /// Fails if `who` was evicted and the eviction cooldown did not pass yet
+        fn ensure_eviction_cooldown_passed(who: &T::AccountId) -> DispatchResult {
+            if let Some(until) = <EvictedCollators<T>>::get(who) {
+                ensure!(
+                    Self::last_session_index() >= until,
+                    Error::<T>::EvictionCooldownActive
+                );
+                <EvictedCollators<T>>::remove(who);
+            }
+            Ok(())
+        }
+
         /// Assemble the current set of candidates and invulnerables into the next collator set.
         ///
         /// This is done on the fly, as frequent as we are told to do so, as the session manager.
-        pub fn assemble_collators(candidates: Vec<T::AccountId>) -> Vec<T::AccountId> {
+        /// Only the first [`DesiredCandidates`] candidates are kept, or with [`ReputationSelection`]
+        /// enabled those with the best reputation, ties going to the higher bond.
+        pub fn assemble_collators(mut candidates: Vec<T::AccountId>) -> Vec<T::AccountId> {
+            let desired = Self::desired_candidates() as usize;
+            if candidates.len() > desired {
+                if Self::reputation_selection() {
+                    // stable sort keeps the bond order among equal reputations
+                    candidates.sort_by_cached_key(|who| core::cmp::Reverse(Self::reputation(who)));
+                }
+                candidates.truncate(desired);
+            }
             let mut collators = Self::invulnerables();
             collators.extend(candidates.into_iter().collect::<Vec<_>>());
             collators
         }
 
+        /// Store the candidates selected for the next session and report previously selected
+        /// candidates that were pushed back into the queue
+        fn note_selected_candidates(candidates: &[T::AccountId], collators: &[T::AccountId]) {
+            let selected = candidates
+                .iter()
+                .filter(|who| collators.contains(who))
+                .cloned()
+                .collect::<Vec<_>>();
+            for who in Self::selected_candidates() {
+                if candidates.contains(&who) && !selected.contains(&who) {
+                    Self::deposit_event(Event::CandidateDisplaced(who));
+                }
+            }
+            <SelectedCandidates<T>>::put(selected);
+        }
+
+        /// Share of its expected blocks `collator` authored over its recorded sessions
+        ///
+        /// Collators without any recorded session have full reputation.
+        pub fn reputation(collator: &T::AccountId) -> Percent {
+            let (authored, expected) = Self::performance_history(collator).iter().fold(
+                (BlockCount::zero(), BlockCount::zero()),
+                |(authored, expected), session| {
+                    (
+                        authored.saturating_add(session.authored.min(session.expected)),
+                        expected.saturating_add(session.expected),
+                    )
+                },
+            );
+            if expected.is_zero() {
+                return Percent::one();
+            }
+            Percent::from_rational(authored, expected)
+        }
+
+        /// Reputation of all candidates and invulnerables
+        pub fn reputations() -> Vec<(T::AccountId, Percent)> {
+            Self::invulnerables()
+                .into_iter()
+                .chain(
+                    Self::candidates()
+                        .into_iter()
+                        .map(|candidate| candidate.who),
+                )
+                .map(|who| {
+                    let reputation = Self::reputation(&who);
+                    (who, reputation)
+                })
+                .collect()
+        }
+
+        /// Append the blocks authored in the ending session to the performance history of each
+        /// collator, dropping sessions older than [`Config::PerformanceHistoryLength`]
+        ///
+        /// Collators which are neither candidates nor invulnerables anymore, but still authored
+        /// in the ending session, are not recorded so their history is not recreated.
+        pub fn record_collator_performance() {
+            let performance = <BlocksPerCollatorThisSession<T>>::iter().collect::<Vec<_>>();
+            if performance.is_empty() {
+                return;
+            }
+            let total = performance
+                .iter()
+                .fold(BlockCount::zero(), |total, (_, blocks)| {
+                    total.saturating_add(*blocks)
+                });
+            let expected = total / performance.len() as BlockCount;
+            if expected.is_zero() {
+                return; // no blocks recorded in this session ( e.g. genesis )
+            }
+            let history_length = T::PerformanceHistoryLength::get() as usize;
+            let candidates = Self::candidates();
+            let invulnerables = Self::invulnerables();
+            for (collator, authored) in performance {
+                if !invulnerables.contains(&collator)
+                    && !candidates.iter().any(|candidate| candidate.who == collator)
+                {
+                    continue;
+                }
+                <PerformanceHistory<T>>::mutate(&collator, |history| {
+                    history.push(SessionPerformance { authored, expected });
+                    if history.len() > history_length {
+                        history.drain(..history.len() - history_length);
+                    }
+                });
+            }
+        }
+
         /// Removes collators with unsatisfactory performance
         /// Returns the removed AccountIds
         pub fn evict_bad_collators(
@@ -597,6 +858,12 @@ pub mod pallet {
             if collator_perf_this_session.is_empty() {
                 return Vec::new(); // no validator performance recorded ( should not happen )
             }
+            if Self::reputation_selection() {
+                // rank collators by reputation in percent instead of this session's blocks only
+                for (acc_id, score) in collator_perf_this_session.iter_mut() {
+                    *score = Self::reputation(acc_id).deconstruct() as BlockCount;
+                }
+            }
 
             // 1. Ascending sort of collator performance list by number of produced blocks
             collator_perf_this_session.sort_unstable_by_key(|k| k.1);
@@ -633,6 +900,7 @@ pub mod pallet {
                         #[allow(clippy::bind_instead_of_map)] Self::try_remove_candidate(acc_id)
                             .and_then(|_| {
                                 removed_account_ids.push(acc_id.clone());
+                                Self::note_eviction(acc_id);
                                 log::info!("Removed collator of account {:?} as it only produced {} blocks this session which is below acceptable threshold of {}", &acc_id, my_blocks_this_session,evict_below_blocks);
                                 Ok(())
                             })
@@ -647,6 +915,33 @@ pub mod pallet {
             removed_account_ids
         }
 
+        /// Block `collator` from registering again until the eviction cooldown passed
+        fn note_eviction(collator: &T::AccountId) {
+            let cooldown = Self::eviction_cooldown();
+            if !cooldown.is_zero() {
+                <EvictedCollators<T>>::insert(
+                    collator,
+                    Self::last_session_index().saturating_add(cooldown),
+                );
+            }
+        }
+
+        /// Forget the evicted collators whose eviction cooldown passed by session `index`,
+        /// returning the number of entries read and removed
+        pub fn prune_evicted_collators(index: SessionIndex) -> (u64, u64) {
+            let mut read = 0u64;
+            let expired = <EvictedCollators<T>>::iter()
+                .filter_map(|(collator, until)| {
+                    read += 1;
+                    (index >= until).then_some(collator)
+                })
+                .collect::<Vec<_>>();
+            for collator in &expired {
+                <EvictedCollators<T>>::remove(collator);
+            }
+            (read, expired.len() as u64)
+        }
+
         /// Reset the performance map to the currently active validators at 0 blocks
         pub fn reset_collator_performance() {
             let validators = T::ValidatorRegistration::validators();
@@ -789,8 +1084,11 @@ pub mod pallet {
                 <frame_system::Pallet<T>>::block_number(),
             );
 
+            <LastSessionIndex<T>>::put(index);
             let candidates = Self::candidates();
             let candidates_len_before = candidates.len();
+            let collators_len = <BlocksPerCollatorThisSession<T>>::iter_keys().count() as u64;
+            Self::record_collator_performance();
             let removed_candidate_ids = Self::evict_bad_collators(candidates.clone());
             let active_candidate_ids = candidates
                 .iter()
@@ -802,9 +1100,19 @@ pub mod pallet {
                     }
                 })
                 .collect::<Vec<_>>();
-            let result = Self::assemble_collators(active_candidate_ids);
+            let result = Self::assemble_collators(active_candidate_ids.clone());
+            Self::note_selected_candidates(&active_candidate_ids, &result);
+            let (evicted_read, evicted_removed) = Self::prune_evicted_collators(index);
             frame_system::Pallet::<T>::register_extra_weight_unchecked(
-                T::WeightInfo::new_session(candidates_len_before as u32),
+                T::WeightInfo::new_session(candidates_len_before as u32)
+                    .saturating_add(
+                        // read and write the performance history of each collator and the selection
+                        T::DbWeight::get().reads_writes(collators_len + 1, collators_len + 1),
+                    )
+                    .saturating_add(
+                        // read the evicted collators and remove the expired ones
+                        T::DbWeight::get().reads_writes(evicted_read, evicted_removed),
+                    ),
                 DispatchClass::Mandatory,
             );
 
@@ -17,15 +17,18 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use super::*;
+use core::marker::PhantomData;
 #[allow(deprecated)]
 use frame_support::migration::remove_storage_prefix;
 use frame_support::{
     dispatch::GetStorageVersion,
     migration::{have_storage_value, storage_key_iter},
     pallet_prelude::Weight,
-    traits::{Get, PalletInfoAccess, StorageVersion},
+    traits::{Get, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
     Twox64Concat,
 };
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
 
 /// This migrates the pallet from the standard version by parity to our modified storage.
 impl<T: Config> Pallet<T> {
@@ -107,3 +110,66 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 }
+
+/// Orders the candidates by bond, which selection and the waiting queue rely on since V2.
+impl<T: Config> Pallet<T> {
+    pub fn migrate_v1_to_v2() -> frame_support::weights::Weight {
+        if Self::on_chain_storage_version() < 2 {
+            log::info!("Executing collator-selection V1->V2 migration!");
+
+            // stable sort keeps the registration order among equal bonds
+            let mut candidates = Self::candidates();
+            candidates.sort_by(|a, b| b.deposit.cmp(&a.deposit));
+            log::info!(" >>> Ordered {} candidates by bond", candidates.len());
+            <Candidates<T>>::put(candidates);
+
+            // Update storage version.
+            StorageVersion::new(2).put::<Self>();
+
+            // Return the weight consumed by the migration.
+            T::DbWeight::get().reads_writes(2, 2)
+        } else {
+            log::debug!("collator-selection V1->V2 migration not needed!");
+            Weight::zero()
+        }
+    }
+
+    pub fn pre_migrate_v1_to_v2() -> Result<(), &'static str> {
+        if Self::on_chain_storage_version() != 1 {
+            return Err("Migration to V2 does not apply");
+        }
+        Ok(())
+    }
+
+    pub fn post_migrate_v1_to_v2() -> Result<(), &'static str> {
+        if Self::on_chain_storage_version() != 2 {
+            return Err("storage version not upgraded");
+        }
+        if Self::candidates()
+            .windows(2)
+            .any(|pair| pair[0].deposit < pair[1].deposit)
+        {
+            return Err("Candidates are not ordered by bond");
+        }
+        Ok(())
+    }
+}
+
+/// Runtime upgrade hook running [`Pallet::migrate_v1_to_v2`].
+pub struct MigrateV1ToV2<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateV1ToV2<T> {
+    fn on_runtime_upgrade() -> Weight {
+        Pallet::<T>::migrate_v1_to_v2()
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+        Pallet::<T>::pre_migrate_v1_to_v2()?;
+        Ok(Vec::new())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+        Pallet::<T>::post_migrate_v1_to_v2()
+    }
+}
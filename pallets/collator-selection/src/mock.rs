@@ -211,6 +211,7 @@ impl Config for Test {
     type ValidatorRegistration = IsRegistered;
     type WeightInfo = ();
     type CanAuthor = ();
+    type PerformanceHistoryLength = ConstU32<3>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -17,7 +17,8 @@
 use crate as collator_selection;
 use crate::{
     mock::*, BlocksPerCollatorThisSession, CandidateInfo, Error, EvictionBaseline,
-    EvictionTolerance,
+    EvictionTolerance, LastSessionIndex, PerformanceHistory, ReputationSelection,
+    SessionPerformance,
 };
 use frame_support::{
     assert_noop, assert_ok,
@@ -186,6 +187,48 @@ fn set_eviction_baseline() {
     });
 }
 
+#[test]
+fn set_reputation_selection() {
+    new_test_ext().execute_with(|| {
+        // given
+        assert!(!CollatorSelection::reputation_selection());
+
+        // can set
+        assert_ok!(CollatorSelection::set_reputation_selection(
+            RuntimeOrigin::signed(RootAccount::get()),
+            true
+        ));
+        assert!(CollatorSelection::reputation_selection());
+
+        // rejects bad origin.
+        assert_noop!(
+            CollatorSelection::set_reputation_selection(RuntimeOrigin::signed(1), false),
+            BadOrigin
+        );
+    });
+}
+
+#[test]
+fn set_eviction_cooldown() {
+    new_test_ext().execute_with(|| {
+        // given
+        assert_eq!(CollatorSelection::eviction_cooldown(), 0);
+
+        // can set
+        assert_ok!(CollatorSelection::set_eviction_cooldown(
+            RuntimeOrigin::signed(RootAccount::get()),
+            4
+        ));
+        assert_eq!(CollatorSelection::eviction_cooldown(), 4);
+
+        // rejects bad origin.
+        assert_noop!(
+            CollatorSelection::set_eviction_cooldown(RuntimeOrigin::signed(1), 2),
+            BadOrigin
+        );
+    });
+}
+
 #[test]
 fn set_eviction_tolerance() {
     new_test_ext().execute_with(|| {
@@ -231,18 +274,20 @@ fn cannot_register_candidate_if_too_many() {
         // reset desired candidates:
         <crate::DesiredCandidates<Test>>::put(0);
 
-        // can't accept anyone anymore.
-        assert_noop!(
-            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(3)),
-            Error::<Test>::TooManyCandidates,
-        );
-
-        // reset desired candidates:
-        <crate::DesiredCandidates<Test>>::put(1);
+        // candidates beyond the desired ones wait in the queue.
         assert_ok!(CollatorSelection::register_as_candidate(
-            RuntimeOrigin::signed(4)
+            RuntimeOrigin::signed(3)
         ));
 
+        // fill up to MaxCandidates
+        for who in 10..29 {
+            Balances::make_free_balance_be(&who, 100);
+            assert_ok!(CollatorSelection::register_as_candidate(
+                RuntimeOrigin::signed(who)
+            ));
+        }
+        assert_eq!(CollatorSelection::candidates().len(), 20);
+
         // but no more
         assert_noop!(
             CollatorSelection::register_as_candidate(RuntimeOrigin::signed(5)),
@@ -370,6 +415,98 @@ fn leave_intent() {
     });
 }
 
+#[test]
+fn update_bond_reorders_candidates() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE, EVE]);
+
+        // outbid everyone
+        assert_ok!(CollatorSelection::update_bond(
+            RuntimeOrigin::signed(EVE),
+            20
+        ));
+        assert_eq!(candidate_ids(), vec![EVE, CHAD, DAVE]);
+        assert_eq!(Balances::reserved_balance(EVE), 20);
+        assert_eq!(Balances::free_balance(EVE), 80);
+
+        // ties go to the earliest to reach the bond
+        assert_ok!(CollatorSelection::update_bond(
+            RuntimeOrigin::signed(CHAD),
+            20
+        ));
+        assert_eq!(candidate_ids(), vec![EVE, CHAD, DAVE]);
+
+        // lowering the bond moves back behind equal bonds
+        assert_ok!(CollatorSelection::update_bond(
+            RuntimeOrigin::signed(EVE),
+            10
+        ));
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE, EVE]);
+        assert_eq!(Balances::reserved_balance(EVE), 10);
+        assert_eq!(Balances::free_balance(EVE), 90);
+
+        // new candidates queue behind higher bonds
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(DAVE)));
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(DAVE)
+        ));
+        assert_eq!(candidate_ids(), vec![CHAD, EVE, DAVE]);
+
+        // errors
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(DAVE), 9),
+            Error::<Test>::BondBelowCandidacyBond
+        );
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(ALICE), 20),
+            Error::<Test>::NotCandidate
+        );
+        assert_noop!(
+            CollatorSelection::update_bond(RuntimeOrigin::signed(DAVE), 200),
+            BalancesError::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn higher_bond_displaces_selected_candidate_at_session_boundary() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::set_desired_candidates(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+
+        // EVE waits in the queue
+        initialize_to_block(10);
+        assert_eq!(CollatorSelection::selected_candidates(), vec![CHAD, DAVE]);
+        initialize_to_block(20);
+        assert_eq!(Session::validators(), vec![ALICE, BOB, CHAD, DAVE]);
+
+        // EVE outbids DAVE, which takes effect at the next session boundary
+        assert_ok!(CollatorSelection::update_bond(
+            RuntimeOrigin::signed(EVE),
+            20
+        ));
+        assert_eq!(CollatorSelection::selected_candidates(), vec![CHAD, DAVE]);
+        initialize_to_block(29);
+        set_all_validator_perf_to(10);
+        initialize_to_block(30);
+        assert_eq!(CollatorSelection::selected_candidates(), vec![EVE, CHAD]);
+        System::assert_has_event(RuntimeEvent::CollatorSelection(
+            crate::Event::CandidateDisplaced(DAVE),
+        ));
+        initialize_to_block(39);
+        set_all_validator_perf_to(10);
+        initialize_to_block(40);
+        assert_eq!(Session::validators(), vec![ALICE, BOB, EVE, CHAD]);
+        // DAVE is still a candidate and keeps its bond
+        assert_eq!(candidate_ids(), vec![EVE, CHAD, DAVE]);
+        assert_eq!(Balances::reserved_balance(DAVE), 10);
+    });
+}
+
 #[test]
 fn authorship_event_handler() {
     new_test_ext().execute_with(|| {
@@ -789,17 +926,17 @@ fn register_candidate_should_work() {
             BadOrigin,
         );
 
-        // Cannot add collator if it reaches desired candidate
-        // Now it should be 2 candidates.
+        // Can add collator beyond desired candidates, it waits in the queue
+        // Now it should be 3 candidates.
         assert_ok!(CollatorSelection::register_candidate(
             RuntimeOrigin::signed(RootAccount::get()),
             4
         ));
-        assert_eq!(CollatorSelection::candidates().len(), 2);
-        assert_noop!(
-            CollatorSelection::register_candidate(RuntimeOrigin::signed(RootAccount::get()), 5),
-            Error::<Test>::TooManyCandidates
-        );
+        assert_ok!(CollatorSelection::register_candidate(
+            RuntimeOrigin::signed(RootAccount::get()),
+            5
+        ));
+        assert_eq!(CollatorSelection::candidates().len(), 3);
     });
 }
 
@@ -938,3 +1075,259 @@ fn increase_bond_after_register_candidate() {
         );
     });
 }
+
+#[test]
+fn performance_history_is_recorded_and_truncated() {
+    new_test_ext().execute_with(|| {
+        let _ = BlocksPerCollatorThisSession::<Test>::clear(u32::MAX, None);
+        // sessions without blocks are not recorded
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 0);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 0);
+        CollatorSelection::record_collator_performance();
+        assert!(CollatorSelection::performance_history(ALICE).is_empty());
+        assert_eq!(
+            CollatorSelection::reputation(&BOB),
+            Percent::from_percent(100)
+        );
+
+        // 16 blocks by 2 collators => 8 expected each
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 6);
+        CollatorSelection::record_collator_performance();
+        assert_eq!(
+            CollatorSelection::performance_history(ALICE),
+            vec![SessionPerformance {
+                authored: 10,
+                expected: 8
+            }]
+        );
+        // authoring more than expected does not raise reputation above 100%
+        assert_eq!(
+            CollatorSelection::reputation(&ALICE),
+            Percent::from_percent(100)
+        );
+        assert_eq!(
+            CollatorSelection::reputation(&BOB),
+            Percent::from_percent(75)
+        );
+
+        // only the last 3 sessions are kept
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 8);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 8);
+        for _ in 0..3 {
+            CollatorSelection::record_collator_performance();
+        }
+        assert_eq!(
+            CollatorSelection::performance_history(BOB),
+            vec![
+                SessionPerformance {
+                    authored: 8,
+                    expected: 8
+                };
+                3
+            ]
+        );
+        assert_eq!(
+            CollatorSelection::reputation(&BOB),
+            Percent::from_percent(100)
+        );
+    });
+}
+
+#[test]
+fn reputation_selection_prefers_reliable_candidates() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::set_desired_candidates(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        PerformanceHistory::<Test>::insert(
+            CHAD,
+            vec![SessionPerformance {
+                authored: 2,
+                expected: 10,
+            }],
+        );
+        PerformanceHistory::<Test>::insert(
+            EVE,
+            vec![SessionPerformance {
+                authored: 9,
+                expected: 10,
+            }],
+        );
+        assert_eq!(
+            CollatorSelection::reputations(),
+            vec![
+                (ALICE, Percent::from_percent(100)),
+                (BOB, Percent::from_percent(100)),
+                (CHAD, Percent::from_percent(20)),
+                (DAVE, Percent::from_percent(100)),
+                (EVE, Percent::from_percent(90)),
+            ]
+        );
+
+        // highest bonds when disabled
+        assert_eq!(
+            CollatorSelection::assemble_collators(candidate_ids()),
+            vec![ALICE, BOB, CHAD, DAVE]
+        );
+        // the least reliable candidate is dropped when enabled
+        ReputationSelection::<Test>::put(true);
+        assert_eq!(
+            CollatorSelection::assemble_collators(candidate_ids()),
+            vec![ALICE, BOB, DAVE, EVE]
+        );
+        // all candidates are kept if there is room
+        assert_ok!(CollatorSelection::set_desired_candidates(
+            RuntimeOrigin::signed(RootAccount::get()),
+            3
+        ));
+        assert_eq!(
+            CollatorSelection::assemble_collators(candidate_ids()),
+            vec![ALICE, BOB, CHAD, DAVE, EVE]
+        );
+    });
+}
+
+#[test]
+fn reputation_selection_evicts_by_reputation() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        ReputationSelection::<Test>::put(true);
+        // CHAD had a bad session but is reliable overall, EVE is not
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(CHAD, 4);
+        BlocksPerCollatorThisSession::<Test>::insert(DAVE, 9);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        let good = SessionPerformance {
+            authored: 10,
+            expected: 10,
+        };
+        PerformanceHistory::<Test>::insert(
+            CHAD,
+            vec![
+                good,
+                good,
+                SessionPerformance {
+                    authored: 8,
+                    expected: 10,
+                },
+            ],
+        );
+        PerformanceHistory::<Test>::insert(
+            EVE,
+            vec![SessionPerformance {
+                authored: 2,
+                expected: 10,
+            }],
+        );
+        // 80th percentile = 100%, kick below 90%
+        assert_eq!(
+            CollatorSelection::evict_bad_collators(CollatorSelection::candidates()),
+            vec![EVE]
+        );
+        assert_eq!(candidate_ids(), vec![CHAD, DAVE]);
+        // evicted collators start afresh
+        assert!(CollatorSelection::performance_history(EVE).is_empty());
+    });
+}
+
+#[test]
+fn leaving_candidate_performance_history_is_dropped() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        let _ = BlocksPerCollatorThisSession::<Test>::clear(u32::MAX, None);
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 8);
+        BlocksPerCollatorThisSession::<Test>::insert(CHAD, 8);
+        CollatorSelection::record_collator_performance();
+        assert_eq!(CollatorSelection::performance_history(CHAD).len(), 1);
+
+        assert_ok!(CollatorSelection::leave_intent(RuntimeOrigin::signed(CHAD)));
+        assert!(CollatorSelection::performance_history(CHAD).is_empty());
+
+        // blocks authored after leaving, until the session rotates, do not recreate it
+        CollatorSelection::record_collator_performance();
+        assert!(CollatorSelection::performance_history(CHAD).is_empty());
+        assert_eq!(CollatorSelection::performance_history(ALICE).len(), 2);
+    });
+}
+
+#[test]
+fn evicted_collator_cannot_register_during_cooldown() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::set_eviction_cooldown(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        LastSessionIndex::<Test>::put(5);
+
+        // 80th percentile = 10, kick *below* 9, remove CHAD,EVE
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(CHAD, 4);
+        BlocksPerCollatorThisSession::<Test>::insert(DAVE, 9);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        assert_eq!(
+            CollatorSelection::evict_bad_collators(CollatorSelection::candidates()),
+            vec![EVE, CHAD]
+        );
+        assert_eq!(CollatorSelection::evicted_until(CHAD), Some(7));
+
+        // neither on their own nor by governance during the cooldown
+        assert_noop!(
+            CollatorSelection::register_as_candidate(RuntimeOrigin::signed(CHAD)),
+            Error::<Test>::EvictionCooldownActive
+        );
+        LastSessionIndex::<Test>::put(6);
+        assert_noop!(
+            CollatorSelection::register_candidate(RuntimeOrigin::signed(RootAccount::get()), EVE),
+            Error::<Test>::EvictionCooldownActive
+        );
+
+        // readmitted once the cooldown passed
+        LastSessionIndex::<Test>::put(7);
+        assert_ok!(CollatorSelection::register_as_candidate(
+            RuntimeOrigin::signed(CHAD)
+        ));
+        assert_ok!(CollatorSelection::register_candidate(
+            RuntimeOrigin::signed(RootAccount::get()),
+            EVE
+        ));
+        assert_eq!(candidate_ids(), vec![DAVE, CHAD, EVE]);
+        assert_eq!(CollatorSelection::evicted_until(CHAD), None);
+    });
+}
+
+#[test]
+fn expired_evictions_are_pruned() {
+    new_test_ext().execute_with(|| {
+        setup_3_candidates();
+        assert_ok!(CollatorSelection::set_eviction_cooldown(
+            RuntimeOrigin::signed(RootAccount::get()),
+            2
+        ));
+        LastSessionIndex::<Test>::put(5);
+        BlocksPerCollatorThisSession::<Test>::insert(ALICE, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(BOB, 10);
+        BlocksPerCollatorThisSession::<Test>::insert(CHAD, 4);
+        BlocksPerCollatorThisSession::<Test>::insert(DAVE, 9);
+        BlocksPerCollatorThisSession::<Test>::insert(EVE, 0);
+        assert_eq!(
+            CollatorSelection::evict_bad_collators(CollatorSelection::candidates()),
+            vec![EVE, CHAD]
+        );
+
+        // kept during the cooldown
+        assert_eq!(CollatorSelection::prune_evicted_collators(6), (2, 0));
+        assert_eq!(CollatorSelection::evicted_until(CHAD), Some(7));
+        assert_eq!(CollatorSelection::evicted_until(EVE), Some(7));
+
+        // forgotten once it passed, without registering again
+        assert_eq!(CollatorSelection::prune_evicted_collators(7), (2, 2));
+        assert_eq!(CollatorSelection::evicted_until(CHAD), None);
+        assert_eq!(CollatorSelection::evicted_until(EVE), None);
+    });
+}
@@ -56,6 +56,9 @@ pub trait WeightInfo {
     fn register_candidate(c: u32, ) -> Weight;
     fn note_author() -> Weight;
     fn new_session(c: u32, ) -> Weight;
+    fn set_reputation_selection() -> Weight;
+    fn set_eviction_cooldown() -> Weight;
+    fn update_bond(c: u32, ) -> Weight;
 }
 
 /// Weights for manta_collator_selection using the Substrate node and recommended hardware.
@@ -154,6 +157,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().writes(3_u64))
             .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c as u64)))
     }
+    // Storage: CollatorSelection ReputationSelection (r:0 w:1)
+    fn set_reputation_selection() -> Weight {
+        Weight::from_ref_time(9_100_000)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    // Storage: CollatorSelection EvictionCooldown (r:0 w:1)
+    fn set_eviction_cooldown() -> Weight {
+        Weight::from_ref_time(9_050_000)
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    // Storage: CollatorSelection CandidacyBond (r:1 w:0)
+    // Storage: CollatorSelection Candidates (r:1 w:1)
+    fn update_bond(c: u32, ) -> Weight {
+        Weight::from_ref_time(31_200_000)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(512_000).saturating_mul(c.into()))
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
 }
 
 // For backwards compatibility and tests
@@ -251,4 +273,23 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().writes(3_u64))
             .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c as u64)))
     }
+    // Storage: CollatorSelection ReputationSelection (r:0 w:1)
+    fn set_reputation_selection() -> Weight {
+        Weight::from_ref_time(9_100_000)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    // Storage: CollatorSelection EvictionCooldown (r:0 w:1)
+    fn set_eviction_cooldown() -> Weight {
+        Weight::from_ref_time(9_050_000)
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    // Storage: CollatorSelection CandidacyBond (r:1 w:0)
+    // Storage: CollatorSelection Candidates (r:1 w:1)
+    fn update_bond(c: u32, ) -> Weight {
+        Weight::from_ref_time(31_200_000)
+            // Standard Error: 1_000
+            .saturating_add(Weight::from_ref_time(512_000).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
 }
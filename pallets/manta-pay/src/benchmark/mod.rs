@@ -20,8 +20,13 @@ use crate::{
     },
     Call, Config, Event, Pallet, StandardAssetId, TransferPost,
 };
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::traits::Get;
+use frame_benchmarking::{
+    benchmarks, impl_benchmark_test_suite, whitelisted_caller, BenchmarkError,
+};
+use frame_support::{
+    traits::{EnsureOrigin, Get},
+    Hashable,
+};
 use frame_system::RawOrigin;
 use manta_support::manta_pay::{
     asset_value_decode, asset_value_encode, field_from_id, id_from_field, AccountId, Asset,
@@ -31,10 +36,12 @@ use manta_primitives::{
     assets::{AssetConfig, AssetRegistry, FungibleLedger, TestingDefault},
     constants::TEST_DEFAULT_ASSET_ED,
     types::Balance,
+    xcm::PrivateDeposit,
 };
 use scale_codec::Decode;
 
-mod precomputed_coins;
+/// Transfer Posts Precomputed for Asset `8`, see the `precompute_coins` binary
+pub mod precomputed_coins;
 
 pub const INITIAL_VALUE: u128 = 1_000_000_000_000_000_000_000u128;
 
@@ -148,6 +155,37 @@ benchmarks! {
         // FIXME: add balance checking
         assert_last_event::<T, _>(Event::Transfer { asset, source: caller.clone(), sink });
     }
+
+    to_private_from_deposit {
+        let caller: T::AccountId = whitelisted_caller();
+        let mint_post = TransferPost::decode(&mut &*TO_PRIVATE).unwrap();
+        let asset = mint_post.source(0).unwrap();
+        let asset_id = id_from_field(asset.id).unwrap();
+        init_asset::<T>(&caller, asset_id, asset_value_decode(asset.value));
+        Pallet::<T>::deposit(asset_id, asset_value_decode(asset.value), mint_post.blake2_256()).unwrap();
+    }: to_private_from_deposit (
+        RawOrigin::Signed(caller.clone()),
+        mint_post
+    ) verify {
+        assert_last_event::<T, _>(Event::ToPrivate { asset, source: Pallet::<T>::account_id() });
+    }
+
+    reclaim_deposit {
+        let caller: T::AccountId = whitelisted_caller();
+        let mint_post = TransferPost::decode(&mut &*TO_PRIVATE).unwrap();
+        let asset = mint_post.source(0).unwrap();
+        let asset_id = id_from_field(asset.id).unwrap();
+        init_asset::<T>(&caller, asset_id, asset_value_decode(asset.value));
+        let commitment = mint_post.blake2_256();
+        Pallet::<T>::deposit(asset_id, asset_value_decode(asset.value), commitment).unwrap();
+        frame_system::Pallet::<T>::set_block_number(
+            frame_system::Pallet::<T>::block_number() + T::DepositExpiry::get()
+        );
+        let origin = T::DepositReclaimOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+    }: _<T::RuntimeOrigin>(origin, commitment, caller.clone())
+    verify {
+        assert_last_event::<T, _>(Event::PrivateDepositReclaimed { commitment, asset, beneficiary: caller });
+    }
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -18,7 +18,7 @@
 //!
 //! THIS FILE IS AUTOMATICALLY GENERATED by `src/bin/precompute_coins.rs`. DO NOT EDIT.
 
-pub(crate) const TO_PRIVATE: &[u8] = &[
+pub const TO_PRIVATE: &[u8] = &[
     0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 4, 16, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -47,7 +47,7 @@ pub(crate) const TO_PRIVATE: &[u8] = &[
     201, 134, 1, 0,
 ];
 
-pub(crate) const PRIVATE_TRANSFER_INPUT: &[&[u8]] = &[
+pub const PRIVATE_TRANSFER_INPUT: &[&[u8]] = &[
     &[
         0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 4, 16, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
@@ -107,7 +107,7 @@ pub(crate) const PRIVATE_TRANSFER_INPUT: &[&[u8]] = &[
     ],
 ];
 
-pub(crate) const PRIVATE_TRANSFER: &[u8] = &[
+pub const PRIVATE_TRANSFER: &[u8] = &[
     1, 99, 184, 129, 64, 219, 43, 38, 250, 203, 144, 54, 87, 154, 137, 84, 153, 13, 203, 245, 144,
     25, 222, 100, 227, 78, 144, 16, 68, 93, 232, 211, 151, 240, 25, 36, 44, 6, 87, 63, 50, 53, 149,
     24, 211, 157, 139, 225, 229, 231, 240, 95, 135, 166, 42, 153, 171, 204, 3, 231, 109, 225, 197,
@@ -171,7 +171,7 @@ pub(crate) const PRIVATE_TRANSFER: &[u8] = &[
     201, 252, 40, 229, 117, 48, 26, 104, 59, 89, 146, 40, 0,
 ];
 
-pub(crate) const TO_PUBLIC_INPUT: &[&[u8]] = &[
+pub const TO_PUBLIC_INPUT: &[&[u8]] = &[
     &[
         0, 1, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 4, 16, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
@@ -232,7 +232,7 @@ pub(crate) const TO_PUBLIC_INPUT: &[&[u8]] = &[
     ],
 ];
 
-pub(crate) const TO_PUBLIC: &[u8] = &[
+pub const TO_PUBLIC: &[u8] = &[
     1, 108, 180, 218, 200, 24, 13, 75, 39, 129, 97, 180, 105, 253, 150, 255, 152, 105, 28, 138, 51,
     67, 17, 218, 57, 222, 218, 147, 29, 150, 199, 96, 28, 65, 156, 231, 67, 135, 246, 148, 36, 109,
     112, 127, 148, 119, 62, 177, 50, 71, 82, 108, 50, 122, 222, 177, 187, 119, 1, 186, 73, 194,
@@ -135,7 +135,7 @@ macro_rules! write_const_array {
     ($writer:ident, $name:ident, $value:expr) => {
         writeln!(
             $writer,
-            "pub(crate) const {}: &[u8] = &{:?};\n",
+            "pub const {}: &[u8] = &{:?};\n",
             stringify!($name),
             $value.encode().as_slice()
         )
@@ -147,7 +147,7 @@ macro_rules! write_const_nested_array {
     ($writer:ident, $name:ident, $value:expr) => {
         writeln!(
             $writer,
-            "pub(crate) const {}: &[&[u8]] = &[{}];\n",
+            "pub const {}: &[&[u8]] = &[{}];\n",
             stringify!($name),
             $value
                 .iter()
@@ -161,11 +161,85 @@ macro_rules! write_const_nested_array {
     };
 }
 
-/// Builds sample transactions for testing.
+/// Returns the hex string of the SCALE encoding of `value`.
+#[inline]
+fn scale_hex<T>(value: &T) -> String
+where
+    T: Encode,
+{
+    value
+        .encode()
+        .iter()
+        .fold(String::from("0x"), |hex, byte| hex + &format!("{byte:02x}"))
+}
+
+/// Builds `count` private transfers together with their [`ToPrivate`] inputs and writes their
+/// posts as the MantaPay genesis configuration of a chain spec.
+///
+/// Usage: `precompute_coins --genesis <count> [target_file]`
+#[inline]
+fn write_genesis(args: &[String]) -> Result<()> {
+    let count = args
+        .first()
+        .map(|count| count.parse())
+        .transpose()?
+        .unwrap_or(1);
+    let target_file = args
+        .get(1)
+        .map(PathBuf::from)
+        .unwrap_or(env::current_dir()?.join("manta_pay_genesis.json"));
+    assert!(
+        !target_file.exists(),
+        "Specify a file to place the generated genesis: {target_file:?}.",
+    );
+
+    let directory = tempfile::tempdir().expect("Unable to generate temporary test directory.");
+    println!("[INFO] Temporary Directory: {directory:?}");
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let (proving_context, _, parameters, utxo_accumulator_model) =
+        load_parameters(directory.path()).expect("Unable to load parameters.");
+    let mut utxo_accumulator = UtxoAccumulator::new(utxo_accumulator_model);
+    let asset_id = 8.into();
+
+    let mut senders = Vec::new();
+    let mut receivers = Vec::new();
+    for index in 0..count {
+        let (private_transfer_input, private_transfer) = sample_private_transfer(
+            &proving_context,
+            &parameters,
+            &mut utxo_accumulator,
+            asset_id,
+            [10_000, 20_000],
+            &mut rng,
+        );
+        for post in private_transfer_input.into_iter().chain([private_transfer]) {
+            senders.extend(post.sender_posts);
+            receivers.extend(post.receiver_posts);
+        }
+        println!("[INFO] Private Transfer {}/{count}", index + 1);
+    }
+
+    fs::write(
+        target_file,
+        format!(
+            "{{\n  \"senders\": \"{}\",\n  \"receivers\": \"{}\"\n}}\n",
+            scale_hex(&senders),
+            scale_hex(&receivers)
+        ),
+    )?;
+    Ok(directory.close()?)
+}
+
+/// Builds sample transactions for testing, or a genesis ledger with `--genesis`.
 #[inline]
 fn main() -> Result<()> {
-    let target_file = env::args()
-        .nth(1)
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("--genesis") {
+        return write_genesis(&args[1..]);
+    }
+    let target_file = args
+        .first()
         .map(PathBuf::from)
         .unwrap_or(env::current_dir()?.join("precomputed_coins.rs"));
     assert!(
@@ -27,6 +27,7 @@
 //! * To Public Asset Conversion (see [`to_public`])
 //! * Private Asset Transfer (see [`private_transfer`]
 //! * Public Asset Transfer (see [`public_transfer`])
+//! * To Private Conversion of XCM Deposits (see [`to_private_from_deposit`])
 //!
 //! To use it in your runtime, you need to implement the assets [`Config`].
 //!
@@ -40,6 +41,8 @@
 //! * [`to_private`]: Converts a private asset back into a public one.
 //! * [`private_transfer`]: Transfers assets between two private accounts.
 //! * [`public_transfer`]: Transfers assets between two public accounts.
+//! * [`to_private_from_deposit`]: Converts assets deposited over XCM into private ones.
+//! * [`reclaim_deposit`]: Returns assets deposited over XCM which were not converted in time.
 //!
 //! Please refer to the [`Call`] enum and its associated variants for documentation on each
 //! function.
@@ -50,6 +53,8 @@
 //! [`to_public`]: Pallet::to_public
 //! [`private_transfer`]: Pallet::private_transfer
 //! [`public_transfer`]: Pallet::public_transfer
+//! [`to_private_from_deposit`]: Pallet::to_private_from_deposit
+//! [`reclaim_deposit`]: Pallet::reclaim_deposit
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(doc_cfg, feature(doc_cfg))]
@@ -61,7 +66,7 @@ use alloc::{vec, vec::Vec};
 use core::marker::PhantomData;
 use errors::{ReceiverLedgerError, SenderLedgerError, TransferLedgerError};
 use frame_support::{
-    pallet_prelude::*, traits::tokens::ExistenceRequirement, transactional, PalletId,
+    pallet_prelude::*, traits::tokens::ExistenceRequirement, transactional, Hashable, PalletId,
 };
 use frame_system::pallet_prelude::*;
 use manta_pay::{
@@ -80,13 +85,19 @@ use manta_pay::{
     manta_util::codec::Decode as _,
     parameters::load_transfer_parameters,
 };
-use manta_primitives::assets::{self, AssetConfig, FungibleLedger as _};
+use manta_primitives::{
+    assets::{
+        self, AssetConfig, AssetIdType, BalanceType, FungibleLedger as _, RestrictedAssets as _,
+    },
+    xcm::PrivateDeposit,
+};
 use manta_support::manta_pay::{
-    asset_value_decode, asset_value_encode, fp_decode, fp_encode, id_from_field, AccountId, Asset,
-    AssetValue, Checkpoint, FullIncomingNote, InitialSyncResponse, MTParametersError,
-    NullifierCommitment, OutgoingNote, PullResponse, ReceiverChunk, SenderChunk, StandardAssetId,
-    TransferPost, Utxo, UtxoAccumulatorOutput, UtxoItemHashError, UtxoMerkleTreePath,
-    VerifyingContextError, Wrap, WrapPair,
+    asset_value_decode, asset_value_encode, field_from_id, fp_decode, fp_encode, id_from_field,
+    AccountId, Asset, AssetValue, Checkpoint, FullIncomingNote, InitialSyncResponse,
+    MTParametersError, NullifierCommitment, OutgoingNote, PullResponse, ReceiverChunk,
+    ReceiverPost, SenderChunk, SenderPost, StandardAssetId, TransferPost, Utxo,
+    UtxoAccumulatorOutput, UtxoItemHashError, UtxoMerkleTreePath, VerifyingContextError, Wrap,
+    WrapPair,
 };
 use manta_util::codec::Encode;
 
@@ -111,6 +122,12 @@ pub mod rpc;
 #[cfg(feature = "runtime")]
 pub mod runtime;
 
+/// Maximum Number of Updates per Shard (based on benchmark result)
+pub const PULL_MAX_RECEIVER_UPDATE_SIZE: u64 = 32768;
+
+/// Maximum Size of Sender Data Update (based on benchmark result)
+pub const PULL_MAX_SENDER_UPDATE_SIZE: u64 = 32768;
+
 /// Fungible Ledger Error
 pub type FungibleLedgerError = assets::FungibleLedgerError<StandardAssetId, AssetValue>;
 
@@ -142,6 +159,13 @@ pub mod pallet {
 
         /// Pallet ID
         type PalletId: Get<PalletId>;
+
+        /// Origin which can reclaim expired private deposits
+        type DepositReclaimOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Number of blocks after which a private deposit which was not shielded can be reclaimed
+        #[pallet::constant]
+        type DepositExpiry: Get<Self::BlockNumber>;
     }
 
     /// Fungible Ledger Implementation for [`Config`]
@@ -191,6 +215,85 @@ pub mod pallet {
     #[pallet::storage]
     pub(super) type NullifierSetSize<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Assets Deposited over XCM and Held for a `to_private` Transfer, with their Deposit Block
+    ///
+    /// Keyed by the hash of the transfer post which shields the deposit, see
+    /// [`to_private_from_deposit`](Pallet::to_private_from_deposit). Deposits which are not
+    /// shielded can be reclaimed after [`Config::DepositExpiry`] blocks, see
+    /// [`reclaim_deposit`](Pallet::reclaim_deposit).
+    #[pallet::storage]
+    pub(super) type PendingDeposits<T: Config> =
+        StorageMap<_, Twox64Concat, [u8; 32], (Asset, T::BlockNumber), OptionQuery>;
+
+    /// Genesis Configuration
+    ///
+    /// Preloads the shielded ledger, e.g. with the posts written by `precompute_coins --genesis`,
+    /// so that wallet synchronization and load tests can run against a realistically sized ledger.
+    /// The posts are serialized as the hex string of their SCALE encoding.
+    #[pallet::genesis_config]
+    #[derive(Clone)]
+    pub struct GenesisConfig {
+        /// Sender posts whose nullifiers are inserted into the nullifier set in order
+        #[serde(with = "manta_primitives::scale_hex")]
+        pub senders: Vec<SenderPost>,
+
+        /// Receiver posts whose UTXOs are inserted into the UTXO set, the shards and the shard
+        /// trees, like the receivers of a transfer
+        #[serde(with = "manta_primitives::scale_hex")]
+        pub receivers: Vec<ReceiverPost>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                senders: Vec::new(),
+                receivers: Vec::new(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        #[inline]
+        fn build(&self) {
+            let senders = self
+                .senders
+                .iter()
+                .cloned()
+                .map(config::SenderPost::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Genesis sender posts must decode.");
+            let receivers = self
+                .receivers
+                .iter()
+                .cloned()
+                .map(config::ReceiverPost::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Genesis receiver posts must decode.");
+            let mut ledger = Ledger::<T>(PhantomData);
+            ledger
+                .spend_all(
+                    &(Wrap(()), ()),
+                    senders
+                        .into_iter()
+                        .map(|post| (Wrap(post.utxo_accumulator_output), Wrap(post.nullifier))),
+                )
+                .map_err(|err| Error::<T>::from(SenderPostError::from(err)))
+                .expect("Unable to spend the genesis nullifiers.");
+            ledger
+                .register_all(
+                    &(Wrap(()), ()),
+                    receivers
+                        .into_iter()
+                        .map(|post| (Wrap(post.utxo), post.note)),
+                )
+                .map_err(|err| Error::<T>::from(ReceiverPostError::from(err)))
+                .expect("Unable to register the genesis UTXOs.");
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T>
     where
@@ -218,6 +321,12 @@ pub mod pallet {
                     Error::<T>::ZeroTransfer
                 );
             }
+            if let Some(asset_id) = post.asset_id.and_then(id_from_field) {
+                ensure!(
+                    !<T::AssetConfig as AssetConfig<T>>::RestrictedAssets::is_restricted(&asset_id),
+                    Error::<T>::RestrictedAsset
+                );
+            }
             Self::post_transaction(None, vec![origin], vec![], post)
         }
 
@@ -298,6 +407,77 @@ pub mod pallet {
             });
             Ok(().into())
         }
+
+        /// Transforms the assets held for a private deposit into private ones using `post`, the
+        /// `to_private` transfer whose hash the deposit was sent to. The pallet account holding
+        /// the deposit is the public source of the transfer, so `post` is not linked to any
+        /// public account of the depositor.
+        ///
+        /// # Note
+        ///
+        /// In this transaction, `origin` is just signing the `post`. It can be the sovereign
+        /// account of the chain sending the deposit, when `post` is sent in a `Transact` right
+        /// after the deposit.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::to_private_from_deposit())]
+        #[transactional]
+        pub fn to_private_from_deposit(
+            origin: OriginFor<T>,
+            post: TransferPost,
+        ) -> DispatchResultWithPostInfo {
+            let _origin = ensure_signed(origin)?;
+            ensure!(
+                post.sources.len() == 1
+                    && post.sender_posts.is_empty()
+                    && post.receiver_posts.len() == 1
+                    && post.sinks.is_empty()
+                    && post.sink_accounts.is_empty(),
+                Error::<T>::InvalidShape
+            );
+            let (deposit, _) = PendingDeposits::<T>::take(post.blake2_256())
+                .ok_or(Error::<T>::NoPendingDeposit)?;
+            ensure!(
+                post.source(0) == Some(deposit),
+                Error::<T>::PendingDepositMismatch
+            );
+            Self::post_transaction(None, vec![Self::account_id()], vec![], post)
+        }
+
+        /// Transfers the assets held for the private deposit to the transfer post whose hash is
+        /// `commitment` to `beneficiary`, once the deposit was not shielded for
+        /// [`Config::DepositExpiry`] blocks, e.g. because the post was invalid or never sent.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::reclaim_deposit())]
+        #[transactional]
+        pub fn reclaim_deposit(
+            origin: OriginFor<T>,
+            commitment: [u8; 32],
+            beneficiary: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            T::DepositReclaimOrigin::ensure_origin(origin)?;
+            let (asset, deposited_at) =
+                PendingDeposits::<T>::get(commitment).ok_or(Error::<T>::NoPendingDeposit)?;
+            ensure!(
+                frame_system::Pallet::<T>::block_number()
+                    >= deposited_at.saturating_add(T::DepositExpiry::get()),
+                Error::<T>::PendingDepositNotExpired
+            );
+            PendingDeposits::<T>::remove(commitment);
+            FungibleLedger::<T>::transfer(
+                id_from_field(asset.id).ok_or(Error::<T>::InvalidAssetId)?,
+                &Self::account_id(),
+                &beneficiary,
+                asset_value_decode(asset.value),
+                ExistenceRequirement::AllowDeath,
+            )
+            .map_err(Error::<T>::from)?;
+            Self::deposit_event(Event::PrivateDepositReclaimed {
+                commitment,
+                asset,
+                beneficiary,
+            });
+            Ok(().into())
+        }
     }
 
     /// Event
@@ -339,6 +519,27 @@ pub mod pallet {
             /// Sink Account
             sink: T::AccountId,
         },
+
+        /// Private Deposit Event
+        PrivateDepositReceived {
+            /// Hash of the Transfer Post Shielding the Deposit
+            commitment: [u8; 32],
+
+            /// Asset Deposited
+            asset: Asset,
+        },
+
+        /// Private Deposit Reclaimed Event
+        PrivateDepositReclaimed {
+            /// Hash of the Transfer Post which did not Shield the Deposit
+            commitment: [u8; 32],
+
+            /// Asset Reclaimed
+            asset: Asset,
+
+            /// Beneficiary Account
+            beneficiary: T::AccountId,
+        },
     }
 
     /// Error
@@ -359,6 +560,11 @@ pub mod pallet {
         /// The asset id of the transfer could not be converted correctly to the standard format.
         InvalidAssetId,
 
+        /// Restricted Asset
+        ///
+        /// The asset is restricted, e.g. its registration is pending, and cannot be privatized.
+        RestrictedAsset,
+
         /// Invalid Shape
         ///
         /// The transfer had an invalid shape.
@@ -434,6 +640,9 @@ pub mod pallet {
         /// [`InvalidTransfer`](FungibleLedgerError::InvalidTransfer) from [`FungibleLedgerError`]
         PublicUpdateInvalidTransfer,
 
+        /// [`FrozenAsset`](FungibleLedgerError::FrozenAsset) from [`FungibleLedgerError`]
+        PublicUpdateFrozenAsset,
+
         /// Fungible Ledger Encode Error
         FungibleLedgerEncodeError,
 
@@ -485,6 +694,26 @@ pub mod pallet {
         /// Transfer Ledger Proof Error
         TransferLedgerProofSystemFailed,
 
+        /// Pending Deposit Exists
+        ///
+        /// Assets are already held for a transfer post with the same hash.
+        PendingDepositExists,
+
+        /// No Pending Deposit
+        ///
+        /// No assets are held for the hash of the transfer post.
+        NoPendingDeposit,
+
+        /// Pending Deposit Mismatch
+        ///
+        /// The source of the transfer post does not match the assets held for it.
+        PendingDepositMismatch,
+
+        /// Pending Deposit Not Expired
+        ///
+        /// The assets held for the transfer post cannot be reclaimed yet.
+        PendingDepositNotExpired,
+
         /// Marker Error, this error exists for `PhantomData` should never happen
         Marker,
     }
@@ -494,12 +723,6 @@ pub mod pallet {
         T: Config,
         T::AccountId: From<AccountId> + Into<AccountId>,
     {
-        /// Maximum Number of Updates per Shard (based on benchmark result)
-        const PULL_MAX_RECEIVER_UPDATE_SIZE: u64 = 32768;
-
-        /// Maximum Size of Sender Data Update (based on benchmark result)
-        const PULL_MAX_SENDER_UPDATE_SIZE: u64 = 32768;
-
         /// Pulls receiver data from the ledger starting at the `receiver_indices`.
         /// The pull algorithm is greedy. It tries to pull as many as possible from each shard
         /// before moving to the next shard.
@@ -511,8 +734,8 @@ pub mod pallet {
             let mut more_receivers = false;
             let mut receivers = Vec::new();
             let mut receivers_pulled: u64 = 0;
-            let max_update = if max_update_request > Self::PULL_MAX_RECEIVER_UPDATE_SIZE {
-                Self::PULL_MAX_RECEIVER_UPDATE_SIZE
+            let max_update = if max_update_request > PULL_MAX_RECEIVER_UPDATE_SIZE {
+                PULL_MAX_RECEIVER_UPDATE_SIZE
             } else {
                 max_update_request
             };
@@ -582,8 +805,8 @@ pub mod pallet {
         #[inline]
         fn pull_senders(sender_index: usize, max_update_request: u64) -> (bool, SenderChunk) {
             let mut senders = Vec::new();
-            let max_sender_index = if max_update_request > Self::PULL_MAX_SENDER_UPDATE_SIZE {
-                (sender_index as u64) + Self::PULL_MAX_SENDER_UPDATE_SIZE
+            let max_sender_index = if max_update_request > PULL_MAX_SENDER_UPDATE_SIZE {
+                (sender_index as u64) + PULL_MAX_SENDER_UPDATE_SIZE
             } else {
                 (sender_index as u64) + max_update_request
             };
@@ -653,6 +876,59 @@ pub mod pallet {
             Ok(().into())
         }
     }
+
+    impl<T> AssetIdType for Pallet<T>
+    where
+        T: Config,
+    {
+        type AssetId = StandardAssetId;
+    }
+
+    impl<T> BalanceType for Pallet<T>
+    where
+        T: Config,
+    {
+        type Balance = AssetValue;
+    }
+
+    /// Deposits are minted to the pallet account, which then acts as the public source of the
+    /// `to_private` transfer posted with [`to_private_from_deposit`](Pallet::to_private_from_deposit).
+    impl<T> PrivateDeposit for Pallet<T>
+    where
+        T: Config,
+        T::AccountId: From<AccountId> + Into<AccountId>,
+    {
+        #[inline]
+        fn deposit(
+            asset_id: StandardAssetId,
+            amount: AssetValue,
+            commitment: [u8; 32],
+        ) -> DispatchResult {
+            ensure!(amount > 0, Error::<T>::ZeroTransfer);
+            ensure!(
+                !<T::AssetConfig as AssetConfig<T>>::RestrictedAssets::is_restricted(&asset_id),
+                Error::<T>::RestrictedAsset
+            );
+            ensure!(
+                !PendingDeposits::<T>::contains_key(commitment),
+                Error::<T>::PendingDepositExists
+            );
+            FungibleLedger::<T>::deposit_minting_with_check(
+                asset_id,
+                &Self::account_id(),
+                amount,
+                true,
+            )
+            .map_err(Error::<T>::from)?;
+            let asset = Asset::new(field_from_id(asset_id), asset_value_encode(amount));
+            PendingDeposits::<T>::insert(
+                commitment,
+                (asset, frame_system::Pallet::<T>::block_number()),
+            );
+            Self::deposit_event(Event::PrivateDepositReceived { commitment, asset });
+            Ok(())
+        }
+    }
 }
 
 /// Preprocessed Event
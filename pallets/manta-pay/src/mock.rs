@@ -17,7 +17,7 @@
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU32, IsInVec},
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, IsInVec},
     PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot};
@@ -245,6 +245,9 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
     type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Test {
@@ -255,6 +258,13 @@ impl pallet_asset_manager::Config for Test {
     type AssetConfig = MantaAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId32>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = ConstU128<1_000>;
+    type Slash = ();
+    type PriceFeederOrigin = EnsureRoot<AccountId32>;
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000_000>;
+    type PriceStalenessThreshold = ConstU32<10>;
     type WeightInfo = ();
 }
 
@@ -267,6 +277,8 @@ impl crate::Config for Test {
     type WeightInfo = crate::weights::SubstrateWeight<Self>;
     type PalletId = MantaPayPalletId;
     type AssetConfig = MantaAssetConfig;
+    type DepositReclaimOrigin = EnsureRoot<AccountId32>;
+    type DepositExpiry = ConstU32<10>;
 }
 
 parameter_types! {
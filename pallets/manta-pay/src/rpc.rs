@@ -17,15 +17,19 @@
 //! MantaPay RPC Interfaces
 
 use crate::runtime::PullLedgerDiffApi;
-use alloc::sync::Arc;
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use core::marker::PhantomData;
 use jsonrpsee::{
     core::{async_trait, RpcResult},
     proc_macros::rpc,
     types::error::{CallError, ErrorObject},
 };
-use manta_support::manta_pay::{
-    Checkpoint, DenseInitialSyncResponse, DensePullResponse, InitialSyncResponse, PullResponse,
+use manta_support::{
+    manta_pay::{
+        Checkpoint, DenseInitialSyncResponse, DensePullResponse, InitialSyncResponse, PullResponse,
+        RawCheckpoint,
+    },
+    snapshot::{Ledger, LedgerSnapshot, LedgerSnapshotChunk, LedgerSnapshotInfo, SnapshotError},
 };
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
@@ -34,6 +38,9 @@ use sp_runtime::{generic::BlockId, traits::Block};
 /// Pull Ledger Diff Error Code
 pub const PULL_LEDGER_DIFF_ERROR: i32 = 1;
 
+/// Ledger Snapshot Error Code
+pub const LEDGER_SNAPSHOT_ERROR: i32 = 2;
+
 /// Pull API
 #[rpc(server)]
 pub trait PullApi {
@@ -71,24 +78,94 @@ pub trait PullApi {
     ) -> RpcResult<DenseInitialSyncResponse>;
 }
 
+/// Ledger Index
+///
+/// Ledger diffs maintained outside of the runtime, e.g. by a node-side indexer following the
+/// imported blocks. [`Pull`] serves `pull_ledger_diff` from the index for the blocks it covers,
+/// and falls back to the runtime API for the other blocks.
+pub trait LedgerIndex<B>: Send + Sync
+where
+    B: Block,
+{
+    /// Returns the diff of the ledger at block `at` since `checkpoint`, with the same contents
+    /// as the `pull_ledger_diff` runtime API, or `None` if the block is not indexed.
+    fn pull_ledger_diff(
+        &self,
+        at: B::Hash,
+        checkpoint: RawCheckpoint,
+        max_receivers: u64,
+        max_senders: u64,
+    ) -> Option<PullResponse>;
+}
+
 /// Pull RPC API Implementation
-pub struct Pull<B, C> {
+pub struct Pull<B, C>
+where
+    B: Block,
+{
     /// Client
     client: Arc<C>,
 
+    /// Ledger Index
+    index: Option<Arc<dyn LedgerIndex<B>>>,
+
     /// Type Parameter Marker
     __: PhantomData<B>,
 }
 
-impl<B, C> Pull<B, C> {
+impl<B, C> Pull<B, C>
+where
+    B: Block,
+{
     /// Builds a new [`Pull`] RPC API implementation.
     #[inline]
     pub fn new(client: Arc<C>) -> Self {
         Self {
             client,
+            index: None,
             __: PhantomData,
         }
     }
+
+    /// Serves the ledger diffs of the blocks covered by `index` from it instead of the runtime.
+    #[inline]
+    pub fn with_index(mut self, index: Arc<dyn LedgerIndex<B>>) -> Self {
+        self.index = Some(index);
+        self
+    }
+}
+
+impl<B, C> Pull<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: PullLedgerDiffApi<B>,
+{
+    /// Returns the diff of the ledger at the last finalized block since `checkpoint`, from the
+    /// index if it covers the block, and from the runtime API otherwise.
+    #[inline]
+    fn pull(
+        &self,
+        checkpoint: Checkpoint,
+        max_receivers: u64,
+        max_senders: u64,
+    ) -> Result<PullResponse, sp_api::ApiError> {
+        let at = self.client.info().finalized_hash;
+        let checkpoint = RawCheckpoint::from(checkpoint);
+        if let Some(response) = self
+            .index
+            .as_ref()
+            .and_then(|index| index.pull_ledger_diff(at, checkpoint, max_receivers, max_senders))
+        {
+            return Ok(response);
+        }
+        self.client.runtime_api().pull_ledger_diff(
+            &BlockId::hash(at),
+            checkpoint,
+            max_receivers,
+            max_senders,
+        )
+    }
 }
 
 #[async_trait]
@@ -105,9 +182,7 @@ where
         max_receivers: u64,
         max_senders: u64,
     ) -> RpcResult<PullResponse> {
-        let api = self.client.runtime_api();
-        let at = BlockId::hash(self.client.info().finalized_hash);
-        api.pull_ledger_diff(&at, checkpoint.into(), max_receivers, max_senders)
+        self.pull(checkpoint, max_receivers, max_senders)
             .map_err(|err| {
                 CallError::Custom(ErrorObject::owned(
                     PULL_LEDGER_DIFF_ERROR,
@@ -125,9 +200,7 @@ where
         max_receivers: u64,
         max_senders: u64,
     ) -> RpcResult<DensePullResponse> {
-        let api = self.client.runtime_api();
-        let at = BlockId::hash(self.client.info().finalized_hash);
-        api.pull_ledger_diff(&at, checkpoint.into(), max_receivers, max_senders)
+        self.pull(checkpoint, max_receivers, max_senders)
             .map(Into::into)
             .map_err(|err| {
                 CallError::Custom(ErrorObject::owned(
@@ -178,3 +251,74 @@ where
             })
     }
 }
+
+/// Ledger Snapshot API
+#[rpc(server)]
+pub trait LedgerSnapshotApi {
+    /// Returns the information of the snapshot of `ledger` served by the node.
+    #[method(name = "mantaPay_ledger_snapshot_info")]
+    fn ledger_snapshot_info(&self, ledger: Ledger) -> RpcResult<LedgerSnapshotInfo>;
+
+    /// Returns the chunk at `index` of the snapshot file of `ledger` served by the node.
+    #[method(name = "mantaPay_ledger_snapshot_chunk")]
+    fn ledger_snapshot_chunk(&self, ledger: Ledger, index: u32) -> RpcResult<LedgerSnapshotChunk>;
+}
+
+/// Ledger Snapshot RPC API Implementation
+#[derive(Clone, Default)]
+pub struct LedgerSnapshots {
+    /// Snapshot Information and Files by Ledger
+    snapshots: Arc<BTreeMap<Ledger, (LedgerSnapshotInfo, Vec<u8>)>>,
+}
+
+impl LedgerSnapshots {
+    /// Serves the snapshot file `bytes`, checking its header and checksum first, and returns its
+    /// ledger. A previously served snapshot of the same ledger is replaced.
+    #[inline]
+    pub fn insert(&mut self, bytes: Vec<u8>) -> Result<Ledger, SnapshotError> {
+        let snapshot = LedgerSnapshot::from_bytes(&bytes)?;
+        let info = LedgerSnapshotInfo::new(&snapshot, &bytes);
+        Arc::make_mut(&mut self.snapshots).insert(snapshot.ledger, (info, bytes));
+        Ok(snapshot.ledger)
+    }
+
+    /// Returns `true` if no snapshot is served.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Returns the information and file of the snapshot of `ledger`.
+    #[inline]
+    fn get(&self, ledger: Ledger) -> RpcResult<&(LedgerSnapshotInfo, Vec<u8>)> {
+        self.snapshots.get(&ledger).ok_or_else(|| {
+            CallError::Custom(ErrorObject::owned(
+                LEDGER_SNAPSHOT_ERROR,
+                "No snapshot is served for the ledger",
+                Some(format!("{ledger:?}")),
+            ))
+            .into()
+        })
+    }
+}
+
+#[async_trait]
+impl LedgerSnapshotApiServer for LedgerSnapshots {
+    #[inline]
+    fn ledger_snapshot_info(&self, ledger: Ledger) -> RpcResult<LedgerSnapshotInfo> {
+        Ok(self.get(ledger)?.0.clone())
+    }
+
+    #[inline]
+    fn ledger_snapshot_chunk(&self, ledger: Ledger, index: u32) -> RpcResult<LedgerSnapshotChunk> {
+        let (info, bytes) = self.get(ledger)?;
+        LedgerSnapshotChunk::new(bytes, index).ok_or_else(|| {
+            CallError::Custom(ErrorObject::owned(
+                LEDGER_SNAPSHOT_ERROR,
+                "Snapshot chunk index out of range",
+                Some(format!("{index} >= {}", info.chunks)),
+            ))
+            .into()
+        })
+    }
+}
@@ -18,11 +18,15 @@ use crate::{
     fp_decode, id_from_field,
     mock::{
         new_test_ext, Assets, MantaAssetConfig, MantaAssetRegistry, MantaPay,
-        RuntimeOrigin as MockOrigin, Test,
+        RuntimeOrigin as MockOrigin, System, Test,
     },
-    Error, FungibleLedger,
+    Error, Event, FungibleLedger,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GenesisBuild, Get},
+    Hashable,
 };
-use frame_support::{assert_noop, assert_ok};
 use manta_accounting::transfer::test::value_distribution;
 use manta_crypto::{
     arkworks::constraint::fp::Fp,
@@ -46,11 +50,13 @@ use manta_crypto::accumulator::Accumulator;
 use manta_pay::config::{Asset, Authorization, FullParametersRef, Receiver, ToPrivate, ToPublic};
 use manta_primitives::{
     assets::{
-        AssetConfig, AssetRegistry, AssetRegistryMetadata, AssetStorageMetadata,
-        FungibleLedger as _,
+        AssetConfig, AssetLocation, AssetRegistry, AssetRegistryMetadata, AssetStorageMetadata,
+        FungibleLedger as _, TestingDefault,
     },
     constants::{TEST_DEFAULT_ASSET_ED, TEST_DEFAULT_ASSET_ED2},
+    xcm::PrivateDeposit,
 };
+use sp_runtime::traits::BadOrigin;
 use std::{env, path::Path};
 
 /// UTXO Accumulator for Building Circuits
@@ -397,6 +403,159 @@ fn to_private_with_zero_should_not_work() {
     });
 }
 
+/// Tests to_private of an asset whose registration is still pending should fail.
+#[test]
+fn to_private_with_restricted_asset_should_not_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        let asset_id = rng.gen();
+        let total_free_supply: AssetValue = rng.gen();
+        initialize_test(asset_id, total_free_supply + TEST_DEFAULT_ASSET_ED);
+        pallet_asset_manager::PendingAssetRegistrations::<Test>::insert(
+            asset_id,
+            pallet_asset_manager::AssetRegistrationProposal {
+                proposer: ALICE,
+                deposit: 0,
+                location: AssetLocation::default(),
+                metadata: AssetRegistryMetadata::testing_default_insufficient(),
+            },
+        );
+        assert_noop!(
+            MantaPay::to_private(
+                MockOrigin::signed(ALICE),
+                sample_to_private(field_from_id(asset_id), 10, &mut rng)
+            ),
+            Error::<Test>::RestrictedAsset
+        );
+    });
+}
+
+/// Tests that assets held for a private deposit can only be shielded by the matching post.
+#[test]
+fn to_private_from_deposit_should_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = rng.gen();
+        let value = 1_000_000;
+        initialize_test(asset_id, TEST_DEFAULT_ASSET_ED);
+        let post = sample_to_private(field_from_id(asset_id), value, &mut rng);
+        let commitment = post.blake2_256();
+        assert_noop!(
+            MantaPay::to_private_from_deposit(MockOrigin::signed(ALICE), post.clone()),
+            Error::<Test>::NoPendingDeposit
+        );
+
+        // The deposit must match the source of the post.
+        let mismatched_post = sample_to_private(field_from_id(asset_id), value, &mut rng);
+        assert_ok!(MantaPay::deposit(
+            asset_id,
+            value + 1,
+            mismatched_post.blake2_256()
+        ));
+        assert_noop!(
+            MantaPay::to_private_from_deposit(MockOrigin::signed(ALICE), mismatched_post),
+            Error::<Test>::PendingDepositMismatch
+        );
+
+        assert_noop!(
+            MantaPay::deposit(asset_id, 0, commitment),
+            Error::<Test>::ZeroTransfer
+        );
+        assert_ok!(MantaPay::deposit(asset_id, value, commitment));
+        System::assert_last_event(
+            Event::<Test>::PrivateDepositReceived {
+                commitment,
+                asset: post.source(0).unwrap(),
+            }
+            .into(),
+        );
+        assert_noop!(
+            MantaPay::deposit(asset_id, value, commitment),
+            Error::<Test>::PendingDepositExists
+        );
+
+        // The post is signed by anyone and its public source is the pallet account.
+        assert_ok!(MantaPay::to_private_from_deposit(
+            MockOrigin::signed(ALICE),
+            post.clone()
+        ));
+        System::assert_last_event(
+            Event::<Test>::ToPrivate {
+                asset: post.source(0).unwrap(),
+                source: MantaPay::account_id(),
+            }
+            .into(),
+        );
+        assert_eq!(Assets::balance(asset_id, ALICE), TEST_DEFAULT_ASSET_ED);
+        assert_eq!(
+            Assets::balance(asset_id, MantaPay::account_id()),
+            TEST_DEFAULT_ASSET_ED + 2 * value + 1
+        );
+        assert_noop!(
+            MantaPay::to_private_from_deposit(MockOrigin::signed(ALICE), post),
+            Error::<Test>::NoPendingDeposit
+        );
+    });
+}
+
+/// Tests that private deposits which were not shielded can be reclaimed once they expired.
+#[test]
+fn reclaim_deposit_should_work() {
+    let mut rng = OsRng;
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let asset_id = rng.gen();
+        let value = 1_000_000;
+        initialize_test(asset_id, TEST_DEFAULT_ASSET_ED);
+        let post = sample_to_private(field_from_id(asset_id), value, &mut rng);
+        let commitment = post.blake2_256();
+        assert_ok!(MantaPay::deposit(asset_id, value, commitment));
+
+        let expiry = <Test as crate::Config>::DepositExpiry::get();
+        System::set_block_number(expiry);
+        assert_noop!(
+            MantaPay::reclaim_deposit(MockOrigin::signed(ALICE), commitment, ALICE),
+            BadOrigin
+        );
+        assert_noop!(
+            MantaPay::reclaim_deposit(MockOrigin::root(), commitment, ALICE),
+            Error::<Test>::PendingDepositNotExpired
+        );
+
+        System::set_block_number(1 + expiry);
+        assert_ok!(MantaPay::reclaim_deposit(
+            MockOrigin::root(),
+            commitment,
+            ALICE
+        ));
+        System::assert_last_event(
+            Event::<Test>::PrivateDepositReclaimed {
+                commitment,
+                asset: post.source(0).unwrap(),
+                beneficiary: ALICE,
+            }
+            .into(),
+        );
+        assert_eq!(
+            Assets::balance(asset_id, ALICE),
+            TEST_DEFAULT_ASSET_ED + value
+        );
+        assert_eq!(
+            Assets::balance(asset_id, MantaPay::account_id()),
+            TEST_DEFAULT_ASSET_ED
+        );
+        assert_noop!(
+            MantaPay::reclaim_deposit(MockOrigin::root(), commitment, ALICE),
+            Error::<Test>::NoPendingDeposit
+        );
+        assert_noop!(
+            MantaPay::to_private_from_deposit(MockOrigin::signed(ALICE), post),
+            Error::<Test>::NoPendingDeposit
+        );
+    });
+}
+
 /// Tests to_public with zero balance should failed.
 #[test]
 fn to_public_with_zero_should_not_work() {
@@ -494,6 +653,32 @@ fn mint_existing_coin_should_not_work() {
     }
 }
 
+/// Tests that the UTXOs preloaded at genesis are registered in the ledger.
+#[test]
+fn genesis_utxos_should_be_registered() {
+    let mut rng = OsRng;
+    let asset_id = rng.gen();
+    let mint_post = sample_to_private(field_from_id(asset_id), 100, &mut rng);
+    let mut storage = frame_system::GenesisConfig::default()
+        .build_storage::<Test>()
+        .unwrap();
+    GenesisBuild::<Test>::assimilate_storage(
+        &crate::GenesisConfig {
+            senders: vec![],
+            receivers: mint_post.receiver_posts.clone(),
+        },
+        &mut storage,
+    )
+    .unwrap();
+    sp_io::TestExternalities::from(storage).execute_with(|| {
+        initialize_test(asset_id, 32579u128);
+        assert_noop!(
+            MantaPay::to_private(MockOrigin::signed(ALICE), mint_post),
+            Error::<Test>::AssetRegistered
+        );
+    });
+}
+
 /// Tests a [`PrivateTransfer`] transaction.
 #[test]
 fn private_transfer_should_work() {
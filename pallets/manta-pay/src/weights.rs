@@ -44,6 +44,12 @@ pub trait WeightInfo {
 
     /// Returns the [`Weight`] of the [`crate::Pallet::public_transfer`] extrinsic.
     fn public_transfer() -> Weight;
+
+    /// Returns the [`Weight`] of the [`crate::Pallet::to_private_from_deposit`] extrinsic.
+    fn to_private_from_deposit() -> Weight;
+
+    /// Returns the [`Weight`] of the [`crate::Pallet::reclaim_deposit`] extrinsic.
+    fn reclaim_deposit() -> Weight;
 }
 
 /// Concrete Weight Functions
@@ -102,4 +108,28 @@ where
     fn public_transfer() -> Weight {
         Weight::from_ref_time(46_629_000)
     }
+
+    /// ```text
+    /// Storage: MantaPay PendingDeposits (r:1 w:1)
+    /// Storage: MantaPay Balances (r:1 w:1)
+    /// Storage: MantaPay UtxoSet (r:1 w:1)
+    /// Storage: MantaPay ShardTrees (r:1 w:1)
+    /// Storage: MantaPay UtxoSetOutputs (r:0 w:1)
+    /// Storage: MantaPay Shards (r:0 w:1)
+    /// ```
+    fn to_private_from_deposit() -> Weight {
+        Weight::from_ref_time(36_400_000_000)
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().writes(8_u64))
+    }
+    /// ```text
+    /// Storage: MantaPay PendingDeposits (r:1 w:1)
+    /// Storage: Assets Asset (r:1 w:1)
+    /// Storage: Assets Account (r:2 w:2)
+    /// ```
+    fn reclaim_deposit() -> Weight {
+        Weight::from_ref_time(46_629_000)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
 }
@@ -76,11 +76,76 @@ macro_rules! write_const_array {
     };
 }
 
-/// Builds sample transactions for testing.
+/// Returns the hex string of the SCALE encoding of `value`.
+#[inline]
+fn scale_hex<T>(value: &T) -> String
+where
+    T: Encode,
+{
+    value
+        .encode()
+        .iter()
+        .fold(String::from("0x"), |hex, byte| hex + &format!("{byte:02x}"))
+}
+
+/// Mints `count` SBTs with consecutive asset ids and writes their receiver posts as the MantaSBT
+/// genesis configuration of a chain spec.
+///
+/// Usage: `precompute_coins --genesis <count> [target_file]`
+#[inline]
+fn write_genesis(args: &[String]) -> Result<()> {
+    let count: u128 = args
+        .first()
+        .map(|count| count.parse())
+        .transpose()?
+        .unwrap_or(1);
+    let target_file = args
+        .get(1)
+        .map(PathBuf::from)
+        .unwrap_or(env::current_dir()?.join("manta_sbt_genesis.json"));
+    assert!(
+        !target_file.exists(),
+        "Specify a file to place the generated genesis: {target_file:?}.",
+    );
+
+    let directory = tempfile::tempdir().expect("Unable to generate temporary test directory.");
+    println!("[INFO] Temporary Directory: {directory:?}");
+
+    let mut rng = ChaCha20Rng::from_seed([0; 32]);
+    let (proving_context, _, parameters, utxo_accumulator_model) =
+        load_parameters(directory.path()).expect("Unable to load parameters.");
+    let mut utxo_accumulator = UtxoAccumulator::new(utxo_accumulator_model);
+
+    let mut receivers = Vec::new();
+    for id in 1..=count {
+        let to_private = to_private_example(
+            &proving_context.to_private,
+            &parameters,
+            &mut utxo_accumulator,
+            id.into(),
+            1,
+            &mut rng,
+        );
+        receivers.extend(to_private.receiver_posts);
+        println!("[INFO] SBT {id}/{count}");
+    }
+
+    fs::write(
+        target_file,
+        format!("{{\n  \"receivers\": \"{}\"\n}}\n", scale_hex(&receivers)),
+    )?;
+    Ok(directory.close()?)
+}
+
+/// Builds sample transactions for testing, or a genesis ledger with `--genesis`.
 #[inline]
 fn main() -> Result<()> {
-    let target_file = env::args()
-        .nth(1)
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.first().map(String::as_str) == Some("--genesis") {
+        return write_genesis(&args[1..]);
+    }
+    let target_file = args
+        .first()
         .map(PathBuf::from)
         .unwrap_or(env::current_dir()?.join("precomputed_coins.rs"));
     assert!(
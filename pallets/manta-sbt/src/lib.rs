@@ -64,9 +64,9 @@ use frame_support::{
 use frame_system::pallet_prelude::*;
 use manta_support::manta_pay::{
     asset_value_encode, fp_decode, fp_encode, id_from_field, AccountId, AssetValue, Checkpoint,
-    FullIncomingNote, MTParametersError, Proof, PullResponse, ReceiverChunk, StandardAssetId,
-    TransferPost, Utxo, UtxoAccumulatorOutput, UtxoItemHashError, UtxoMerkleTreePath,
-    VerifyingContextError, Wrap, WrapPair,
+    FullIncomingNote, MTParametersError, Proof, PullResponse, ReceiverChunk, ReceiverPost,
+    StandardAssetId, TransferPost, Utxo, UtxoAccumulatorOutput, UtxoItemHashError,
+    UtxoMerkleTreePath, VerifyingContextError, Wrap, WrapPair,
 };
 use sha3::{Digest, Keccak256};
 use sp_core::{H160, H256, U256};
@@ -300,6 +300,53 @@ pub mod pallet {
     pub(super) type UtxoAccumulatorOutputs<T: Config> =
         StorageMap<_, Twox64Concat, UtxoAccumulatorOutput, (), ValueQuery>;
 
+    /// Genesis Configuration
+    ///
+    /// Preloads the SBT ledger, e.g. with the posts written by `precompute_coins --genesis`, so
+    /// that wallet synchronization and load tests can run against a realistically sized ledger.
+    /// The posts are serialized as the hex string of their SCALE encoding.
+    #[pallet::genesis_config]
+    #[derive(Clone)]
+    pub struct GenesisConfig {
+        /// Receiver posts whose UTXOs are inserted into the UTXO set, the shards and the shard
+        /// trees, like the receivers of a mint
+        #[serde(with = "manta_primitives::scale_hex")]
+        pub receivers: Vec<ReceiverPost>,
+    }
+
+    #[cfg(feature = "std")]
+    impl Default for GenesisConfig {
+        #[inline]
+        fn default() -> Self {
+            Self {
+                receivers: Vec::new(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig {
+        #[inline]
+        fn build(&self) {
+            let receivers = self
+                .receivers
+                .iter()
+                .cloned()
+                .map(config::ReceiverPost::try_from)
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Genesis receiver posts must decode.");
+            SBTLedger::<T>(PhantomData)
+                .register_all(
+                    &(Wrap(()), ()),
+                    receivers
+                        .into_iter()
+                        .map(|post| (Wrap(post.utxo), post.note)),
+                )
+                .map_err(|err| Error::<T>::from(ReceiverPostError::from(err)))
+                .expect("Unable to register the genesis UTXOs.");
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T>
     where
@@ -274,6 +274,9 @@ impl AssetConfig<Test> for MantaAssetConfig {
     type AssetRegistry = MantaAssetRegistry;
     type StorageMetadata = AssetStorageMetadata;
     type FungibleLedger = NativeAndNonNative<Test, MantaAssetConfig, Balances, Assets>;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Test {
@@ -284,6 +287,13 @@ impl pallet_asset_manager::Config for Test {
     type AssetConfig = MantaAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId32>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = ConstU128<1_000>;
+    type Slash = ();
+    type PriceFeederOrigin = EnsureRoot<AccountId32>;
+    type NativeUnitsPerSecond = ConstU128<1_000_000_000_000>;
+    type PriceStalenessThreshold = ConstU32<10>;
     type WeightInfo = ();
 }
 
@@ -296,6 +306,8 @@ impl pallet_manta_pay::Config for Test {
     type WeightInfo = pallet_manta_pay::weights::SubstrateWeight<Test>;
     type PalletId = MantaPayPalletId;
     type AssetConfig = MantaAssetConfig;
+    type DepositReclaimOrigin = EnsureRoot<AccountId32>;
+    type DepositExpiry = ConstU32<10>;
 }
 
 parameter_types! {
@@ -21,3 +21,4 @@
 extern crate alloc;
 
 pub mod manta_pay;
+pub mod snapshot;
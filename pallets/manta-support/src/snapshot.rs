@@ -0,0 +1,242 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Ledger Snapshots
+//!
+//! A ledger snapshot holds every receiver and sender of a shielded ledger up to some block,
+//! together with the [`Checkpoint`] from which a wallet continues the synchronization with
+//! `pull_ledger_diff`. Snapshot files are laid out as:
+//!
+//! | Length | Content                                    |
+//! |--------|--------------------------------------------|
+//! | 4      | [`MAGIC`]                                  |
+//! | 2      | [`VERSION`] in little endian               |
+//! | 32     | BLAKE2-256 checksum of the body            |
+//! | ...    | body, the SCALE encoded [`LedgerSnapshot`] |
+
+use crate::manta_pay::{Checkpoint, RawCheckpoint, ReceiverChunk, SenderChunk};
+use alloc::{string::String, vec::Vec};
+use scale_codec::{Decode, DecodeAll, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+#[cfg(feature = "serde")]
+use manta_pay::manta_util::serde::{Deserialize, Serialize};
+
+/// Snapshot File Magic Bytes
+pub const MAGIC: [u8; 4] = *b"MLSS";
+
+/// Snapshot File Format Version
+pub const VERSION: u16 = 1;
+
+/// Snapshot File Header Length
+pub const HEADER_LENGTH: usize = MAGIC.len() + 2 + 32;
+
+/// Length of the Chunks a Snapshot File is Served in
+pub const CHUNK_LENGTH: usize = 1 << 20;
+
+/// Shielded Ledger
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde")
+)]
+#[derive(Clone, Copy, Debug, Decode, Encode, Eq, Hash, Ord, PartialEq, PartialOrd, TypeInfo)]
+pub enum Ledger {
+    /// MantaPay Ledger
+    MantaPay,
+
+    /// MantaSBT Ledger
+    MantaSbt,
+}
+
+impl Ledger {
+    /// Returns the name of the pallet holding the ledger in the runtime.
+    #[inline]
+    pub const fn pallet_name(&self) -> &'static str {
+        match self {
+            Self::MantaPay => "MantaPay",
+            Self::MantaSbt => "MantaSbt",
+        }
+    }
+}
+
+/// Ledger Snapshot
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq)]
+pub struct LedgerSnapshot {
+    /// Ledger
+    pub ledger: Ledger,
+
+    /// Number of the Snapshot Block
+    pub block_number: u64,
+
+    /// Hash of the Snapshot Block
+    pub block_hash: [u8; 32],
+
+    /// Checkpoint after Pulling all Receivers and Senders of the Snapshot
+    pub checkpoint: RawCheckpoint,
+
+    /// Receivers, in the order of `pull_ledger_diff` from the default checkpoint: shard by shard
+    /// and by insertion order within a shard
+    pub receivers: ReceiverChunk,
+
+    /// Senders, in insertion order
+    pub senders: SenderChunk,
+}
+
+impl LedgerSnapshot {
+    /// Writes the snapshot into the bytes of a snapshot file.
+    #[inline]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let body = self.encode();
+        let mut bytes = Vec::with_capacity(HEADER_LENGTH + body.len());
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(BlakeTwo256::hash(&body).as_bytes());
+        bytes.extend(body);
+        bytes
+    }
+
+    /// Reads a snapshot from the bytes of a snapshot file, checking its header and checksum.
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        if bytes.len() < HEADER_LENGTH {
+            return Err(SnapshotError::Truncated);
+        }
+        let (header, body) = bytes.split_at(HEADER_LENGTH);
+        if header[..MAGIC.len()] != MAGIC {
+            return Err(SnapshotError::InvalidMagic);
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        if header[6..] != *BlakeTwo256::hash(body).as_bytes() {
+            return Err(SnapshotError::InvalidChecksum);
+        }
+        Self::decode_all(&mut &body[..]).map_err(|_| SnapshotError::InvalidBody)
+    }
+}
+
+/// Snapshot File Error
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotError {
+    /// The file is shorter than the header.
+    Truncated,
+
+    /// The file does not start with [`MAGIC`].
+    InvalidMagic,
+
+    /// The file format version is not [`VERSION`].
+    UnsupportedVersion(u16),
+
+    /// The checksum of the body does not match the header.
+    InvalidChecksum,
+
+    /// The body is not a SCALE encoded [`LedgerSnapshot`].
+    InvalidBody,
+}
+
+/// Ledger Snapshot Information
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerSnapshotInfo {
+    /// Ledger
+    pub ledger: Ledger,
+
+    /// Snapshot File Format Version
+    pub version: u16,
+
+    /// Number of the Snapshot Block
+    pub block_number: u64,
+
+    /// Hash of the Snapshot Block
+    pub block_hash: [u8; 32],
+
+    /// Checkpoint after Pulling all Receivers and Senders of the Snapshot
+    pub checkpoint: Checkpoint,
+
+    /// Number of Receivers
+    pub receivers: u64,
+
+    /// Number of Senders
+    pub senders: u64,
+
+    /// BLAKE2-256 Checksum of the Snapshot Body
+    pub checksum: [u8; 32],
+
+    /// Length of the Snapshot File
+    pub length: u64,
+
+    /// Number of Chunks the Snapshot File is Served in
+    pub chunks: u32,
+}
+
+impl LedgerSnapshotInfo {
+    /// Builds the information of `snapshot` read from the snapshot file `bytes`.
+    #[inline]
+    pub fn new(snapshot: &LedgerSnapshot, bytes: &[u8]) -> Self {
+        let mut checksum = [0; 32];
+        checksum.copy_from_slice(&bytes[6..HEADER_LENGTH]);
+        Self {
+            ledger: snapshot.ledger,
+            version: VERSION,
+            block_number: snapshot.block_number,
+            block_hash: snapshot.block_hash,
+            checkpoint: snapshot.checkpoint.into(),
+            receivers: snapshot.receivers.len() as u64,
+            senders: snapshot.senders.len() as u64,
+            checksum,
+            length: bytes.len() as u64,
+            chunks: bytes.chunks(CHUNK_LENGTH).count() as u32,
+        }
+    }
+}
+
+/// Ledger Snapshot Chunk
+#[cfg_attr(
+    feature = "serde",
+    derive(Deserialize, Serialize),
+    serde(crate = "manta_util::serde", deny_unknown_fields)
+)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerSnapshotChunk {
+    /// Chunk Index
+    pub index: u32,
+
+    /// Number of Chunks the Snapshot File is Served in
+    pub chunks: u32,
+
+    /// Base64 Encoded Bytes of the Snapshot File
+    pub data: String,
+}
+
+impl LedgerSnapshotChunk {
+    /// Returns the chunk at `index` of the snapshot file `bytes`, if it exists.
+    #[inline]
+    pub fn new(bytes: &[u8], index: u32) -> Option<Self> {
+        let chunk = bytes.chunks(CHUNK_LENGTH).nth(index as usize)?;
+        Some(Self {
+            index,
+            chunks: bytes.chunks(CHUNK_LENGTH).count() as u32,
+            data: base64::encode(chunk),
+        })
+    }
+}
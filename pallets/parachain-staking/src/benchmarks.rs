@@ -18,10 +18,11 @@
 
 //! Benchmarking
 use crate::{
-    AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction, Pallet,
-    Points, Range, Round, ScheduledRequest,
+    AwardedPts, BalanceOf, Call, CandidateBondLessRequest, Config, DelegationAction,
+    JailedCandidates, OfflinePolicy, Pallet, ParachainBondBeneficiary, ParachainBondLock, Points,
+    Range, RewardDestination, Round, ScheduledRequest,
 };
-use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, vec};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, vec, whitelisted_caller};
 use frame_support::traits::{tokens::fungible::Inspect, Currency, Get, OnFinalize, OnInitialize};
 use frame_system::RawOrigin;
 use sp_runtime::{Perbill, Percent};
@@ -167,6 +168,32 @@ benchmarks! {
         assert_eq!(Pallet::<T>::parachain_bond_info().percent, Percent::from_percent(33));
     }
 
+    set_offline_policy {
+        let policy = OfflinePolicy {
+            min_authored: Percent::from_percent(50),
+            max_missed_rounds: 3,
+            jail_rounds: 2,
+            slash: Perbill::from_percent(10),
+        };
+    }: _(RawOrigin::Root, policy)
+    verify {
+        assert_eq!(Pallet::<T>::offline_policy(), policy);
+    }
+
+    set_parachain_bond_beneficiaries {
+        let x in 0..<<T as Config>::MaxParachainBondBeneficiaries as Get<u32>>::get();
+        let beneficiaries: Vec<ParachainBondBeneficiary<T::AccountId>> = (0..x)
+            .map(|i| ParachainBondBeneficiary {
+                account: account("beneficiary", i, 0),
+                weight: i + 1,
+                lock_rounds: 10,
+            })
+            .collect();
+    }: _(RawOrigin::Root, beneficiaries)
+    verify {
+        assert_eq!(Pallet::<T>::parachain_bond_beneficiaries().len() as u32, x);
+    }
+
     // ROOT DISPATCHABLES
 
     set_total_selected {
@@ -186,6 +213,15 @@ benchmarks! {
         assert_eq!(Pallet::<T>::round().length, 1200u32);
     }
 
+    set_collator_commission_bounds {}: _(
+        RawOrigin::Root,
+        Perbill::from_percent(5),
+        Perbill::from_percent(50)
+    )
+    verify {
+        assert_eq!(Pallet::<T>::collator_commission_bounds().max, Perbill::from_percent(50));
+    }
+
     // USER DISPATCHABLES
 
     join_candidates {
@@ -356,6 +392,36 @@ benchmarks! {
         assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_active());
     }
 
+    rejoin {
+        let caller: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        Pallet::<T>::go_offline(RawOrigin::Signed(caller.clone()).into())?;
+        <JailedCandidates<T>>::insert(&caller, Pallet::<T>::round().current);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(Pallet::<T>::candidate_info(&caller).unwrap().is_active());
+        assert!(Pallet::<T>::jailed_until(&caller).is_none());
+    }
+
+    unlock_parachain_bond {
+        let (beneficiary, _) = create_funded_user::<T>("beneficiary", USER_SEED, 0u32.into());
+        let now = Pallet::<T>::round().current;
+        Pallet::<T>::update_parachain_bond_locks(
+            &beneficiary,
+            now,
+            Some(ParachainBondLock { amount: 1u32.into(), unlock_round: now }),
+        );
+        let caller: T::AccountId = whitelisted_caller();
+    }: _(RawOrigin::Signed(caller), beneficiary.clone())
+    verify {
+        assert!(Pallet::<T>::parachain_bond_locks(&beneficiary).is_empty());
+    }
+
     candidate_bond_more {
         let more = min_candidate_stk::<T>();
         let caller: T::AccountId = create_funded_collator::<T>(
@@ -825,6 +891,124 @@ benchmarks! {
         );
     }
 
+    set_reward_destination {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let payee: T::AccountId = account("payee", 0u32, USER_SEED);
+    }: _(RawOrigin::Signed(collator.clone()), RewardDestination::Account(payee.clone()))
+    verify {
+        assert_eq!(
+            Pallet::<T>::reward_destination(&collator),
+            RewardDestination::Account(payee)
+        );
+    }
+
+    set_candidate_commission {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        // worst case is scheduling an increase which replaces a pending one
+        Pallet::<T>::set_candidate_commission(
+            RawOrigin::Signed(collator.clone()).into(),
+            Perbill::from_percent(99),
+        )?;
+    }: _(RawOrigin::Signed(collator.clone()), Perbill::one())
+    verify {
+        assert_eq!(
+            Pallet::<T>::candidate_commission_request(&collator).map(|x| x.commission),
+            Some(Perbill::one())
+        );
+    }
+
+    schedule_redelegate {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let target: T::AccountId = create_funded_collator::<T>(
+            "target",
+            USER_SEED,
+            0u32.into(),
+            true,
+            2u32
+        )?;
+        let delegator_bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let (caller, total) = create_funded_user::<T>("caller", USER_SEED, delegator_bond * 2u32.into());
+        Pallet::<T>::delegate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            total,
+            0u32,
+            0u32
+        )?;
+    }: _(RawOrigin::Signed(caller.clone()), collator.clone(), target.clone(), delegator_bond)
+    verify {
+        assert_eq!(
+            Pallet::<T>::delegation_scheduled_requests(&collator),
+            vec![ScheduledRequest {
+                delegator: caller.clone(),
+                when_executable: 1 + <<T as Config>::RedelegationDelay as Get<u32>>::get(),
+                action: DelegationAction::Redelegate(delegator_bond),
+            }],
+        );
+        assert_eq!(Pallet::<T>::redelegation_target(&collator, &caller), Some(target));
+    }
+
+    execute_redelegate {
+        let collator: T::AccountId = create_funded_collator::<T>(
+            "collator",
+            USER_SEED,
+            0u32.into(),
+            true,
+            1u32
+        )?;
+        let target: T::AccountId = create_funded_collator::<T>(
+            "target",
+            USER_SEED,
+            0u32.into(),
+            true,
+            2u32
+        )?;
+        let delegator_bond = <<T as Config>::MinDelegatorStk as Get<BalanceOf<T>>>::get();
+        let (caller, total) = create_funded_user::<T>("caller", USER_SEED, delegator_bond * 2u32.into());
+        Pallet::<T>::delegate(RawOrigin::Signed(
+            caller.clone()).into(),
+            collator.clone(),
+            total,
+            0u32,
+            0u32
+        )?;
+        Pallet::<T>::schedule_redelegate(
+            RawOrigin::Signed(caller.clone()).into(),
+            collator.clone(),
+            target.clone(),
+            delegator_bond
+        )?;
+        roll_to_and_author::<T>(<<T as Config>::RedelegationDelay as Get<u32>>::get(), collator.clone());
+    }: {
+        Pallet::<T>::execute_delegation_request(
+            RawOrigin::Signed(caller.clone()).into(),
+            caller.clone(),
+            collator.clone()
+        )?;
+    } verify {
+        let state = Pallet::<T>::delegator_state(&caller).expect("delegator still exists, qed");
+        assert_eq!(state.get_bond_amount(&target), Some(delegator_bond));
+        assert_eq!(state.total, total);
+    }
+
     // ON_INITIALIZE
 
     round_transition_on_initialize {
@@ -1151,6 +1335,13 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_collator_commission_bounds() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_collator_commission_bounds());
+        });
+    }
+
     #[test]
     fn bench_join_candidates() {
         new_test_ext().execute_with(|| {
@@ -1298,6 +1489,62 @@ mod tests {
         });
     }
 
+    #[test]
+    fn bench_set_reward_destination() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_reward_destination());
+        });
+    }
+
+    #[test]
+    fn bench_set_candidate_commission() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_candidate_commission());
+        });
+    }
+
+    #[test]
+    fn bench_schedule_redelegate() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_schedule_redelegate());
+        });
+    }
+
+    #[test]
+    fn bench_execute_redelegate() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_execute_redelegate());
+        });
+    }
+
+    #[test]
+    fn bench_set_offline_policy() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_offline_policy());
+        });
+    }
+
+    #[test]
+    fn bench_rejoin() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_rejoin());
+        });
+    }
+
+    #[test]
+    fn bench_set_parachain_bond_beneficiaries() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_set_parachain_bond_beneficiaries());
+        });
+    }
+
+    #[test]
+    fn bench_unlock_parachain_bond() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Pallet::<Test>::test_benchmark_unlock_parachain_bond());
+        });
+    }
+
     #[test]
     fn bench_round_transition_on_initialize() {
         new_test_ext().execute_with(|| {
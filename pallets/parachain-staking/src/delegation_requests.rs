@@ -19,29 +19,50 @@
 use crate::{
     pallet::{
         BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error,
-        Event, Pallet, Round, RoundIndex, Total,
+        Event, LastRedelegation, Pallet, RedelegationTargets, RewardDestinations, Round,
+        RoundIndex, TopDelegations, Total,
     },
-    Delegator, DelegatorStatus,
+    Bond, CapacityStatus, Delegator, DelegatorAdded, DelegatorStatus,
+};
+use frame_support::{
+    dispatch::{DispatchResult, DispatchResultWithPostInfo},
+    ensure,
+    traits::Get,
+    RuntimeDebug,
 };
-use frame_support::{dispatch::DispatchResultWithPostInfo, ensure, traits::Get, RuntimeDebug};
 use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::traits::Saturating;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::traits::{Saturating, Zero};
 use sp_std::{vec, vec::Vec};
 
 /// An action that can be performed upon a delegation
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, PartialOrd, Ord)]
 pub enum DelegationAction<Balance> {
     Revoke(Balance),
     Decrease(Balance),
+    /// Move the amount to the candidate stored in `RedelegationTargets`, the amount stays bonded
+    Redelegate(Balance),
 }
 
-impl<Balance: Copy> DelegationAction<Balance> {
+impl<Balance: Copy + Zero> DelegationAction<Balance> {
     /// Returns the wrapped amount value.
     pub fn amount(&self) -> Balance {
         match self {
             DelegationAction::Revoke(amount) => *amount,
             DelegationAction::Decrease(amount) => *amount,
+            DelegationAction::Redelegate(amount) => *amount,
+        }
+    }
+
+    /// Returns the amount leaving the bond of the delegator once executed, which is accounted in
+    /// its `less_total`.
+    pub fn unbonding_amount(&self) -> Balance {
+        match self {
+            DelegationAction::Redelegate(_) => Zero::zero(),
+            _ => self.amount(),
         }
     }
 }
@@ -168,6 +189,112 @@ impl<T: Config> Pallet<T> {
         Ok(().into())
     }
 
+    /// Schedules a [DelegationAction::Redelegate] for the delegator, moving `amount` from
+    /// `collator` to `target` once executed. The amount keeps earning rewards with `collator`
+    /// until then.
+    pub(crate) fn delegation_schedule_redelegate(
+        collator: T::AccountId,
+        target: T::AccountId,
+        delegator: T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResultWithPostInfo {
+        let state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+        ensure!(
+            collator != target,
+            <Error<T>>::CannotRedelegateToSameCandidate
+        );
+        let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
+        ensure!(
+            !scheduled_requests
+                .iter()
+                .any(|req| req.delegator == delegator),
+            <Error<T>>::PendingDelegationRequestAlreadyExists,
+        );
+
+        let now = <Round<T>>::get().current;
+        if let Some(last) = <LastRedelegation<T>>::get(&delegator) {
+            ensure!(
+                now >= last.saturating_add(T::RedelegationCooldown::get()),
+                <Error<T>>::RedelegationCooldownNotElapsed
+            );
+        }
+
+        let bonded_amount = state
+            .get_bond_amount(&collator)
+            .ok_or(<Error<T>>::DelegationDNE)?;
+        ensure!(bonded_amount >= amount, <Error<T>>::InsufficientBalance);
+        ensure!(
+            amount >= T::MinDelegation::get(),
+            <Error<T>>::DelegationBelowMin
+        );
+        if amount < bonded_amount {
+            ensure!(
+                bonded_amount - amount >= T::MinDelegation::get(),
+                <Error<T>>::DelegationBelowMin
+            );
+        }
+        Self::ensure_redelegation_allowed(&state, &collator, &target, amount)?;
+
+        let when = now.saturating_add(T::RedelegationDelay::get());
+        scheduled_requests.push(ScheduledRequest {
+            delegator: delegator.clone(),
+            action: DelegationAction::Redelegate(amount),
+            when_executable: when,
+        });
+        <DelegationScheduledRequests<T>>::insert(&collator, scheduled_requests);
+        <RedelegationTargets<T>>::insert(&collator, &delegator, &target);
+        <LastRedelegation<T>>::insert(&delegator, now);
+
+        Self::deposit_event(Event::RedelegationScheduled {
+            delegator,
+            from: collator,
+            to: target,
+            amount,
+            execute_round: when,
+        });
+        Ok(().into())
+    }
+
+    /// Ensures `amount` moved from `collator` can be placed with `target`.
+    ///
+    /// Redelegations are only allowed into the top delegations of `target`, so that the moved
+    /// stake keeps earning rewards. When the top delegations are full, the lowest top delegation
+    /// is moved to the bottom delegations, which may kick out the lowest bottom delegation like
+    /// any other delegation to `target` would.
+    fn ensure_redelegation_allowed(
+        state: &Delegator<T::AccountId, BalanceOf<T>>,
+        collator: &T::AccountId,
+        target: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        let target_info = <CandidateInfo<T>>::get(target).ok_or(<Error<T>>::CandidateDNE)?;
+        ensure!(
+            !Self::delegation_request_exists(target, &state.id),
+            <Error<T>>::PendingDelegationRequestAlreadyExists
+        );
+        let existing = state.get_bond_amount(target);
+        if existing.is_none() {
+            // moving the whole delegation frees its slot
+            let frees_slot = state.get_bond_amount(collator) == Some(amount);
+            ensure!(
+                frees_slot
+                    || (state.delegations.0.len() as u32) < T::MaxDelegationsPerDelegator::get(),
+                <Error<T>>::ExceedMaxDelegationsPerDelegator
+            );
+        }
+        if matches!(target_info.top_capacity, CapacityStatus::Full) {
+            let in_top = <TopDelegations<T>>::get(target)
+                .map(|top| top.delegations.iter().any(|d| d.owner == state.id))
+                .unwrap_or(false);
+            let new_amount = existing.unwrap_or_else(Zero::zero).saturating_add(amount);
+            ensure!(
+                in_top || new_amount > target_info.lowest_top_delegation_amount,
+                <Error<T>>::RedelegationNotInTopDelegations
+            );
+        }
+        Ok(())
+    }
+
     /// Cancels the delegator's existing [ScheduledRequest] towards a given collator.
     pub(crate) fn delegation_cancel_request(
         collator: T::AccountId,
@@ -176,9 +303,13 @@ impl<T: Config> Pallet<T> {
         let mut state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
         let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
 
-        let request =
-            Self::cancel_request_with_state(&delegator, &mut state, &mut scheduled_requests)
-                .ok_or(<Error<T>>::PendingDelegationRequestDNE)?;
+        let request = Self::cancel_request_with_state(
+            &collator,
+            &delegator,
+            &mut state,
+            &mut scheduled_requests,
+        )
+        .ok_or(<Error<T>>::PendingDelegationRequestDNE)?;
 
         <DelegationScheduledRequests<T>>::insert(&collator, scheduled_requests);
         <DelegatorState<T>>::insert(&delegator, state);
@@ -192,6 +323,7 @@ impl<T: Config> Pallet<T> {
     }
 
     fn cancel_request_with_state(
+        collator: &T::AccountId,
         delegator: &T::AccountId,
         state: &mut Delegator<T::AccountId, BalanceOf<T>>,
         scheduled_requests: &mut Vec<ScheduledRequest<T::AccountId, BalanceOf<T>>>,
@@ -201,8 +333,11 @@ impl<T: Config> Pallet<T> {
             .position(|req| &req.delegator == delegator)?;
 
         let request = scheduled_requests.remove(request_idx);
-        let amount = request.action.amount();
+        let amount = request.action.unbonding_amount();
         state.less_total = state.less_total.saturating_sub(amount);
+        if matches!(request.action, DelegationAction::Redelegate(_)) {
+            <RedelegationTargets<T>>::remove(collator, delegator);
+        }
         Some(request)
     }
 
@@ -259,6 +394,8 @@ impl<T: Config> Pallet<T> {
                 <DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
                 if leaving {
                     <DelegatorState<T>>::remove(&delegator);
+                    <RewardDestinations<T>>::remove(&delegator);
+                    <LastRedelegation<T>>::remove(&delegator);
                     Self::deposit_event(Event::DelegatorLeft {
                         delegator,
                         unstaked_amount: amount,
@@ -324,6 +461,94 @@ impl<T: Config> Pallet<T> {
                 }
                 Err(<Error<T>>::DelegationDNE.into())
             }
+            DelegationAction::Redelegate(amount) => {
+                let target = <RedelegationTargets<T>>::get(&collator, &delegator)
+                    .ok_or(<Error<T>>::PendingDelegationRequestDNE)?;
+                let bonded_amount = state
+                    .get_bond_amount(&collator)
+                    .ok_or(<Error<T>>::DelegationDNE)?;
+                ensure!(bonded_amount >= amount, <Error<T>>::DelegationBelowMin);
+                Self::ensure_redelegation_allowed(&state, &collator, &target, amount)?;
+
+                // remove from pending requests
+                scheduled_requests.remove(request_idx);
+                <RedelegationTargets<T>>::remove(&collator, &delegator);
+
+                // move the amount out of the source candidate, the bond lock stays untouched
+                let mut collator_info =
+                    <CandidateInfo<T>>::get(&collator).ok_or(<Error<T>>::CandidateDNE)?;
+                if amount == bonded_amount {
+                    state
+                        .delegations
+                        .remove(&Bond::from_owner(collator.clone()));
+                    collator_info.rm_delegation_if_exists::<T>(
+                        &collator,
+                        delegator.clone(),
+                        amount,
+                    )?;
+                } else {
+                    for bond in &mut state.delegations.0 {
+                        if bond.owner == collator {
+                            bond.amount = bond.amount.saturating_sub(amount);
+                        }
+                    }
+                    collator_info.decrease_delegation::<T>(
+                        &collator,
+                        delegator.clone(),
+                        bonded_amount,
+                        amount,
+                    )?;
+                }
+                <CandidateInfo<T>>::insert(&collator, collator_info);
+                let mut new_total_staked = <Total<T>>::get().saturating_sub(amount);
+
+                // move the amount into the target candidate
+                let mut target_info =
+                    <CandidateInfo<T>>::get(&target).ok_or(<Error<T>>::CandidateDNE)?;
+                let in_top = if let Some(existing) = state.get_bond_amount(&target) {
+                    for bond in &mut state.delegations.0 {
+                        if bond.owner == target {
+                            bond.amount = bond.amount.saturating_add(amount);
+                        }
+                    }
+                    new_total_staked = new_total_staked.saturating_add(amount);
+                    target_info.increase_delegation::<T>(
+                        &target,
+                        delegator.clone(),
+                        existing,
+                        amount,
+                    )?
+                } else {
+                    state.delegations.insert(Bond {
+                        owner: target.clone(),
+                        amount,
+                    });
+                    let (delegator_added, less_total_staked) = target_info.add_delegation::<T>(
+                        &target,
+                        Bond {
+                            owner: delegator.clone(),
+                            amount,
+                        },
+                    )?;
+                    new_total_staked = new_total_staked
+                        .saturating_add(amount)
+                        .saturating_sub(less_total_staked.unwrap_or_else(Zero::zero));
+                    matches!(delegator_added, DelegatorAdded::AddedToTop { .. })
+                };
+                <CandidateInfo<T>>::insert(&target, target_info);
+                <Total<T>>::put(new_total_staked);
+
+                <DelegationScheduledRequests<T>>::insert(&collator, scheduled_requests);
+                <DelegatorState<T>>::insert(&delegator, state);
+                Self::deposit_event(Event::Redelegated {
+                    delegator,
+                    from: collator,
+                    to: target,
+                    amount,
+                    in_top,
+                });
+                Ok(().into())
+            }
         }
     }
 
@@ -353,8 +578,12 @@ impl<T: Config> Pallet<T> {
             let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
 
             // cancel any existing requests
-            let request =
-                Self::cancel_request_with_state(&delegator, &mut state, &mut scheduled_requests);
+            let request = Self::cancel_request_with_state(
+                &collator,
+                &delegator,
+                &mut state,
+                &mut scheduled_requests,
+            );
             let request = match request {
                 Some(revoke_req) if matches!(revoke_req.action, DelegationAction::Revoke(_)) => {
                     existing_revoke_count += 1;
@@ -425,7 +654,12 @@ impl<T: Config> Pallet<T> {
         for bond in state.delegations.0.clone() {
             let collator = bond.owner;
             let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(&collator);
-            Self::cancel_request_with_state(&delegator, &mut state, &mut scheduled_requests);
+            Self::cancel_request_with_state(
+                &collator,
+                &delegator,
+                &mut state,
+                &mut scheduled_requests,
+            );
             updated_scheduled_requests.push((collator, scheduled_requests));
         }
 
@@ -478,6 +712,8 @@ impl<T: Config> Pallet<T> {
                 Self::delegation_remove_request_with_state(&bond.owner, &delegator, &mut state);
             }
             <DelegatorState<T>>::remove(&delegator);
+            <RewardDestinations<T>>::remove(&delegator);
+            <LastRedelegation<T>>::remove(&delegator);
             Self::deposit_event(Event::DelegatorLeft {
                 delegator,
                 unstaked_amount: state.total,
@@ -540,6 +776,8 @@ impl<T: Config> Pallet<T> {
             unstaked_amount,
         });
         <DelegatorState<T>>::remove(&delegator);
+        <RewardDestinations<T>>::remove(&delegator);
+        <LastRedelegation<T>>::remove(&delegator);
 
         Ok(().into())
     }
@@ -559,8 +797,11 @@ impl<T: Config> Pallet<T> {
 
         if let Some(request_idx) = maybe_request_idx {
             let request = scheduled_requests.remove(request_idx);
-            let amount = request.action.amount();
+            let amount = request.action.unbonding_amount();
             state.less_total = state.less_total.saturating_sub(amount);
+            if matches!(request.action, DelegationAction::Redelegate(_)) {
+                <RedelegationTargets<T>>::remove(collator, delegator);
+            }
             <DelegationScheduledRequests<T>>::insert(collator, scheduled_requests);
         }
     }
@@ -615,7 +856,7 @@ mod tests {
             },
         ];
         let removed_request =
-            <Pallet<Test>>::cancel_request_with_state(&1, &mut state, &mut scheduled_requests);
+            <Pallet<Test>>::cancel_request_with_state(&2, &1, &mut state, &mut scheduled_requests);
 
         assert_eq!(
             removed_request,
@@ -666,7 +907,7 @@ mod tests {
             action: DelegationAction::Decrease(50),
         }];
         let removed_request =
-            <Pallet<Test>>::cancel_request_with_state(&1, &mut state, &mut scheduled_requests);
+            <Pallet<Test>>::cancel_request_with_state(&2, &1, &mut state, &mut scheduled_requests);
 
         assert_eq!(removed_request, None,);
         assert_eq!(
@@ -26,18 +26,23 @@
 //! At the start of every round,
 //! * issuance is calculated for collators (and their delegators) for block authoring
 //! `T::RewardPaymentDelay` rounds ago
+//! * candidates that authored too few blocks in the last round for `OfflinePolicy::max_missed_rounds`
+//! consecutive rounds are jailed and part of their self bond is slashed into the treasury
 //! * a new set of collators is chosen from the candidates
 //!
 //! Immediately following a round change, payments are made once-per-block until all payments have
 //! been made. In each such block, one collator is chosen for a rewards payment and is paid along
-//! with each of its top `T::MaxTopDelegationsPerCandidate` delegators.
+//! with each of its top `T::MaxTopDelegationsPerCandidate` delegators. Further collators are paid
+//! in `on_idle` as long as the remaining block weight allows, and any signed account may pay a
+//! collator of a round still awaiting payment by calling `payout_stakers`.
 //!
 //! To join the set of candidates, call `join_candidates` with `bond >= MinCandidateStk`.
 //! To leave the set of candidates, call `schedule_leave_candidates`. If the call succeeds,
 //! the collator is removed from the pool of candidates so they cannot be selected for future
 //! collator sets, but they are not unbonded until their exit request is executed. Any signed
 //! account may trigger the exit `T::LeaveCandidatesDelay` rounds after the round in which the
-//! original request was made.
+//! original request was made. Jailed candidates return to the pool by calling `rejoin` once
+//! `OfflinePolicy::jail_rounds` rounds have passed.
 //!
 //! To join the set of delegators, call `delegate` and pass in an account that is
 //! already a collator candidate and `bond >= MinDelegatorStk`. Each delegator can delegate up to
@@ -45,6 +50,8 @@
 //!
 //! To revoke a delegation, call `revoke_delegation` with the collator candidate's account.
 //! To leave the set of delegators and revoke all delegations, call `leave_delegators`.
+//! To move stake to another collator candidate without unbonding it, call `schedule_redelegate`
+//! and execute the request `T::RedelegationDelay` rounds later.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::all)]
@@ -52,10 +59,17 @@
 mod delegation_requests;
 pub mod inflation;
 pub mod migrations;
+mod queries;
 pub mod traits;
 pub mod types;
 pub mod weights;
 
+#[cfg(feature = "rpc")]
+pub mod rpc;
+
+#[cfg(feature = "runtime")]
+pub mod runtime;
+
 #[cfg(any(test, feature = "runtime-benchmarks"))]
 #[allow(clippy::all)]
 mod benchmarks;
@@ -96,10 +110,13 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use parity_scale_codec::Decode;
     use sp_runtime::{
-        traits::{Saturating, Zero},
+        traits::{SaturatedConversion, Saturating, Zero},
         Perbill, Percent,
     };
-    use sp_std::{collections::btree_map::BTreeMap, prelude::*};
+    use sp_std::{
+        collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+        prelude::*,
+    };
 
     /// Pallet for parachain staking
     #[pallet::pallet]
@@ -114,6 +131,10 @@ pub mod pallet {
 
     pub const COLLATOR_LOCK_ID: LockIdentifier = *b"stkngcol";
     pub const DELEGATOR_LOCK_ID: LockIdentifier = *b"stkngdel";
+    /// Points awarded to the author of each block
+    pub(crate) const POINTS_PER_BLOCK: RewardPoint = 20;
+    /// Maximum keys removed from each payout snapshot map of a paid round per block
+    pub(crate) const MAX_PAYOUT_CLEANUP_KEYS: u32 = 64;
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -150,6 +171,15 @@ pub mod pallet {
         /// Number of rounds that delegation less requests must wait before executable
         #[pallet::constant]
         type DelegationBondLessDelay: Get<RoundIndex>;
+        /// Number of rounds candidate requests to increase commission must wait to take effect
+        #[pallet::constant]
+        type CommissionIncreaseDelay: Get<RoundIndex>;
+        /// Number of rounds that redelegation requests must wait before executable
+        #[pallet::constant]
+        type RedelegationDelay: Get<RoundIndex>;
+        /// Minimum number of rounds between two redelegations scheduled by the same delegator
+        #[pallet::constant]
+        type RedelegationCooldown: Get<RoundIndex>;
         /// Number of rounds after which block authors are rewarded
         #[pallet::constant]
         type RewardPaymentDelay: Get<RoundIndex>;
@@ -165,6 +195,13 @@ pub mod pallet {
         /// Maximum delegations per delegator
         #[pallet::constant]
         type MaxDelegationsPerDelegator: Get<u32>;
+        /// Maximum beneficiaries the parachain bond reserve can be split across
+        #[pallet::constant]
+        type MaxParachainBondBeneficiaries: Get<u32>;
+        /// Maximum pending parachain bond allocations per beneficiary, further allocations are
+        /// merged into the one unlocking last
+        #[pallet::constant]
+        type MaxParachainBondLocks: Get<u32>;
         /// Default commission due to collators, is `CollatorCommission` storage value in genesis
         #[pallet::constant]
         type DefaultCollatorCommission: Get<Perbill>;
@@ -188,6 +225,9 @@ pub mod pallet {
         type MinDelegatorStk: Get<BalanceOf<Self>>;
         /// Get the current block author
         type BlockAuthor: Get<Self::AccountId>;
+        /// Treasury account, receives rewards of stakers with `RewardDestination::Treasury` and
+        /// rewards which cannot be paid to any other destination
+        type TreasuryAccount: Get<Self::AccountId>;
         /// Handler to notify the runtime when a collator is paid.
         /// If you don't need it, you can specify the type `()`.
         type OnCollatorPayout: OnCollatorPayout<Self::AccountId, BalanceOf<Self>>;
@@ -245,6 +285,20 @@ pub mod pallet {
         PalletAlreadyInitialized,
         NotEnoughCollatorsForPalletInit,
         InvalidState,
+        CandidateOrDelegatorDNE,
+        CommissionOutOfBounds,
+        InvalidCommissionBounds,
+        CannotRedelegateToSameCandidate,
+        RedelegationCooldownNotElapsed,
+        RedelegationNotInTopDelegations,
+        CannotGoOnlineIfJailed,
+        CandidateNotJailed,
+        CandidateCannotRejoinYet,
+        TooManyParachainBondBeneficiaries,
+        InvalidParachainBondBeneficiaries,
+        NoParachainBondToUnlock,
+        DelayedPayoutDNE,
+        AwardedPtsDNE,
     }
 
     #[pallet::event]
@@ -370,6 +424,43 @@ pub mod pallet {
             cancelled_request: CancelledScheduledRequest<BalanceOf<T>>,
             collator: T::AccountId,
         },
+        /// Delegator requested to move part of a delegation to another candidate.
+        RedelegationScheduled {
+            delegator: T::AccountId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            execute_round: RoundIndex,
+        },
+        /// Delegation moved to another candidate without being unbonded.
+        Redelegated {
+            delegator: T::AccountId,
+            from: T::AccountId,
+            to: T::AccountId,
+            amount: BalanceOf<T>,
+            in_top: bool,
+        },
+        /// Candidate authored too few blocks in the round.
+        CandidateMissedRound {
+            candidate: T::AccountId,
+            round: RoundIndex,
+            authored: u32,
+            expected: u32,
+            missed_rounds: u32,
+        },
+        /// Candidate was removed from the candidate pool for authoring too few blocks.
+        CandidateJailed {
+            candidate: T::AccountId,
+            rejoin_round: RoundIndex,
+        },
+        /// Part of the self bond of a jailed candidate was slashed into the treasury.
+        CandidateSlashed {
+            candidate: T::AccountId,
+            amount: BalanceOf<T>,
+            new_bond: BalanceOf<T>,
+        },
+        /// Jailed candidate rejoined the set of collator candidates.
+        CandidateRejoined { candidate: T::AccountId },
         /// New delegation (increase of the existing one).
         Delegation {
             delegator: T::AccountId,
@@ -389,6 +480,22 @@ pub mod pallet {
             account: T::AccountId,
             rewards: BalanceOf<T>,
         },
+        /// Paid the rewards of a collator or delegator to its reward destination account.
+        RewardedToDestination {
+            account: T::AccountId,
+            destination: T::AccountId,
+            rewards: BalanceOf<T>,
+        },
+        /// Reward destination account did not exist at payout, rewards fell back to the next one.
+        RewardDestinationUnavailable {
+            account: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        },
+        /// Reward destination of a collator or delegator (re)set.
+        RewardDestinationSet {
+            account: T::AccountId,
+            destination: RewardDestination<T::AccountId>,
+        },
         /// Transferred to account which holds funds reserved for parachain bond.
         ReservedForParachainBond {
             account: T::AccountId,
@@ -401,6 +508,21 @@ pub mod pallet {
         },
         /// Percent of inflation reserved for parachain bond (re)set.
         ParachainBondReservePercentSet { old: Percent, new: Percent },
+        /// Beneficiaries splitting the parachain bond reserve (re)set.
+        ParachainBondBeneficiariesSet {
+            beneficiaries: Vec<ParachainBondBeneficiary<T::AccountId>>,
+        },
+        /// Funds reserved for parachain bond are held in reserve in the beneficiary account.
+        ParachainBondLocked {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            unlock_round: RoundIndex,
+        },
+        /// Parachain bond allocations reached their unlock round.
+        ParachainBondUnlocked {
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
         /// Annual inflation input (first 3) was used to derive new per-round inflation (last 3)
         InflationSet {
             annual_min: Perbill,
@@ -420,6 +542,25 @@ pub mod pallet {
         TotalSelectedSet { old: u32, new: u32 },
         /// Set collator commission to this value.
         CollatorCommissionSet { old: Perbill, new: Perbill },
+        /// Set the bounds for candidate commission to these values.
+        CollatorCommissionBoundsSet { min: Perbill, max: Perbill },
+        /// Set the policy applied to candidates authoring too few blocks.
+        OfflinePolicySet {
+            old: OfflinePolicy,
+            new: OfflinePolicy,
+        },
+        /// Candidate commission changed.
+        CandidateCommissionSet {
+            candidate: T::AccountId,
+            old: Perbill,
+            new: Perbill,
+        },
+        /// Candidate requested to increase commission.
+        CandidateCommissionIncreaseScheduled {
+            candidate: T::AccountId,
+            commission: Perbill,
+            execute_round: RoundIndex,
+        },
         /// Set blocks per round
         BlocksPerRoundSet {
             current_round: RoundIndex,
@@ -439,12 +580,20 @@ pub mod pallet {
 
             let mut round = <Round<T>>::get();
             if round.should_update(n) {
+                // number of blocks of the ending round, which may differ from its length if the
+                // length was changed during the round
+                let ended_round_length = (n - round.first).saturated_into::<u32>();
                 // mutate round
                 round.update(n);
                 // notify that new round begin
                 weight = weight.saturating_add(T::OnNewRound::on_new_round(round.current));
                 // pay all stakers for T::RewardPaymentDelay rounds ago
-                Self::prepare_staking_payouts(round.current);
+                weight = weight.saturating_add(Self::prepare_staking_payouts(round.current));
+                // jail candidates which authored too few blocks in the last round
+                weight = weight.saturating_add(Self::jail_offline_candidates(
+                    round.current,
+                    ended_round_length,
+                ));
                 // select top collator candidates for next round
                 let (collator_count, delegation_count, total_staked) =
                     Self::select_top_candidates(round.current);
@@ -467,6 +616,7 @@ pub mod pallet {
             }
 
             weight = weight.saturating_add(Self::handle_delayed_payouts(round.current));
+            weight = weight.saturating_add(Self::handle_payout_cleanups());
 
             // add on_finalize weight
             weight = weight.saturating_add(
@@ -481,6 +631,9 @@ pub mod pallet {
         fn on_finalize(_n: T::BlockNumber) {
             Self::award_points_to_block_author();
         }
+        fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+            Self::handle_idle_payouts(<Round<T>>::get().current, remaining_weight)
+        }
     }
 
     #[pallet::storage]
@@ -488,6 +641,23 @@ pub mod pallet {
     /// Commission percent taken off of rewards for all collators
     type CollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn collator_commission_bounds)]
+    /// Bounds within which candidates may set their own commission
+    type CollatorCommissionBounds<T: Config> = StorageValue<_, CommissionBounds, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission)]
+    /// Commission set by each candidate, `CollatorCommission` applies if unset
+    pub(crate) type CandidateCommission<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, Perbill, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn candidate_commission_request)]
+    /// Pending commission increase per candidate, applied at the first snapshot once due
+    pub(crate) type CandidateCommissionRequests<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, CandidateCommissionIncreaseRequest, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn total_selected)]
     /// The total candidates selected every round
@@ -499,6 +669,24 @@ pub mod pallet {
     type ParachainBondInfo<T: Config> =
         StorageValue<_, ParachainBondConfig<T::AccountId>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn parachain_bond_beneficiaries)]
+    /// Beneficiaries splitting the parachain bond reserve by weight, replacing the
+    /// `ParachainBondInfo` account unless empty
+    pub(crate) type ParachainBondBeneficiaries<T: Config> =
+        StorageValue<_, Vec<ParachainBondBeneficiary<T::AccountId>>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn parachain_bond_locks)]
+    /// Parachain bond allocations still held in reserve in each beneficiary account
+    pub(crate) type ParachainBondLocks<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<ParachainBondLock<BalanceOf<T>>, T::MaxParachainBondLocks>,
+        ValueQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn round)]
     /// Current round index and next round scheduled transition
@@ -532,6 +720,42 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn redelegation_target)]
+    /// Target candidate of each pending redelegation, keyed by source candidate and delegator
+    pub(crate) type RedelegationTargets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        T::AccountId,
+        T::AccountId,
+        OptionQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn last_redelegation)]
+    /// Round in which each delegator last scheduled a redelegation
+    pub(crate) type LastRedelegation<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn offline_policy)]
+    /// Policy applied to selected candidates which author too few blocks
+    pub(crate) type OfflinePolicyConfig<T: Config> = StorageValue<_, OfflinePolicy, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn missed_rounds)]
+    /// Number of consecutive rounds in which each selected candidate authored too few blocks
+    pub(crate) type MissedRounds<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn jailed_until)]
+    /// Jailed candidates and the round from which they may rejoin the candidate pool
+    pub(crate) type JailedCandidates<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, RoundIndex, OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn top_delegations)]
     /// Top delegations for collator candidate
@@ -583,12 +807,31 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn at_stake_commission)]
+    /// Snapshot of collator commission at the start of the round
+    pub type AtStakeCommission<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        RoundIndex,
+        Twox64Concat,
+        T::AccountId,
+        Perbill,
+        OptionQuery,
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn delayed_payouts)]
     /// Delayed payouts
     pub type DelayedPayouts<T: Config> =
         StorageMap<_, Twox64Concat, RoundIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
 
+    #[pallet::storage]
+    /// Paid rounds whose `AwardedPts`, `AtStake` and `AtStakeCommission` snapshots are still being
+    /// removed, `MAX_PAYOUT_CLEANUP_KEYS` per map and block
+    pub(crate) type PendingPayoutCleanups<T: Config> =
+        StorageMap<_, Twox64Concat, RoundIndex, (), OptionQuery>;
+
     #[pallet::storage]
     #[pallet::getter(fn staked)]
     /// Total counted stake for selected candidates in the round
@@ -617,6 +860,12 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::storage]
+    #[pallet::getter(fn reward_destination)]
+    /// Reward destination of each collator or delegator, `RewardDestination::Staked` if unset
+    pub type RewardDestinations<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, RewardDestination<T::AccountId>, ValueQuery>;
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub candidates: Vec<(T::AccountId, BalanceOf<T>)>,
@@ -1027,6 +1276,8 @@ pub mod pallet {
                         // since it is assumed that they were removed incrementally before only the
                         // last delegation was left.
                         <DelegatorState<T>>::remove(&bond.owner);
+                        <RewardDestinations<T>>::remove(&bond.owner);
+                        <LastRedelegation<T>>::remove(&bond.owner);
                         <T as Config>::Currency::remove_lock(DELEGATOR_LOCK_ID, &bond.owner);
                     } else {
                         <DelegatorState<T>>::insert(&bond.owner, delegator);
@@ -1057,6 +1308,11 @@ pub mod pallet {
             // return stake to collator
             <T as Config>::Currency::remove_lock(COLLATOR_LOCK_ID, &candidate);
             <CandidateInfo<T>>::remove(&candidate);
+            <RewardDestinations<T>>::remove(&candidate);
+            <CandidateCommission<T>>::remove(&candidate);
+            <CandidateCommissionRequests<T>>::remove(&candidate);
+            <MissedRounds<T>>::remove(&candidate);
+            <JailedCandidates<T>>::remove(&candidate);
             <DelegationScheduledRequests<T>>::remove(&candidate);
             <TopDelegations<T>>::remove(&candidate);
             <BottomDelegations<T>>::remove(&candidate);
@@ -1081,12 +1337,21 @@ pub mod pallet {
             let collator = ensure_signed(origin)?;
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(state.is_leaving(), Error::<T>::CandidateNotLeaving);
-            state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             ensure!(
                 candidates.0.len() as u32 <= candidate_count,
                 Error::<T>::TooLowCandidateCountWeightHintCancelLeaveCandidates
             );
+            if <JailedCandidates<T>>::contains_key(&collator) {
+                // jailed candidates stay out of the candidate pool until they `rejoin`
+                state.go_offline();
+                <CandidateInfo<T>>::insert(&collator, state);
+                Self::deposit_event(Event::CancelledCandidateExit {
+                    candidate: collator,
+                });
+                return Ok(().into());
+            }
+            state.go_online();
             ensure!(
                 candidates.insert(Bond {
                     owner: collator.clone(),
@@ -1127,6 +1392,10 @@ pub mod pallet {
             let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
             ensure!(!state.is_active(), Error::<T>::AlreadyActive);
             ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            ensure!(
+                !<JailedCandidates<T>>::contains_key(&collator),
+                Error::<T>::CannotGoOnlineIfJailed
+            );
             state.go_online();
             let mut candidates = <CandidatePool<T>>::get();
             ensure!(
@@ -1355,7 +1624,10 @@ pub mod pallet {
             Self::delegation_schedule_bond_decrease(candidate, delegator, less)
         }
         #[pallet::call_index(25)]
-        #[pallet::weight(<T as Config>::WeightInfo::execute_delegator_bond_less())]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::execute_delegator_bond_less()
+                .max(<T as Config>::WeightInfo::execute_redelegate())
+        )]
         /// Execute pending request to change an existing delegation
         pub fn execute_delegation_request(
             origin: OriginFor<T>,
@@ -1375,6 +1647,249 @@ pub mod pallet {
             let delegator = ensure_signed(origin)?;
             Self::delegation_cancel_request(candidate, delegator)
         }
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_reward_destination())]
+        /// Set where the staking rewards of the caller are paid
+        /// - only callable by collator candidates and delegators
+        /// - if the destination does not exist at payout, rewards fall back to the staking account
+        /// and then to the treasury
+        pub fn set_reward_destination(
+            origin: OriginFor<T>,
+            destination: RewardDestination<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            let account = ensure_signed(origin)?;
+            ensure!(
+                Self::is_candidate(&account) || Self::is_delegator(&account),
+                Error::<T>::CandidateOrDelegatorDNE
+            );
+            ensure!(
+                <RewardDestinations<T>>::get(&account) != destination,
+                Error::<T>::NoWritingSameValue
+            );
+            if destination == RewardDestination::Staked {
+                <RewardDestinations<T>>::remove(&account);
+            } else {
+                <RewardDestinations<T>>::insert(&account, destination.clone());
+            }
+            Self::deposit_event(Event::RewardDestinationSet {
+                account,
+                destination,
+            });
+            Ok(().into())
+        }
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_collator_commission_bounds())]
+        /// Set the bounds within which candidates may set their own commission
+        /// - candidate commissions outside of the new bounds are clamped into them
+        pub fn set_collator_commission_bounds(
+            origin: OriginFor<T>,
+            min: Perbill,
+            max: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            frame_system::ensure_root(origin)?;
+            let new = CommissionBounds { min, max };
+            ensure!(new.is_valid(), Error::<T>::InvalidCommissionBounds);
+            ensure!(
+                <CollatorCommissionBounds<T>>::get() != new,
+                Error::<T>::NoWritingSameValue
+            );
+            <CollatorCommissionBounds<T>>::put(new);
+            Self::deposit_event(Event::CollatorCommissionBoundsSet { min, max });
+            Ok(().into())
+        }
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_candidate_commission())]
+        /// Set the commission of the calling collator candidate
+        /// - decreases take effect immediately
+        /// - increases take effect `CommissionIncreaseDelay` rounds later, replacing any pending
+        /// increase; setting the current commission cancels a pending increase
+        pub fn set_candidate_commission(
+            origin: OriginFor<T>,
+            #[pallet::compact] new: Perbill,
+        ) -> DispatchResultWithPostInfo {
+            let candidate = ensure_signed(origin)?;
+            ensure!(Self::is_candidate(&candidate), Error::<T>::CandidateDNE);
+            ensure!(
+                <CollatorCommissionBounds<T>>::get().contains(new),
+                Error::<T>::CommissionOutOfBounds
+            );
+            let old = Self::candidate_commission_rate(&candidate);
+            let pending = <CandidateCommissionRequests<T>>::take(&candidate);
+            if new > old {
+                let when = <Round<T>>::get()
+                    .current
+                    .saturating_add(T::CommissionIncreaseDelay::get());
+                <CandidateCommissionRequests<T>>::insert(
+                    &candidate,
+                    CandidateCommissionIncreaseRequest {
+                        commission: new,
+                        when_executable: when,
+                    },
+                );
+                Self::deposit_event(Event::CandidateCommissionIncreaseScheduled {
+                    candidate,
+                    commission: new,
+                    execute_round: when,
+                });
+            } else {
+                ensure!(
+                    new != old || pending.is_some(),
+                    Error::<T>::NoWritingSameValue
+                );
+                <CandidateCommission<T>>::insert(&candidate, new);
+                Self::deposit_event(Event::CandidateCommissionSet {
+                    candidate,
+                    old,
+                    new,
+                });
+            }
+            Ok(().into())
+        }
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_redelegate())]
+        /// Request to move `amount` of the delegation to `from` over to `to`
+        /// - the amount stays bonded and keeps earning with `from` until executed
+        /// - executable after `RedelegationDelay` rounds through `execute_delegation_request`
+        /// - at most one redelegation per `RedelegationCooldown` rounds
+        pub fn schedule_redelegate(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+            to: T::AccountId,
+            #[pallet::compact] amount: BalanceOf<T>,
+        ) -> DispatchResultWithPostInfo {
+            let delegator = ensure_signed(origin)?;
+            Self::delegation_schedule_redelegate(from, to, delegator, amount)
+        }
+        #[pallet::call_index(31)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_offline_policy())]
+        /// Set the policy applied to selected candidates which author too few blocks
+        /// - candidates authoring less than `min_authored` of their expected blocks for
+        /// `max_missed_rounds` consecutive rounds are jailed for `jail_rounds` rounds
+        /// - `slash` of the self bond of jailed candidates is transferred to the treasury
+        pub fn set_offline_policy(
+            origin: OriginFor<T>,
+            new: OfflinePolicy,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            let old = <OfflinePolicyConfig<T>>::get();
+            ensure!(old != new, Error::<T>::NoWritingSameValue);
+            <OfflinePolicyConfig<T>>::put(new);
+            Self::deposit_event(Event::OfflinePolicySet { old, new });
+            Ok(().into())
+        }
+        #[pallet::call_index(32)]
+        #[pallet::weight(<T as Config>::WeightInfo::rejoin())]
+        /// Rejoin the set of collator candidates after being jailed for authoring too few blocks
+        /// - the self bond must be topped up to `MinCandidateStk` if it was slashed below it
+        pub fn rejoin(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+            let collator = ensure_signed(origin)?;
+            let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+            let rejoin_round =
+                <JailedCandidates<T>>::get(&collator).ok_or(Error::<T>::CandidateNotJailed)?;
+            ensure!(
+                <Round<T>>::get().current >= rejoin_round,
+                Error::<T>::CandidateCannotRejoinYet
+            );
+            ensure!(!state.is_leaving(), Error::<T>::CannotGoOnlineIfLeaving);
+            ensure!(
+                state.bond >= T::MinCandidateStk::get(),
+                Error::<T>::CandidateBondBelowMin
+            );
+            state.go_online();
+            let mut candidates = <CandidatePool<T>>::get();
+            ensure!(
+                candidates.insert(Bond {
+                    owner: collator.clone(),
+                    amount: state.total_counted
+                }),
+                Error::<T>::AlreadyActive
+            );
+            <CandidatePool<T>>::put(candidates);
+            <CandidateInfo<T>>::insert(&collator, state);
+            <JailedCandidates<T>>::remove(&collator);
+            <MissedRounds<T>>::remove(&collator);
+            Self::deposit_event(Event::CandidateRejoined {
+                candidate: collator,
+            });
+            Ok(().into())
+        }
+        #[pallet::call_index(33)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::set_parachain_bond_beneficiaries(beneficiaries.len() as u32)
+        )]
+        /// Split the parachain bond reserve across `beneficiaries` by weight
+        /// - an empty list sends the whole reserve to the `ParachainBondInfo` account
+        /// - allocations to beneficiaries with `lock_rounds` stay locked for that many rounds
+        pub fn set_parachain_bond_beneficiaries(
+            origin: OriginFor<T>,
+            beneficiaries: Vec<ParachainBondBeneficiary<T::AccountId>>,
+        ) -> DispatchResultWithPostInfo {
+            T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+            ensure!(
+                beneficiaries.len() as u32 <= T::MaxParachainBondBeneficiaries::get(),
+                Error::<T>::TooManyParachainBondBeneficiaries
+            );
+            let mut accounts = BTreeSet::new();
+            for beneficiary in beneficiaries.iter() {
+                ensure!(
+                    !beneficiary.weight.is_zero() && accounts.insert(beneficiary.account.clone()),
+                    Error::<T>::InvalidParachainBondBeneficiaries
+                );
+            }
+            ensure!(
+                <ParachainBondBeneficiaries<T>>::get() != beneficiaries,
+                Error::<T>::NoWritingSameValue
+            );
+            <ParachainBondBeneficiaries<T>>::put(beneficiaries.clone());
+            Self::deposit_event(Event::ParachainBondBeneficiariesSet { beneficiaries });
+            Ok(().into())
+        }
+        #[pallet::call_index(34)]
+        #[pallet::weight(<T as Config>::WeightInfo::unlock_parachain_bond())]
+        /// Unlock the parachain bond allocations of `beneficiary` which reached their unlock round
+        pub fn unlock_parachain_bond(
+            origin: OriginFor<T>,
+            beneficiary: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let now = <Round<T>>::get().current;
+            ensure!(
+                !Self::update_parachain_bond_locks(&beneficiary, now, None).is_zero(),
+                Error::<T>::NoParachainBondToUnlock
+            );
+            Ok(().into())
+        }
+        #[pallet::call_index(35)]
+        #[pallet::weight(<T as Config>::WeightInfo::pay_one_collator_reward(
+            T::MaxTopDelegationsPerCandidate::get()
+        ))]
+        /// Pay `collator` and its delegators for `round` if they were not paid yet
+        /// - callable by any signed account, e.g. to pay out rounds which are no longer due for
+        /// payment in `on_initialize` or `on_idle`
+        /// - the payout state of `round` is cleaned up once its last collator is paid, its
+        /// snapshots in the following blocks
+        pub fn payout_stakers(
+            origin: OriginFor<T>,
+            round: RoundIndex,
+            collator: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+            let payout_info =
+                <DelayedPayouts<T>>::get(round).ok_or(Error::<T>::DelayedPayoutDNE)?;
+            let pts = <AwardedPts<T>>::take(round, &collator);
+            ensure!(!pts.is_zero(), Error::<T>::AwardedPtsDNE);
+            let total_points = <Points<T>>::get(round);
+            let (_, mut weight) =
+                Self::pay_collator_reward(round, collator, pts, total_points, &payout_info);
+            // read AwardedPts
+            weight = weight.saturating_add(T::DbWeight::get().reads(1));
+            if <AwardedPts<T>>::iter_prefix(round).next().is_none() {
+                Self::clean_up_round_payouts(round);
+                // write DelayedPayouts, Points, PendingPayoutCleanups
+                weight = weight.saturating_add(T::DbWeight::get().writes(3));
+            }
+            Ok(Some(weight).into())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -1479,6 +1994,27 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Returns the commission rate of a candidate, clamped into `CollatorCommissionBounds`
+        pub fn candidate_commission_rate(candidate: &T::AccountId) -> Perbill {
+            let commission = <CandidateCommission<T>>::get(candidate)
+                .unwrap_or_else(<CollatorCommission<T>>::get);
+            <CollatorCommissionBounds<T>>::get().clamp(commission)
+        }
+        /// Applies the pending commission increase of `candidate` if it is executable in `now`
+        fn apply_commission_request(candidate: &T::AccountId, now: RoundIndex) {
+            if let Some(request) = <CandidateCommissionRequests<T>>::get(candidate) {
+                if request.when_executable <= now {
+                    let old = Self::candidate_commission_rate(candidate);
+                    <CandidateCommissionRequests<T>>::remove(candidate);
+                    <CandidateCommission<T>>::insert(candidate, request.commission);
+                    Self::deposit_event(Event::CandidateCommissionSet {
+                        candidate: candidate.clone(),
+                        old,
+                        new: request.commission,
+                    });
+                }
+            }
+        }
         /// Caller must ensure candidate is active before calling
         pub(crate) fn update_active(candidate: T::AccountId, total: BalanceOf<T>) {
             let mut candidates = <CandidatePool<T>>::get();
@@ -1490,7 +2026,7 @@ pub mod pallet {
             <CandidatePool<T>>::put(candidates);
         }
         /// Compute round issuance based on total staked for the given round
-        fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
+        pub(crate) fn compute_issuance(staked: BalanceOf<T>) -> BalanceOf<T> {
             let config = <InflationConfig<T>>::get();
             let round_issuance = crate::inflation::round_issuance_range::<T>(config.round);
             // TODO: consider interpolation instead of bounded range
@@ -1523,34 +2059,68 @@ pub mod pallet {
             });
             Ok(())
         }
-        fn prepare_staking_payouts(now: RoundIndex) {
+        /// Returns the weight of splitting the parachain bond reserve across its beneficiaries
+        fn prepare_staking_payouts(now: RoundIndex) -> Weight {
             // payout is now - delay rounds ago => now - delay > 0 else return early
             let delay = T::RewardPaymentDelay::get();
             if now <= delay {
-                return;
+                return Weight::zero();
             }
             let round_to_payout = now.saturating_sub(delay);
             let total_points = <Points<T>>::get(round_to_payout);
             if total_points.is_zero() {
-                return;
+                return Weight::zero();
             }
             let total_staked = <Staked<T>>::take(round_to_payout);
             let total_issuance = Self::compute_issuance(total_staked);
             let mut left_issuance = total_issuance;
-            // reserve portion of issuance for parachain bond account
+            // reserve portion of issuance for parachain bond beneficiaries
             let bond_config = <ParachainBondInfo<T>>::get();
             let parachain_bond_reserve = bond_config.percent * total_issuance;
-            if let Ok(imb) = <T as Config>::Currency::deposit_into_existing(
-                &bond_config.account,
-                parachain_bond_reserve,
-            ) {
-                // update round issuance iff transfer succeeds
-                left_issuance = left_issuance.saturating_sub(imb.peek());
-                Self::deposit_event(Event::ReservedForParachainBond {
+            let mut beneficiaries = <ParachainBondBeneficiaries<T>>::get();
+            if beneficiaries.is_empty() {
+                beneficiaries.push(ParachainBondBeneficiary {
                     account: bond_config.account,
-                    value: imb.peek(),
+                    weight: 1,
+                    lock_rounds: 0,
                 });
             }
+            // read ParachainBondBeneficiaries
+            // read, write beneficiary balance and ParachainBondLocks, reserve for each beneficiary
+            let reserve_weight = T::DbWeight::get().reads_writes(
+                1u64.saturating_add(2u64.saturating_mul(beneficiaries.len() as u64)),
+                3u64.saturating_mul(beneficiaries.len() as u64),
+            );
+            let total_weight = beneficiaries.iter().fold(0u32, |acc, beneficiary| {
+                acc.saturating_add(beneficiary.weight)
+            });
+            // rounding remainders of the split stay in the staking reward
+            for ParachainBondBeneficiary {
+                account,
+                weight,
+                lock_rounds,
+            } in beneficiaries
+            {
+                let share = Perbill::from_rational(weight, total_weight) * parachain_bond_reserve;
+                if let Ok(imb) = <T as Config>::Currency::deposit_into_existing(&account, share) {
+                    // update round issuance iff transfer succeeds
+                    left_issuance = left_issuance.saturating_sub(imb.peek());
+                    Self::deposit_event(Event::ReservedForParachainBond {
+                        account: account.clone(),
+                        value: imb.peek(),
+                    });
+                    if !lock_rounds.is_zero() && !imb.peek().is_zero() {
+                        Self::update_parachain_bond_locks(
+                            &account,
+                            now,
+                            Some(ParachainBondLock {
+                                amount: imb.peek(),
+                                unlock_round: now.saturating_add(lock_rounds),
+                            }),
+                        );
+                    }
+                }
+            }
 
             let payout = DelayedPayout {
                 round_issuance: total_issuance,
@@ -1559,6 +2129,60 @@ pub mod pallet {
             };
 
             <DelayedPayouts<T>>::insert(round_to_payout, payout);
+            reserve_weight
+        }
+
+        /// Release the parachain bond allocations of `account` which reached their unlock round by
+        /// `now` from reserve and hold the `new` allocation in reserve.
+        ///
+        /// Allocations are reserved rather than locked so that they add up with the staking locks
+        /// of beneficiaries which also stake.
+        /// Returns the unlocked amount
+        pub(crate) fn update_parachain_bond_locks(
+            account: &T::AccountId,
+            now: RoundIndex,
+            new: Option<ParachainBondLock<BalanceOf<T>>>,
+        ) -> BalanceOf<T> {
+            let mut unlocked = BalanceOf::<T>::zero();
+            let mut locks = <ParachainBondLocks<T>>::get(account);
+            locks.retain(|lock| {
+                if lock.unlock_round <= now {
+                    unlocked = unlocked.saturating_add(lock.amount);
+                    false
+                } else {
+                    true
+                }
+            });
+            if !unlocked.is_zero() {
+                <T as Config>::Currency::unreserve(account, unlocked);
+                Self::deposit_event(Event::ParachainBondUnlocked {
+                    account: account.clone(),
+                    amount: unlocked,
+                });
+            }
+            if let Some(new) = new {
+                if <T as Config>::Currency::reserve(account, new.amount).is_ok() {
+                    if let Err(new) = locks.try_push(new) {
+                        // merge into the allocation unlocking last so that nothing is released
+                        // early
+                        if let Some(last) = locks.iter_mut().max_by_key(|lock| lock.unlock_round) {
+                            last.amount = last.amount.saturating_add(new.amount);
+                            last.unlock_round = last.unlock_round.max(new.unlock_round);
+                        }
+                    }
+                    Self::deposit_event(Event::ParachainBondLocked {
+                        account: account.clone(),
+                        amount: new.amount,
+                        unlock_round: new.unlock_round,
+                    });
+                }
+            }
+            if locks.is_empty() {
+                <ParachainBondLocks<T>>::remove(account);
+            } else {
+                <ParachainBondLocks<T>>::insert(account, locks);
+            }
+            unlocked
         }
 
         /// Wrapper around pay_one_collator_reward which handles the following logic:
@@ -1580,8 +2204,7 @@ pub mod pallet {
                 if result.0.is_none() {
                     // result.0 indicates whether or not a payout was made
                     // clean up storage items that we no longer need
-                    <DelayedPayouts<T>>::remove(paid_for_round);
-                    <Points<T>>::remove(paid_for_round);
+                    Self::clean_up_round_payouts(paid_for_round);
                 }
                 result.1 // weight consumed by pay_one_collator_reward
             } else {
@@ -1589,6 +2212,75 @@ pub mod pallet {
             }
         }
 
+        /// Keep paying collators of the round due for payment while `remaining_weight` fits the
+        /// payout of a collator with full top delegations. The single payout per block made in
+        /// `on_initialize` by `handle_delayed_payouts` is not affected.
+        fn handle_idle_payouts(now: RoundIndex, remaining_weight: Weight) -> Weight {
+            // read Round
+            let mut consumed = T::DbWeight::get().reads(1);
+            // rounds are only prepared for payout once now - delay > 0, see prepare_staking_payouts
+            let delay = T::RewardPaymentDelay::get();
+            if now <= delay {
+                return consumed;
+            }
+            let paid_for_round = now.saturating_sub(delay);
+            // read DelayedPayouts, Points, AwardedPts
+            let max_payout_weight = <T as Config>::WeightInfo::pay_one_collator_reward(
+                T::MaxTopDelegationsPerCandidate::get(),
+            )
+            .saturating_add(T::DbWeight::get().reads(3));
+            while consumed
+                .saturating_add(max_payout_weight)
+                .all_lte(remaining_weight)
+            {
+                consumed = consumed.saturating_add(T::DbWeight::get().reads(1));
+                if !<DelayedPayouts<T>>::contains_key(paid_for_round) {
+                    break;
+                }
+                consumed = consumed.saturating_add(Self::handle_delayed_payouts(now));
+            }
+            consumed
+        }
+
+        /// Remove the payout state of `round` once all its collators are paid and schedule the
+        /// removal of its snapshots, including the ones of selected collators which authored no
+        /// block, in `handle_payout_cleanups`
+        pub(crate) fn clean_up_round_payouts(round: RoundIndex) {
+            <DelayedPayouts<T>>::remove(round);
+            <Points<T>>::remove(round);
+            <PendingPayoutCleanups<T>>::insert(round, ());
+        }
+
+        /// Remove up to `MAX_PAYOUT_CLEANUP_KEYS` snapshots of each map of a paid round and
+        /// returns the weight consumed
+        fn handle_payout_cleanups() -> Weight {
+            // read PendingPayoutCleanups
+            let mut weight = T::DbWeight::get().reads(1);
+            let round = match <PendingPayoutCleanups<T>>::iter_keys().next() {
+                Some(round) => round,
+                None => return weight,
+            };
+            // NOTE: Keys removed in previous blocks are committed, so no cursor is needed to
+            //       continue the removal.
+            let results = [
+                <AwardedPts<T>>::clear_prefix(round, MAX_PAYOUT_CLEANUP_KEYS, None),
+                <AtStake<T>>::clear_prefix(round, MAX_PAYOUT_CLEANUP_KEYS, None),
+                <AtStakeCommission<T>>::clear_prefix(round, MAX_PAYOUT_CLEANUP_KEYS, None),
+            ];
+            let mut done = true;
+            for result in results {
+                weight = weight.saturating_add(
+                    T::DbWeight::get().reads_writes(result.loops.into(), result.unique.into()),
+                );
+                done &= result.maybe_cursor.is_none();
+            }
+            if done {
+                <PendingPayoutCleanups<T>>::remove(round);
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+            weight
+        }
+
         /// Payout a single collator from the given round.
         ///
         /// Returns an optional tuple of (Collator's AccountId, total paid)
@@ -1610,65 +2302,17 @@ pub mod pallet {
                 return (None, Weight::zero());
             }
 
-            let mint = |amt: BalanceOf<T>, to: T::AccountId| {
-                if let Ok(amount_transferred) =
-                    <T as Config>::Currency::deposit_into_existing(&to, amt)
-                {
-                    Self::deposit_event(Event::Rewarded {
-                        account: to.clone(),
-                        rewards: amount_transferred.peek(),
-                    });
-                }
-            };
-
-            let collator_fee = payout_info.collator_commission;
-            let collator_issuance = collator_fee * payout_info.round_issuance;
-
             if let Some((collator, pts)) =
                 <AwardedPts<T>>::iter_prefix(paid_for_round).drain().next()
             {
-                let mut extra_weight = Weight::zero();
-                let pct_due = Perbill::from_rational(pts, total_points);
-                let total_paid = pct_due * payout_info.total_staking_reward;
-                let mut amt_due = total_paid;
-                // Take the snapshot of block author and delegations
-                let state = <AtStake<T>>::take(paid_for_round, &collator);
-                let num_delegators = state.delegations.len();
-                if state.delegations.is_empty() {
-                    // solo collator with no delegators
-                    mint(amt_due, collator.clone());
-                    extra_weight += T::OnCollatorPayout::on_collator_payout(
-                        paid_for_round,
-                        collator.clone(),
-                        amt_due,
-                    );
-                } else {
-                    // pay collator first; commission + due_portion
-                    let collator_pct = Perbill::from_rational(state.bond, state.total);
-                    let commission = pct_due * collator_issuance;
-                    amt_due = amt_due.saturating_sub(commission);
-                    let collator_reward = (collator_pct * amt_due).saturating_add(commission);
-                    mint(collator_reward, collator.clone());
-                    extra_weight += T::OnCollatorPayout::on_collator_payout(
-                        paid_for_round,
-                        collator.clone(),
-                        collator_reward,
-                    );
-                    // pay delegators due portion
-                    for Bond { owner, amount } in state.delegations {
-                        let percent = Perbill::from_rational(amount, state.total);
-                        let due = percent * amt_due;
-                        if !due.is_zero() {
-                            mint(due, owner.clone());
-                        }
-                    }
-                }
-
-                (
-                    Some((collator, total_paid)),
-                    <T as Config>::WeightInfo::pay_one_collator_reward(num_delegators as u32)
-                        + extra_weight,
-                )
+                let (total_paid, weight) = Self::pay_collator_reward(
+                    paid_for_round,
+                    collator.clone(),
+                    pts,
+                    total_points,
+                    &payout_info,
+                );
+                (Some((collator, total_paid)), weight)
             } else {
                 // Note that we don't clean up storage here; it is cleaned up in
                 // handle_delayed_payouts()
@@ -1676,6 +2320,120 @@ pub mod pallet {
             }
         }
 
+        /// Pay `collator` and its delegators for the `pts` it was awarded out of `total_points`
+        /// in `paid_for_round`. The awarded points must already be removed from storage.
+        ///
+        /// Returns the total paid and the weight consumed
+        pub(crate) fn pay_collator_reward(
+            paid_for_round: RoundIndex,
+            collator: T::AccountId,
+            pts: RewardPoint,
+            total_points: RewardPoint,
+            payout_info: &DelayedPayout<BalanceOf<T>>,
+        ) -> (BalanceOf<T>, Weight) {
+            let mint = |amt: BalanceOf<T>, to: T::AccountId| Self::mint_reward(&to, amt);
+
+            let collator_fee = <AtStakeCommission<T>>::take(paid_for_round, &collator)
+                .unwrap_or(payout_info.collator_commission);
+            let collator_issuance = collator_fee * payout_info.round_issuance;
+            let mut extra_weight = Weight::zero();
+            let pct_due = Perbill::from_rational(pts, total_points);
+            let total_paid = pct_due * payout_info.total_staking_reward;
+            let mut amt_due = total_paid;
+            // Take the snapshot of block author and delegations
+            let state = <AtStake<T>>::take(paid_for_round, &collator);
+            let num_delegators = state.delegations.len();
+            if state.delegations.is_empty() {
+                // solo collator with no delegators
+                mint(amt_due, collator.clone());
+                extra_weight += T::OnCollatorPayout::on_collator_payout(
+                    paid_for_round,
+                    collator.clone(),
+                    amt_due,
+                );
+            } else {
+                // pay collator first; commission + due_portion
+                let collator_pct = Perbill::from_rational(state.bond, state.total);
+                let commission = pct_due * collator_issuance;
+                amt_due = amt_due.saturating_sub(commission);
+                let collator_reward = (collator_pct * amt_due).saturating_add(commission);
+                mint(collator_reward, collator.clone());
+                extra_weight += T::OnCollatorPayout::on_collator_payout(
+                    paid_for_round,
+                    collator.clone(),
+                    collator_reward,
+                );
+                // pay delegators due portion
+                for Bond { owner, amount } in state.delegations {
+                    let percent = Perbill::from_rational(amount, state.total);
+                    let due = percent * amt_due;
+                    if !due.is_zero() {
+                        mint(due, owner.clone());
+                    }
+                }
+            }
+
+            (
+                total_paid,
+                <T as Config>::WeightInfo::pay_one_collator_reward(num_delegators as u32)
+                    + extra_weight,
+            )
+        }
+
+        /// Mint `amt` of rewards for `staker` into its reward destination.
+        ///
+        /// If the destination does not exist, rewards fall back to the staking account and then to
+        /// the treasury, so they are never silently dropped.
+        fn mint_reward(staker: &T::AccountId, amt: BalanceOf<T>) {
+            let destination = <RewardDestinations<T>>::get(staker);
+            if let RewardDestination::Account(payee) = &destination {
+                if let Ok(amount_transferred) =
+                    <T as Config>::Currency::deposit_into_existing(payee, amt)
+                {
+                    Self::deposit_event(Event::RewardedToDestination {
+                        account: staker.clone(),
+                        destination: payee.clone(),
+                        rewards: amount_transferred.peek(),
+                    });
+                    return;
+                }
+                Self::deposit_event(Event::RewardDestinationUnavailable {
+                    account: staker.clone(),
+                    destination: destination.clone(),
+                });
+            }
+            if destination != RewardDestination::Treasury {
+                if let Ok(amount_transferred) =
+                    <T as Config>::Currency::deposit_into_existing(staker, amt)
+                {
+                    Self::deposit_event(Event::Rewarded {
+                        account: staker.clone(),
+                        rewards: amount_transferred.peek(),
+                    });
+                    return;
+                }
+                Self::deposit_event(Event::RewardDestinationUnavailable {
+                    account: staker.clone(),
+                    destination: RewardDestination::Staked,
+                });
+            }
+            let treasury = T::TreasuryAccount::get();
+            let amount_transferred = <T as Config>::Currency::deposit_creating(&treasury, amt);
+            if amount_transferred.peek().is_zero() && !amt.is_zero() {
+                log::warn!(
+                    "Reward of {:?} for {:?} could not be paid to any destination",
+                    amt,
+                    staker
+                );
+                return;
+            }
+            Self::deposit_event(Event::RewardedToDestination {
+                account: staker.clone(),
+                destination: treasury,
+                rewards: amount_transferred.peek(),
+            });
+        }
+
         /// Compute the top `TotalSelected` candidates in the CandidatePool and return
         /// a vec of their AccountIds (in the order of selection)
         pub fn compute_top_candidates() -> Vec<T::AccountId> {
@@ -1721,6 +2479,9 @@ pub mod pallet {
                         delegation_count.saturating_add(snapshot.delegations.len() as u32);
                     total = total.saturating_add(snapshot.total);
                     total_per_candidate.insert(account.clone(), snapshot.total);
+                    if let Some(commission) = <AtStakeCommission<T>>::get(last_round, &account) {
+                        <AtStakeCommission<T>>::insert(now, &account, commission);
+                    }
                     <AtStake<T>>::insert(now, account, snapshot);
                 }
                 // `SelectedCandidates` remains unchanged from last round
@@ -1758,6 +2519,12 @@ pub mod pallet {
                     total: total_counted,
                 };
                 <AtStake<T>>::insert(now, account, snapshot);
+                Self::apply_commission_request(account, now);
+                <AtStakeCommission<T>>::insert(
+                    now,
+                    account,
+                    Self::candidate_commission_rate(account),
+                );
                 Self::deposit_event(Event::CollatorChosen {
                     round: now,
                     collator_account: account.clone(),
@@ -1775,7 +2542,7 @@ pub mod pallet {
         /// This will:
         /// - if [DelegationChange::Revoke] is outstanding, set the bond amount to 0.
         /// - if [DelegationChange::Decrease] is outstanding, subtract the bond by specified amount.
-        /// - else, do nothing
+        /// - else (including a pending [DelegationAction::Redelegate]), do nothing
         ///
         /// The intended bond amounts will be used while calculating rewards.
         fn get_rewardable_delegators(collator: &T::AccountId) -> CountedDelegations<T> {
@@ -1809,6 +2576,8 @@ pub mod pallet {
                             uncounted_stake = uncounted_stake.saturating_add(*amount);
                             bond.amount.saturating_sub(*amount)
                         }
+                        // redelegated stake keeps earning until it is moved
+                        Some(DelegationAction::Redelegate(_)) => bond.amount,
                     };
 
                     bond
@@ -1827,9 +2596,115 @@ pub mod pallet {
         fn award_points_to_block_author() {
             let author = T::BlockAuthor::get();
             let now = <Round<T>>::get().current;
-            let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(20);
+            let score_plus_20 = <AwardedPts<T>>::get(now, &author).saturating_add(POINTS_PER_BLOCK);
             <AwardedPts<T>>::insert(now, author, score_plus_20);
-            <Points<T>>::mutate(now, |x| *x = x.saturating_add(20));
+            <Points<T>>::mutate(now, |x| *x = x.saturating_add(POINTS_PER_BLOCK));
+        }
+        /// Track the blocks authored in the round before `now`, which lasted `round_length`
+        /// blocks, by the candidates selected for it and jail the ones that underperformed for too
+        /// many consecutive rounds according to the `OfflinePolicy`
+        fn jail_offline_candidates(now: RoundIndex, round_length: u32) -> Weight {
+            // read OfflinePolicyConfig, SelectedCandidates, Points
+            let mut weight = T::DbWeight::get().reads(3);
+            let policy = <OfflinePolicyConfig<T>>::get();
+            let selected = <SelectedCandidates<T>>::get();
+            let round = now.saturating_sub(1);
+            // misses only count while consecutive, so candidates which were not selected for the
+            // round start over
+            let tracked = <MissedRounds<T>>::iter_keys().collect::<Vec<_>>();
+            weight = weight.saturating_add(T::DbWeight::get().reads(tracked.len() as u64));
+            for candidate in tracked {
+                if !selected.contains(&candidate) {
+                    <MissedRounds<T>>::remove(&candidate);
+                    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                }
+            }
+            // skip rounds without any recorded author, e.g. right after genesis
+            if !policy.is_enabled() || selected.is_empty() || <Points<T>>::get(round).is_zero() {
+                return weight;
+            }
+            let expected = round_length / selected.len() as u32;
+            let required = policy.min_authored * expected;
+            for candidate in selected {
+                // read AwardedPts, MissedRounds, CandidatePool
+                // write MissedRounds
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 1));
+                let authored = <AwardedPts<T>>::get(round, &candidate) / POINTS_PER_BLOCK;
+                if authored >= required {
+                    <MissedRounds<T>>::remove(&candidate);
+                    continue;
+                }
+                let missed_rounds = <MissedRounds<T>>::get(&candidate).saturating_add(1);
+                Self::deposit_event(Event::CandidateMissedRound {
+                    candidate: candidate.clone(),
+                    round,
+                    authored,
+                    expected,
+                    missed_rounds,
+                });
+                // never jail below the minimum number of candidates available for selection
+                if missed_rounds < policy.max_missed_rounds
+                    || <CandidatePool<T>>::get().0.len() as u32 <= T::MinSelectedCandidates::get()
+                {
+                    <MissedRounds<T>>::insert(&candidate, missed_rounds);
+                    continue;
+                }
+                weight = weight.saturating_add(Self::jail_candidate(candidate, now, &policy));
+            }
+            weight
+        }
+        /// Remove `candidate` from the candidate pool until `policy.jail_rounds` rounds after
+        /// `now` and slash `policy.slash` of its self bond into the treasury
+        fn jail_candidate(
+            candidate: T::AccountId,
+            now: RoundIndex,
+            policy: &OfflinePolicy,
+        ) -> Weight {
+            // read CandidateInfo, Total
+            // write CandidateInfo, CandidatePool, Total, MissedRounds, JailedCandidates, lock,
+            // candidate and treasury balances
+            let weight = T::DbWeight::get().reads_writes(2, 8);
+            let mut state = match <CandidateInfo<T>>::get(&candidate) {
+                Some(state) => state,
+                None => return weight,
+            };
+            // leaving candidates are already out of the candidate pool
+            if state.is_active() {
+                state.go_offline();
+                let mut candidates = <CandidatePool<T>>::get();
+                if candidates.remove(&Bond::from_owner(candidate.clone())) {
+                    <CandidatePool<T>>::put(candidates);
+                }
+            }
+            let slashed = policy.slash * state.bond;
+            if !slashed.is_zero() {
+                state.bond = state.bond.saturating_sub(slashed);
+                state.total_counted = state.total_counted.saturating_sub(slashed);
+                <Total<T>>::mutate(|total| *total = total.saturating_sub(slashed));
+                <T as Config>::Currency::set_lock(
+                    COLLATOR_LOCK_ID,
+                    &candidate,
+                    state.bond,
+                    WithdrawReasons::all(),
+                );
+                let (imbalance, _) = <T as Config>::Currency::slash(&candidate, slashed);
+                let amount = imbalance.peek();
+                <T as Config>::Currency::resolve_creating(&T::TreasuryAccount::get(), imbalance);
+                Self::deposit_event(Event::CandidateSlashed {
+                    candidate: candidate.clone(),
+                    amount,
+                    new_bond: state.bond,
+                });
+            }
+            <CandidateInfo<T>>::insert(&candidate, state);
+            <MissedRounds<T>>::remove(&candidate);
+            let rejoin_round = now.saturating_add(policy.jail_rounds);
+            <JailedCandidates<T>>::insert(&candidate, rejoin_round);
+            Self::deposit_event(Event::CandidateJailed {
+                candidate,
+                rejoin_round,
+            });
+            weight
         }
     }
 
@@ -110,11 +110,16 @@ parameter_types! {
     pub const LeaveDelegatorsDelay: u32 = 2;
     pub const RevokeDelegationDelay: u32 = 2;
     pub const DelegationBondLessDelay: u32 = 2;
+    pub const CommissionIncreaseDelay: u32 = 2;
+    pub const RedelegationDelay: u32 = 2;
+    pub const RedelegationCooldown: u32 = 4;
     pub const RewardPaymentDelay: u32 = 2;
     pub const MinSelectedCandidates: u32 = 5;
     pub const MaxTopDelegationsPerCandidate: u32 = 4;
     pub const MaxBottomDelegationsPerCandidate: u32 = 4;
     pub const MaxDelegationsPerDelegator: u32 = 4;
+    pub const MaxParachainBondBeneficiaries: u32 = 3;
+    pub const MaxParachainBondLocks: u32 = 2;
     pub const DefaultCollatorCommission: Perbill = Perbill::from_percent(20);
     pub const DefaultParachainBondReservePercent: Percent = Percent::from_percent(30);
     pub const MinCollatorStk: u128 = 1;
@@ -122,6 +127,7 @@ parameter_types! {
     pub const MinWhitelistCandidateStk: u128 = 1; // WHITELIST - remove
     pub const MinDelegatorStk: u128 = 5;
     pub const MinDelegation: u128 = 3;
+    pub const TreasuryAccount: AccountId = 7777;
 }
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
@@ -134,11 +140,16 @@ impl Config for Test {
     type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
     type RevokeDelegationDelay = RevokeDelegationDelay;
     type DelegationBondLessDelay = DelegationBondLessDelay;
+    type CommissionIncreaseDelay = CommissionIncreaseDelay;
+    type RedelegationDelay = RedelegationDelay;
+    type RedelegationCooldown = RedelegationCooldown;
     type RewardPaymentDelay = RewardPaymentDelay;
     type MinSelectedCandidates = MinSelectedCandidates;
     type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
     type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
     type MaxDelegationsPerDelegator = MaxDelegationsPerDelegator;
+    type MaxParachainBondBeneficiaries = MaxParachainBondBeneficiaries;
+    type MaxParachainBondLocks = MaxParachainBondLocks;
     type DefaultCollatorCommission = DefaultCollatorCommission;
     type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
     type MinCollatorStk = MinCollatorStk; // WHITELIST - remove
@@ -147,6 +158,7 @@ impl Config for Test {
     type MinDelegatorStk = MinDelegatorStk;
     type MinDelegation = MinDelegation;
     type BlockAuthor = BlockAuthor;
+    type TreasuryAccount = TreasuryAccount;
     type OnCollatorPayout = ();
     type OnNewRound = ();
     type WeightInfo = ();
@@ -195,6 +207,7 @@ impl manta_collator_selection::Config for Test {
     type ValidatorRegistration = IsRegistered;
     type WeightInfo = ();
     type CanAuthor = ();
+    type PerformanceHistoryLength = ConstU32<3>;
 }
 
 use sp_runtime::{traits::OpaqueKeys, RuntimeAppPublic};
@@ -0,0 +1,163 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only views of the staking state backing the `ParachainStakingApi`
+
+use crate::{
+    pallet::{
+        AtStake, AtStakeCommission, AwardedPts, BalanceOf, BottomDelegations, CandidateInfo,
+        CandidatePool, Config, DelegationScheduledRequests, DelegatorState, Pallet, Points, Round,
+        Staked,
+    },
+    Bond, CandidateSummary, DelegationPosition, DelegatorPosition, PendingUnbondRequest,
+};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Perbill,
+};
+use sp_std::vec::Vec;
+
+impl<T: Config> Pallet<T> {
+    /// Returns all delegations of `delegator`, flagging the ones in the bottom set of their
+    /// collator
+    pub fn delegator_position(
+        delegator: &T::AccountId,
+    ) -> Option<DelegatorPosition<T::AccountId, BalanceOf<T>>> {
+        let state = <DelegatorState<T>>::get(delegator)?;
+        let delegations = state
+            .delegations
+            .0
+            .into_iter()
+            .map(|Bond { owner, amount }| {
+                let in_bottom = <BottomDelegations<T>>::get(&owner)
+                    .map(|bottom| bottom.delegations.iter().any(|d| &d.owner == delegator))
+                    .unwrap_or(false);
+                DelegationPosition {
+                    collator: owner,
+                    amount,
+                    in_bottom,
+                }
+            })
+            .collect();
+        Some(DelegatorPosition {
+            total: state.total,
+            less_total: state.less_total,
+            delegations,
+        })
+    }
+
+    /// Returns the scheduled revoke and decrease requests of `delegator`
+    pub fn delegator_pending_requests(
+        delegator: &T::AccountId,
+    ) -> Vec<PendingUnbondRequest<T::AccountId, BalanceOf<T>>> {
+        let now = <Round<T>>::get().current;
+        <DelegatorState<T>>::get(delegator)
+            .map(|state| state.delegations.0)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|Bond { owner, .. }| {
+                <DelegationScheduledRequests<T>>::get(&owner)
+                    .into_iter()
+                    .find(|request| &request.delegator == delegator)
+                    .map(|request| PendingUnbondRequest {
+                        collator: owner,
+                        when_executable: request.when_executable,
+                        executable: request.when_executable <= now,
+                        action: request.action,
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns the summary of `candidate` including its top and bottom delegation thresholds
+    pub fn candidate_summary(
+        candidate: &T::AccountId,
+    ) -> Option<CandidateSummary<T::AccountId, BalanceOf<T>>> {
+        let info = <CandidateInfo<T>>::get(candidate)?;
+        Some(CandidateSummary {
+            candidate: candidate.clone(),
+            bond: info.bond,
+            delegation_count: info.delegation_count,
+            total_counted: info.total_counted,
+            lowest_top_delegation_amount: info.lowest_top_delegation_amount,
+            highest_bottom_delegation_amount: info.highest_bottom_delegation_amount,
+            lowest_bottom_delegation_amount: info.lowest_bottom_delegation_amount,
+            top_capacity: info.top_capacity,
+            bottom_capacity: info.bottom_capacity,
+            status: info.status,
+            commission: Self::candidate_commission_rate(candidate),
+            is_selected: Self::is_selected_candidate(candidate),
+        })
+    }
+
+    /// Returns the summaries of all candidates in the candidate pool
+    pub fn candidate_summaries() -> Vec<CandidateSummary<T::AccountId, BalanceOf<T>>> {
+        <CandidatePool<T>>::get()
+            .0
+            .iter()
+            .filter_map(|bond| Self::candidate_summary(&bond.owner))
+            .collect()
+    }
+
+    /// Estimates the reward `account` earns for the current round, as a collator and as a
+    /// delegator, if it is paid out with the current `InflationConfig`.
+    ///
+    /// The share of each selected collator follows its `AwardedPts` relative to the round
+    /// `Points`; before any block was authored in the round, points are assumed to be evenly
+    /// distributed among the selected collators.
+    pub fn estimated_round_reward(account: &T::AccountId) -> BalanceOf<T> {
+        let round = <Round<T>>::get().current;
+        let selected = Self::selected_candidates();
+        if selected.is_empty() {
+            return Zero::zero();
+        }
+        let round_issuance = Self::compute_issuance(<Staked<T>>::get(round));
+        let parachain_bond_reserve = Self::parachain_bond_info().percent * round_issuance;
+        let total_staking_reward = round_issuance.saturating_sub(parachain_bond_reserve);
+        let total_points = <Points<T>>::get(round);
+        let mut reward = BalanceOf::<T>::zero();
+        for collator in selected.iter() {
+            let pct_due = if total_points.is_zero() {
+                Perbill::from_rational(1u32, selected.len() as u32)
+            } else {
+                Perbill::from_rational(<AwardedPts<T>>::get(round, collator), total_points)
+            };
+            let total_paid = pct_due * total_staking_reward;
+            let state = <AtStake<T>>::get(round, collator);
+            if state.delegations.is_empty() {
+                if collator == account {
+                    reward = reward.saturating_add(total_paid);
+                }
+                continue;
+            }
+            let collator_fee = <AtStakeCommission<T>>::get(round, collator)
+                .unwrap_or_else(|| Self::candidate_commission_rate(collator));
+            let commission = pct_due * (collator_fee * round_issuance);
+            let amt_due = total_paid.saturating_sub(commission);
+            if collator == account {
+                let collator_pct = Perbill::from_rational(state.bond, state.total);
+                reward = reward.saturating_add((collator_pct * amt_due).saturating_add(commission));
+            }
+            for Bond { owner, amount } in state.delegations {
+                if &owner == account {
+                    let percent = Perbill::from_rational(amount, state.total);
+                    reward = reward.saturating_add(percent * amt_due);
+                }
+            }
+        }
+        reward
+    }
+}
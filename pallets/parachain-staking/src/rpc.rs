@@ -0,0 +1,163 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Parachain Staking RPC Interfaces
+
+use crate::{
+    runtime::ParachainStakingApi, CandidateSummary, DelegatorPosition, PendingUnbondRequest,
+};
+use core::marker::PhantomData;
+use jsonrpsee::{
+    core::{async_trait, RpcResult},
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use parity_scale_codec::Codec;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block};
+use std::sync::Arc;
+
+/// Staking Runtime API Error Code
+pub const STAKING_RUNTIME_API_ERROR: i32 = 1;
+
+/// Staking API
+#[rpc(server)]
+pub trait StakingApi<AccountId, Balance> {
+    /// Returns all delegations of `delegator` and whether they are in the bottom set of their
+    /// collator.
+    #[method(name = "parachainStaking_delegator_position", blocking)]
+    fn delegator_position(
+        &self,
+        delegator: AccountId,
+    ) -> RpcResult<Option<DelegatorPosition<AccountId, Balance>>>;
+
+    /// Returns the pending revoke and decrease requests of `delegator`.
+    #[method(name = "parachainStaking_delegator_pending_requests", blocking)]
+    fn delegator_pending_requests(
+        &self,
+        delegator: AccountId,
+    ) -> RpcResult<Vec<PendingUnbondRequest<AccountId, Balance>>>;
+
+    /// Returns the summary of `candidate`.
+    #[method(name = "parachainStaking_candidate_summary", blocking)]
+    fn candidate_summary(
+        &self,
+        candidate: AccountId,
+    ) -> RpcResult<Option<CandidateSummary<AccountId, Balance>>>;
+
+    /// Returns the summaries of all candidates.
+    #[method(name = "parachainStaking_candidate_summaries", blocking)]
+    fn candidate_summaries(&self) -> RpcResult<Vec<CandidateSummary<AccountId, Balance>>>;
+
+    /// Returns the estimated reward of `account` for the current round.
+    #[method(name = "parachainStaking_estimated_round_reward", blocking)]
+    fn estimated_round_reward(&self, account: AccountId) -> RpcResult<Balance>;
+}
+
+/// Staking RPC API Implementation
+pub struct Staking<B, C> {
+    /// Client
+    client: Arc<C>,
+
+    /// Type Parameter Marker
+    __: PhantomData<B>,
+}
+
+impl<B, C> Staking<B, C> {
+    /// Builds a new [`Staking`] RPC API implementation.
+    #[inline]
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            __: PhantomData,
+        }
+    }
+}
+
+/// Converts a runtime API error into an RPC error.
+#[inline]
+fn runtime_error<E>(message: &'static str, err: E) -> jsonrpsee::core::Error
+where
+    E: core::fmt::Debug,
+{
+    CallError::Custom(ErrorObject::owned(
+        STAKING_RUNTIME_API_ERROR,
+        message,
+        Some(format!("{err:?}")),
+    ))
+    .into()
+}
+
+#[async_trait]
+impl<B, C, AccountId, Balance> StakingApiServer<AccountId, Balance> for Staking<B, C>
+where
+    B: Block,
+    C: 'static + ProvideRuntimeApi<B> + HeaderBackend<B>,
+    C::Api: ParachainStakingApi<B, AccountId, Balance>,
+    AccountId: 'static + Codec + DeserializeOwned + Send + Sync + Serialize,
+    Balance: 'static + Codec + Send + Sync + Serialize,
+{
+    #[inline]
+    fn delegator_position(
+        &self,
+        delegator: AccountId,
+    ) -> RpcResult<Option<DelegatorPosition<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.delegator_position(&at, delegator)
+            .map_err(|err| runtime_error("Unable to query delegator position", err))
+    }
+
+    #[inline]
+    fn delegator_pending_requests(
+        &self,
+        delegator: AccountId,
+    ) -> RpcResult<Vec<PendingUnbondRequest<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.delegator_pending_requests(&at, delegator)
+            .map_err(|err| runtime_error("Unable to query pending delegation requests", err))
+    }
+
+    #[inline]
+    fn candidate_summary(
+        &self,
+        candidate: AccountId,
+    ) -> RpcResult<Option<CandidateSummary<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.candidate_summary(&at, candidate)
+            .map_err(|err| runtime_error("Unable to query candidate summary", err))
+    }
+
+    #[inline]
+    fn candidate_summaries(&self) -> RpcResult<Vec<CandidateSummary<AccountId, Balance>>> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.candidate_summaries(&at)
+            .map_err(|err| runtime_error("Unable to query candidate summaries", err))
+    }
+
+    #[inline]
+    fn estimated_round_reward(&self, account: AccountId) -> RpcResult<Balance> {
+        let api = self.client.runtime_api();
+        let at = BlockId::hash(self.client.info().best_hash);
+        api.estimated_round_reward(&at, account)
+            .map_err(|err| runtime_error("Unable to estimate round reward", err))
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Parachain Staking Runtime APIs
+
+use crate::{CandidateSummary, DelegatorPosition, PendingUnbondRequest};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait ParachainStakingApi<AccountId, Balance>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        fn delegator_position(delegator: AccountId) -> Option<DelegatorPosition<AccountId, Balance>>;
+        fn delegator_pending_requests(delegator: AccountId) -> Vec<PendingUnbondRequest<AccountId, Balance>>;
+        fn candidate_summary(candidate: AccountId) -> Option<CandidateSummary<AccountId, Balance>>;
+        fn candidate_summaries() -> Vec<CandidateSummary<AccountId, Balance>>;
+        fn estimated_round_reward(account: AccountId) -> Balance;
+    }
+}
@@ -28,12 +28,14 @@ use crate::{
     mock::{
         roll_one_block, roll_to, roll_to_round_begin, roll_to_round_end, set_author, Balances,
         CollatorSelection, ExtBuilder, ParachainStaking, RuntimeEvent as MetaEvent, RuntimeOrigin,
-        Test,
+        System, Test,
     },
-    AtStake, Bond, CollatorStatus, DelegationScheduledRequests, DelegatorAdded, DelegatorState,
-    DelegatorStatus, Error, Event, Range, DELEGATOR_LOCK_ID,
+    AtStake, AtStakeCommission, AwardedPts, Bond, CapacityStatus, CollatorStatus, DelayedPayouts,
+    DelegationPosition, DelegationScheduledRequests, DelegatorAdded, DelegatorState,
+    DelegatorStatus, Error, Event, OfflinePolicy, ParachainBondBeneficiary, ParachainBondLock,
+    PendingUnbondRequest, Points, Range, RewardDestination, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
 };
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::OnIdle, weights::Weight};
 use sp_runtime::{traits::Zero, DispatchError, ModuleError, Perbill, Percent};
 
 // ~~ ROOT ~~
@@ -251,6 +253,62 @@ fn cannot_set_collator_commission_to_current_collator_commission() {
     });
 }
 
+// SET COLLATOR COMMISSION BOUNDS
+
+#[test]
+fn set_collator_commission_bounds_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_collator_commission_bounds(
+            RuntimeOrigin::root(),
+            Perbill::from_percent(5),
+            Perbill::from_percent(50)
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(
+            Event::CollatorCommissionBoundsSet {
+                min: Perbill::from_percent(5),
+                max: Perbill::from_percent(50),
+            }
+        ));
+    });
+}
+
+#[test]
+fn cannot_set_collator_commission_bounds_with_min_above_max() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_collator_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(50),
+                Perbill::from_percent(5)
+            ),
+            Error::<Test>::InvalidCommissionBounds
+        );
+    });
+}
+
+#[test]
+fn collator_commission_bounds_clamp_candidate_commission() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(
+                ParachainStaking::candidate_commission_rate(&1),
+                Perbill::from_percent(20)
+            );
+            assert_ok!(ParachainStaking::set_collator_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(10)
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_commission_rate(&1),
+                Perbill::from_percent(10)
+            );
+        });
+}
+
 // SET BLOCKS PER ROUND
 
 #[test]
@@ -667,6 +725,262 @@ fn cannot_set_same_parachain_bond_reserve_percent() {
     });
 }
 
+// SET PARACHAIN BOND BENEFICIARIES
+
+fn parachain_bond_beneficiaries() -> Vec<ParachainBondBeneficiary<u64>> {
+    vec![
+        ParachainBondBeneficiary {
+            account: 11,
+            weight: 2,
+            lock_rounds: 0,
+        },
+        ParachainBondBeneficiary {
+            account: 12,
+            weight: 1,
+            lock_rounds: 2,
+        },
+    ]
+}
+
+/// Builds the staking state of `parachain_bond_inflation_reserve_matches_config`, which reserves
+/// 15 for round 2, with `parachain_bond_beneficiaries` in place
+fn parachain_bond_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (1, 100),
+            (2, 100),
+            (3, 100),
+            (4, 100),
+            (5, 100),
+            (6, 100),
+            (7, 100),
+            (8, 100),
+            (9, 100),
+            (10, 100),
+            (11, 1),
+            (12, 1),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 10)])
+        .with_delegations(vec![
+            (6, 1, 10),
+            (7, 1, 10),
+            (8, 2, 10),
+            (9, 2, 10),
+            (10, 1, 10),
+        ])
+        .build();
+    ext.execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_beneficiaries(
+            RuntimeOrigin::root(),
+            parachain_bond_beneficiaries()
+        ));
+    });
+    ext
+}
+
+#[test]
+fn set_parachain_bond_beneficiaries_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_beneficiaries(
+            RuntimeOrigin::root(),
+            parachain_bond_beneficiaries()
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(
+            Event::ParachainBondBeneficiariesSet {
+                beneficiaries: parachain_bond_beneficiaries(),
+            }
+        ));
+        assert_eq!(
+            ParachainStaking::parachain_bond_beneficiaries(),
+            parachain_bond_beneficiaries()
+        );
+    });
+}
+
+#[test]
+fn cannot_set_same_parachain_bond_beneficiaries() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_beneficiaries(RuntimeOrigin::root(), vec![]),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
+}
+
+#[test]
+fn cannot_set_more_than_max_parachain_bond_beneficiaries() {
+    ExtBuilder::default().build().execute_with(|| {
+        let beneficiaries = (11..15)
+            .map(|account| ParachainBondBeneficiary {
+                account,
+                weight: 1,
+                lock_rounds: 0,
+            })
+            .collect();
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_beneficiaries(
+                RuntimeOrigin::root(),
+                beneficiaries
+            ),
+            Error::<Test>::TooManyParachainBondBeneficiaries
+        );
+    });
+}
+
+#[test]
+fn cannot_set_parachain_bond_beneficiary_with_zero_weight() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut beneficiaries = parachain_bond_beneficiaries();
+        beneficiaries[1].weight = 0;
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_beneficiaries(
+                RuntimeOrigin::root(),
+                beneficiaries
+            ),
+            Error::<Test>::InvalidParachainBondBeneficiaries
+        );
+    });
+}
+
+#[test]
+fn cannot_set_duplicate_parachain_bond_beneficiaries() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut beneficiaries = parachain_bond_beneficiaries();
+        beneficiaries[1].account = 11;
+        assert_noop!(
+            ParachainStaking::set_parachain_bond_beneficiaries(
+                RuntimeOrigin::root(),
+                beneficiaries
+            ),
+            Error::<Test>::InvalidParachainBondBeneficiaries
+        );
+    });
+}
+
+#[test]
+fn parachain_bond_reserve_is_split_across_beneficiaries() {
+    parachain_bond_ext().execute_with(|| {
+        set_author(2, 1, 100);
+        // round 2 reserve of 15 is split 2:1 at the start of round 4
+        roll_to_round_begin(4);
+        assert_event_emitted!(Event::ReservedForParachainBond {
+            account: 11,
+            value: 10,
+        });
+        assert_event_emitted!(Event::ReservedForParachainBond {
+            account: 12,
+            value: 5,
+        });
+        assert_event_emitted!(Event::ParachainBondLocked {
+            account: 12,
+            amount: 5,
+            unlock_round: 6,
+        });
+        assert_eq!(Balances::free_balance(&11), 11);
+        assert_eq!(Balances::free_balance(&12), 1);
+        assert_eq!(Balances::reserved_balance(&11), 0);
+        assert_eq!(Balances::reserved_balance(&12), 5);
+    });
+}
+
+#[test]
+fn parachain_bond_allocations_add_up_with_staking_locks() {
+    parachain_bond_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::set_parachain_bond_beneficiaries(
+            RuntimeOrigin::root(),
+            vec![ParachainBondBeneficiary {
+                account: 6,
+                weight: 1,
+                lock_rounds: 2,
+            }]
+        ));
+        set_author(2, 1, 100);
+        roll_to_round_begin(4);
+        assert_eq!(Balances::reserved_balance(&6), 15);
+        assert_eq!(
+            crate::mock::query_lock_amount(6, DELEGATOR_LOCK_ID),
+            Some(10)
+        );
+        // only the balance neither staked nor allocated can be transferred
+        let free = Balances::free_balance(&6);
+        assert_noop!(
+            Balances::transfer(RuntimeOrigin::signed(6), 1, free - 9),
+            pallet_balances::Error::<Test>::LiquidityRestrictions
+        );
+        assert_ok!(Balances::transfer(RuntimeOrigin::signed(6), 1, free - 10));
+    });
+}
+
+#[test]
+fn parachain_bond_allocations_are_merged_beyond_max_locks() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100)])
+        .build()
+        .execute_with(|| {
+            for (amount, unlock_round) in [(1, 3), (2, 4), (3, 5)] {
+                ParachainStaking::update_parachain_bond_locks(
+                    &1,
+                    1,
+                    Some(ParachainBondLock {
+                        amount,
+                        unlock_round,
+                    }),
+                );
+            }
+            assert_eq!(
+                ParachainStaking::parachain_bond_locks(&1),
+                vec![
+                    ParachainBondLock {
+                        amount: 1,
+                        unlock_round: 3
+                    },
+                    ParachainBondLock {
+                        amount: 5,
+                        unlock_round: 5
+                    },
+                ]
+            );
+            assert_eq!(Balances::reserved_balance(&1), 6);
+            assert_eq!(
+                ParachainStaking::update_parachain_bond_locks(&1, 4, None),
+                1
+            );
+            assert_eq!(Balances::reserved_balance(&1), 5);
+        });
+}
+
+#[test]
+fn unlock_parachain_bond_releases_allocations_after_lock_rounds() {
+    parachain_bond_ext().execute_with(|| {
+        set_author(2, 1, 100);
+        roll_to_round_begin(4);
+        let locked = ParachainStaking::parachain_bond_locks(&12);
+        assert_eq!(
+            locked,
+            vec![ParachainBondLock {
+                amount: 5,
+                unlock_round: 6
+            }]
+        );
+        roll_to_round_begin(5);
+        assert_noop!(
+            ParachainStaking::unlock_parachain_bond(RuntimeOrigin::signed(1), 12),
+            Error::<Test>::NoParachainBondToUnlock
+        );
+        roll_to_round_begin(6);
+        assert_ok!(ParachainStaking::unlock_parachain_bond(
+            RuntimeOrigin::signed(1),
+            12
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::ParachainBondUnlocked {
+            account: 12,
+            amount: 5,
+        }));
+        assert!(ParachainStaking::parachain_bond_locks(&12).is_empty());
+        assert_eq!(Balances::reserved_balance(&12), 0);
+    });
+}
+
 // ~~ PUBLIC ~~
 
 // JOIN CANDIDATES
@@ -4298,6 +4612,1489 @@ fn cancel_delegator_bond_less_updates_delegator_state() {
         });
 }
 
+// SCHEDULE REDELEGATE
+
+#[test]
+fn schedule_redelegate_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RedelegationScheduled {
+                delegator: 2,
+                from: 1,
+                to: 3,
+                amount: 5,
+                execute_round: 3,
+            }));
+            assert_eq!(
+                ParachainStaking::delegation_scheduled_requests(&1),
+                vec![ScheduledRequest {
+                    delegator: 2,
+                    when_executable: 3,
+                    action: DelegationAction::Redelegate(5),
+                }]
+            );
+            assert_eq!(ParachainStaking::redelegation_target(&1, &2), Some(3));
+            // the stake stays bonded so it does not count as less total
+            let state = ParachainStaking::delegator_state(&2).expect("exists");
+            assert_eq!(state.less_total, 0);
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_to_same_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 1, 5),
+                Error::<Test>::CannotRedelegateToSameCandidate
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_to_non_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 5),
+                Error::<Test>::CandidateDNE
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_more_than_bonded() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 11),
+                Error::<Test>::InsufficientBalance
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_leaving_remainder_below_min() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 8),
+                Error::<Test>::DelegationBelowMin
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_with_pending_request() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+                RuntimeOrigin::signed(2),
+                1,
+                2
+            ));
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 5),
+                Error::<Test>::PendingDelegationRequestAlreadyExists
+            );
+        });
+}
+
+#[test]
+fn cannot_schedule_redelegate_into_bottom_delegations() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 30),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (8, 20),
+        ])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![
+            (2, 1, 20),
+            (4, 3, 10),
+            (5, 3, 10),
+            (6, 3, 10),
+            (8, 3, 10),
+        ])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 5),
+                Error::<Test>::RedelegationNotInTopDelegations
+            );
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                11
+            ));
+        });
+}
+
+#[test]
+fn schedule_redelegate_is_rate_limited() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            assert_ok!(ParachainStaking::cancel_delegation_request(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            assert_eq!(ParachainStaking::redelegation_target(&1, &2), None);
+            assert_noop!(
+                ParachainStaking::schedule_redelegate(RuntimeOrigin::signed(2), 1, 3, 5),
+                Error::<Test>::RedelegationCooldownNotElapsed
+            );
+            roll_to_round_begin(5);
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+        });
+}
+
+#[test]
+fn cannot_execute_redelegate_before_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            roll_to_round_begin(2);
+            assert_noop!(
+                ParachainStaking::execute_delegation_request(RuntimeOrigin::signed(2), 2, 1),
+                Error::<Test>::PendingDelegationRequestNotDueYet
+            );
+        });
+}
+
+#[test]
+fn execute_redelegate_moves_stake_without_unlocking() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                4
+            ));
+            roll_to_round_begin(3);
+            let total_before = ParachainStaking::total();
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::Redelegated {
+                delegator: 2,
+                from: 1,
+                to: 3,
+                amount: 4,
+                in_top: true,
+            }));
+            let state = ParachainStaking::delegator_state(&2).expect("exists");
+            assert_eq!(state.total, 10);
+            assert_eq!(state.get_bond_amount(&1), Some(6));
+            assert_eq!(state.get_bond_amount(&3), Some(4));
+            assert_eq!(
+                crate::mock::query_lock_amount(2, DELEGATOR_LOCK_ID),
+                Some(10)
+            );
+            assert_eq!(ParachainStaking::total(), total_before);
+            assert_eq!(
+                ParachainStaking::candidate_info(&1)
+                    .expect("exists")
+                    .total_counted,
+                36
+            );
+            let target = ParachainStaking::candidate_info(&3).expect("exists");
+            assert_eq!(target.total_counted, 24);
+            assert_eq!(target.delegation_count, 1);
+            assert!(ParachainStaking::delegation_scheduled_requests(&1).is_empty());
+            assert_eq!(ParachainStaking::redelegation_target(&1, &2), None);
+        });
+}
+
+#[test]
+fn execute_redelegate_of_whole_delegation_moves_it() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                10
+            ));
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            let state = ParachainStaking::delegator_state(&2).expect("exists");
+            assert_eq!(state.delegations.0.len(), 1);
+            assert_eq!(state.get_bond_amount(&1), None);
+            assert_eq!(state.get_bond_amount(&3), Some(10));
+            assert_eq!(
+                crate::mock::query_lock_amount(2, DELEGATOR_LOCK_ID),
+                Some(10)
+            );
+            let source = ParachainStaking::candidate_info(&1).expect("exists");
+            assert_eq!(source.total_counted, 30);
+            assert_eq!(source.delegation_count, 0);
+        });
+}
+
+#[test]
+fn execute_redelegate_into_existing_delegation_increases_it() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            roll_to_round_begin(3);
+            assert_ok!(ParachainStaking::execute_delegation_request(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            let state = ParachainStaking::delegator_state(&2).expect("exists");
+            assert_eq!(state.total, 20);
+            assert_eq!(state.get_bond_amount(&1), Some(5));
+            assert_eq!(state.get_bond_amount(&3), Some(15));
+            let target = ParachainStaking::candidate_info(&3).expect("exists");
+            assert_eq!(target.total_counted, 35);
+            assert_eq!(target.delegation_count, 1);
+        });
+}
+
+#[test]
+fn pending_redelegation_keeps_earning_with_source() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            roll_to_round_begin(2);
+            let snapshot = <AtStake<Test>>::get(2, 1);
+            assert_eq!(snapshot.total, 40);
+            assert_eq!(
+                snapshot.delegations,
+                vec![Bond {
+                    owner: 2,
+                    amount: 10
+                }]
+            );
+        });
+}
+
+#[test]
+fn leave_delegators_replaces_pending_redelegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_delegators(
+                RuntimeOrigin::signed(2)
+            ));
+            assert_eq!(ParachainStaking::redelegation_target(&1, &2), None);
+            assert!(ParachainStaking::delegation_request_revoke_exists(&1, &2));
+            let state = ParachainStaking::delegator_state(&2).expect("exists");
+            assert_eq!(state.less_total, 10);
+        });
+}
+
+#[test]
+fn execute_leave_delegators_removes_last_redelegation() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_redelegate(
+                RuntimeOrigin::signed(2),
+                1,
+                3,
+                5
+            ));
+            assert_eq!(ParachainStaking::last_redelegation(&2), Some(1));
+            assert_ok!(ParachainStaking::schedule_leave_delegators(
+                RuntimeOrigin::signed(2)
+            ));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_delegators(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert_eq!(ParachainStaking::last_redelegation(&2), None);
+        });
+}
+
+// SET REWARD DESTINATION
+
+#[test]
+fn set_reward_destination_event_emits_correctly() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 15)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 15)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(2),
+                RewardDestination::Account(3)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RewardDestinationSet {
+                account: 2,
+                destination: RewardDestination::Account(3),
+            }));
+            assert_eq!(
+                ParachainStaking::reward_destination(&2),
+                RewardDestination::Account(3)
+            );
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(1),
+                RewardDestination::Treasury
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::RewardDestinationSet {
+                account: 1,
+                destination: RewardDestination::Treasury,
+            }));
+        });
+}
+
+#[test]
+fn set_reward_destination_to_staked_clears_storage() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(1),
+                RewardDestination::Treasury
+            ));
+            assert!(crate::RewardDestinations::<Test>::contains_key(&1));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(1),
+                RewardDestination::Staked
+            ));
+            assert!(!crate::RewardDestinations::<Test>::contains_key(&1));
+        });
+}
+
+#[test]
+fn execute_leave_candidates_removes_reward_destination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 10), (2, 10)])
+        .with_candidates(vec![(1, 10)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(1),
+                RewardDestination::Treasury
+            ));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(2),
+                RewardDestination::Account(3)
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_candidates(
+                RuntimeOrigin::signed(1),
+                1u32
+            ));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_candidates(
+                RuntimeOrigin::signed(1),
+                1,
+                1
+            ));
+            assert!(!crate::RewardDestinations::<Test>::contains_key(&1));
+            assert!(!crate::RewardDestinations::<Test>::contains_key(&2));
+        });
+}
+
+#[test]
+fn execute_leave_delegators_removes_reward_destination() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 10)])
+        .with_candidates(vec![(1, 30)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(2),
+                RewardDestination::Account(3)
+            ));
+            assert_ok!(ParachainStaking::schedule_leave_delegators(
+                RuntimeOrigin::signed(2)
+            ));
+            roll_to(10);
+            assert_ok!(ParachainStaking::execute_leave_delegators(
+                RuntimeOrigin::signed(2),
+                2,
+                1
+            ));
+            assert!(!crate::RewardDestinations::<Test>::contains_key(&2));
+        });
+}
+
+#[test]
+fn cannot_set_reward_destination_if_not_candidate_or_delegator() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_reward_destination(
+                    RuntimeOrigin::signed(1),
+                    RewardDestination::Treasury
+                ),
+                Error::<Test>::CandidateOrDelegatorDNE
+            );
+        });
+}
+
+#[test]
+fn cannot_set_reward_destination_to_same_value() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30)])
+        .with_candidates(vec![(1, 30)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_reward_destination(
+                    RuntimeOrigin::signed(1),
+                    RewardDestination::Staked
+                ),
+                Error::<Test>::NoWritingSameValue
+            );
+        });
+}
+
+#[test]
+fn rewards_are_paid_to_payee_account() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(2),
+                RewardDestination::Account(3)
+            ));
+
+            roll_to_round_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 4,
+                },
+                Event::<Test>::RewardedToDestination {
+                    account: 2,
+                    destination: 3,
+                    rewards: 1,
+                },
+            ]);
+            assert_eq!(Balances::free_balance(&2), 40);
+            assert_eq!(Balances::free_balance(&3), 21);
+        });
+}
+
+#[test]
+fn rewards_are_paid_to_treasury() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(1),
+                RewardDestination::Treasury
+            ));
+
+            roll_to_round_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::RewardedToDestination {
+                    account: 1,
+                    destination: 7777,
+                    rewards: 4,
+                },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 1,
+                },
+            ]);
+            assert_eq!(Balances::free_balance(&1), 20);
+            assert_eq!(Balances::free_balance(&7777), 4);
+        });
+}
+
+#[test]
+fn rewards_fall_back_to_staking_account_if_payee_does_not_exist() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40), (3, 20), (4, 20)])
+        .with_candidates(vec![(1, 20), (3, 20), (4, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            (1..=3).for_each(|round| set_author(round, 1, 1));
+            assert_ok!(ParachainStaking::set_reward_destination(
+                RuntimeOrigin::signed(2),
+                RewardDestination::Account(99)
+            ));
+
+            roll_to_round_begin(3);
+            assert_eq_last_events!(vec![
+                Event::<Test>::Rewarded {
+                    account: 1,
+                    rewards: 4,
+                },
+                Event::<Test>::RewardDestinationUnavailable {
+                    account: 2,
+                    destination: RewardDestination::Account(99),
+                },
+                Event::<Test>::Rewarded {
+                    account: 2,
+                    rewards: 1,
+                },
+            ]);
+            assert_eq!(Balances::free_balance(&2), 41);
+            assert_eq!(Balances::free_balance(&99), 0);
+        });
+}
+
+// SET CANDIDATE COMMISSION
+
+#[test]
+fn set_candidate_commission_decrease_applies_immediately() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(10)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateCommissionSet {
+                candidate: 1,
+                old: Perbill::from_percent(20),
+                new: Perbill::from_percent(10),
+            }));
+            assert_eq!(
+                ParachainStaking::candidate_commission_rate(&1),
+                Perbill::from_percent(10)
+            );
+        });
+}
+
+#[test]
+fn set_candidate_commission_increase_applies_after_delay() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(50)
+            ));
+            assert_last_event!(MetaEvent::ParachainStaking(
+                Event::CandidateCommissionIncreaseScheduled {
+                    candidate: 1,
+                    commission: Perbill::from_percent(50),
+                    execute_round: 3,
+                }
+            ));
+            assert_eq!(
+                ParachainStaking::candidate_commission_rate(&1),
+                Perbill::from_percent(20)
+            );
+            roll_to_round_begin(2);
+            assert_eq!(
+                ParachainStaking::at_stake_commission(2, 1),
+                Some(Perbill::from_percent(20))
+            );
+            roll_to_round_begin(3);
+            assert_eq!(
+                ParachainStaking::at_stake_commission(3, 1),
+                Some(Perbill::from_percent(50))
+            );
+            assert_eq!(
+                ParachainStaking::candidate_commission_rate(&1),
+                Perbill::from_percent(50)
+            );
+            assert!(ParachainStaking::candidate_commission_request(&1).is_none());
+            assert_event_emitted!(Event::CandidateCommissionSet {
+                candidate: 1,
+                old: Perbill::from_percent(20),
+                new: Perbill::from_percent(50),
+            });
+        });
+}
+
+#[test]
+fn set_candidate_commission_to_current_cancels_pending_increase() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(50)
+            ));
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::from_percent(20)
+            ));
+            assert!(ParachainStaking::candidate_commission_request(&1).is_none());
+            assert_noop!(
+                ParachainStaking::set_candidate_commission(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(20)
+                ),
+                Error::<Test>::NoWritingSameValue
+            );
+        });
+}
+
+#[test]
+fn cannot_set_candidate_commission_out_of_bounds() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .with_candidates(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_collator_commission_bounds(
+                RuntimeOrigin::root(),
+                Perbill::from_percent(5),
+                Perbill::from_percent(30)
+            ));
+            assert_noop!(
+                ParachainStaking::set_candidate_commission(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(31)
+                ),
+                Error::<Test>::CommissionOutOfBounds
+            );
+            assert_noop!(
+                ParachainStaking::set_candidate_commission(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(4)
+                ),
+                Error::<Test>::CommissionOutOfBounds
+            );
+        });
+}
+
+#[test]
+fn cannot_set_candidate_commission_if_not_candidate() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20)])
+        .build()
+        .execute_with(|| {
+            assert_noop!(
+                ParachainStaking::set_candidate_commission(
+                    RuntimeOrigin::signed(1),
+                    Perbill::from_percent(10)
+                ),
+                Error::<Test>::CandidateDNE
+            );
+        });
+}
+
+#[test]
+fn payout_uses_commission_from_round_snapshot() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 40)])
+        .with_candidates(vec![(1, 20)])
+        .with_delegations(vec![(2, 1, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::one()
+            ));
+            roll_to_round_begin(3);
+            assert_eq!(
+                ParachainStaking::at_stake_commission(3, 1),
+                Some(Perbill::one())
+            );
+            set_author(3, 1, 1);
+            // setting the commission back only affects snapshots from now on
+            assert_ok!(ParachainStaking::set_candidate_commission(
+                RuntimeOrigin::signed(1),
+                Perbill::zero()
+            ));
+            roll_to_round_begin(5);
+            // collator takes the whole reward of round 3
+            assert!(Balances::free_balance(&1) > 20);
+            assert_eq!(Balances::free_balance(&2), 40);
+            assert!(ParachainStaking::at_stake_commission(3, 1).is_none());
+        });
+}
+
+// OFFLINE CANDIDATES
+
+fn offline_policy() -> OfflinePolicy {
+    OfflinePolicy {
+        min_authored: Percent::from_percent(100),
+        max_missed_rounds: 2,
+        jail_rounds: 2,
+        slash: Perbill::from_percent(10),
+    }
+}
+
+/// Builds six candidates of which 1..=5 are selected, with `offline_policy` in place
+fn offline_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .build();
+    ext.execute_with(|| {
+        assert_ok!(ParachainStaking::set_offline_policy(
+            RuntimeOrigin::root(),
+            offline_policy()
+        ));
+    });
+    ext
+}
+
+/// Every selected candidate but 1 authors its expected block in `round`
+fn author_all_but_1(round: u32) {
+    for author in 2..=5 {
+        set_author(round, author, 20);
+    }
+}
+
+#[test]
+fn set_offline_policy_event_emits_correctly() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(ParachainStaking::set_offline_policy(
+            RuntimeOrigin::root(),
+            offline_policy()
+        ));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::OfflinePolicySet {
+            old: OfflinePolicy::default(),
+            new: offline_policy(),
+        }));
+        assert_eq!(ParachainStaking::offline_policy(), offline_policy());
+    });
+}
+
+#[test]
+fn cannot_set_offline_policy_to_current_policy() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_offline_policy(RuntimeOrigin::root(), OfflinePolicy::default()),
+            Error::<Test>::NoWritingSameValue
+        );
+    });
+}
+
+#[test]
+fn cannot_set_offline_policy_if_not_monetary_governance() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::set_offline_policy(RuntimeOrigin::signed(45), offline_policy()),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn default_offline_policy_never_jails() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .build()
+        .execute_with(|| {
+            for round in 1..5 {
+                author_all_but_1(round);
+                roll_to_round_begin(round + 1);
+            }
+            assert_eq!(ParachainStaking::missed_rounds(&1), 0);
+            assert!(ParachainStaking::jailed_until(&1).is_none());
+            assert!(ParachainStaking::candidate_info(&1).unwrap().is_active());
+        });
+}
+
+#[test]
+fn missed_round_is_tracked_and_reset_by_authoring() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        assert_event_emitted!(Event::CandidateMissedRound {
+            candidate: 1,
+            round: 1,
+            authored: 0,
+            expected: 1,
+            missed_rounds: 1,
+        });
+        assert_eq!(ParachainStaking::missed_rounds(&1), 1);
+        assert_eq!(ParachainStaking::missed_rounds(&2), 0);
+        author_all_but_1(2);
+        set_author(2, 1, 20);
+        roll_to_round_begin(3);
+        assert_eq!(ParachainStaking::missed_rounds(&1), 0);
+        assert!(ParachainStaking::candidate_info(&1).unwrap().is_active());
+    });
+}
+
+#[test]
+fn missed_rounds_are_reset_when_candidate_is_not_selected() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(1)));
+        roll_to_round_begin(2);
+        assert_eq!(ParachainStaking::missed_rounds(&1), 1);
+        assert!(!ParachainStaking::is_selected_candidate(&1));
+        assert_ok!(ParachainStaking::go_online(RuntimeOrigin::signed(1)));
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        assert_eq!(ParachainStaking::missed_rounds(&1), 0);
+        assert!(ParachainStaking::is_selected_candidate(&1));
+        author_all_but_1(3);
+        roll_to_round_begin(4);
+        assert_eq!(ParachainStaking::missed_rounds(&1), 1);
+        assert!(ParachainStaking::jailed_until(&1).is_none());
+    });
+}
+
+#[test]
+fn missed_round_expectation_uses_length_of_evaluated_round() {
+    offline_ext().execute_with(|| {
+        assert_ok!(ParachainStaking::set_blocks_per_round(
+            RuntimeOrigin::root(),
+            15
+        ));
+        roll_to(15);
+        assert_eq!(ParachainStaking::round().current, 2);
+        // round 2 is shortened after 14 of its blocks, so it ends after 15 blocks and 3 blocks
+        // per candidate were expected
+        roll_to(29);
+        assert_ok!(ParachainStaking::set_blocks_per_round(
+            RuntimeOrigin::root(),
+            5
+        ));
+        for author in 1..=5 {
+            set_author(2, author, 20);
+        }
+        roll_to(30);
+        assert_eq!(ParachainStaking::round().current, 3);
+        assert_event_emitted!(Event::CandidateMissedRound {
+            candidate: 1,
+            round: 2,
+            authored: 1,
+            expected: 3,
+            missed_rounds: 1,
+        });
+    });
+}
+
+#[test]
+fn rounds_without_authored_blocks_are_not_tracked() {
+    offline_ext().execute_with(|| {
+        roll_to_round_begin(4);
+        for candidate in 1..=5 {
+            assert_eq!(ParachainStaking::missed_rounds(&candidate), 0);
+        }
+    });
+}
+
+#[test]
+fn candidate_is_jailed_and_slashed_after_max_missed_rounds() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        assert_event_emitted!(Event::CandidateSlashed {
+            candidate: 1,
+            amount: 2,
+            new_bond: 18,
+        });
+        assert_event_emitted!(Event::CandidateJailed {
+            candidate: 1,
+            rejoin_round: 5,
+        });
+        let state = ParachainStaking::candidate_info(&1).expect("still a candidate");
+        assert_eq!(state.status, CollatorStatus::Idle);
+        assert_eq!(state.bond, 18);
+        assert_eq!(state.total_counted, 18);
+        assert!(!ParachainStaking::candidate_pool()
+            .0
+            .iter()
+            .any(|bond| bond.owner == 1));
+        assert!(!ParachainStaking::is_selected_candidate(&1));
+        assert_eq!(ParachainStaking::jailed_until(&1), Some(5));
+        assert_eq!(ParachainStaking::missed_rounds(&1), 0);
+        assert_eq!(ParachainStaking::total(), 108);
+        assert_eq!(Balances::free_balance(&1), 18);
+        assert_eq!(
+            crate::mock::query_lock_amount(1, COLLATOR_LOCK_ID),
+            Some(18)
+        );
+        assert_eq!(
+            Balances::free_balance(&crate::mock::TreasuryAccount::get()),
+            2
+        );
+    });
+}
+
+#[test]
+fn candidate_is_not_jailed_below_min_selected_candidates() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_offline_policy(
+                RuntimeOrigin::root(),
+                offline_policy()
+            ));
+            for round in 1..4 {
+                author_all_but_1(round);
+                roll_to_round_begin(round + 1);
+            }
+            assert_eq!(ParachainStaking::missed_rounds(&1), 3);
+            assert!(ParachainStaking::jailed_until(&1).is_none());
+            assert!(ParachainStaking::candidate_info(&1).unwrap().is_active());
+        });
+}
+
+#[test]
+fn jailed_candidate_cannot_go_online() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        assert_noop!(
+            ParachainStaking::go_online(RuntimeOrigin::signed(1)),
+            Error::<Test>::CannotGoOnlineIfJailed
+        );
+    });
+}
+
+#[test]
+fn cannot_rejoin_if_not_jailed() {
+    offline_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::rejoin(RuntimeOrigin::signed(1)),
+            Error::<Test>::CandidateNotJailed
+        );
+    });
+}
+
+#[test]
+fn cannot_rejoin_before_jail_ends() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        roll_to_round_begin(4);
+        assert_noop!(
+            ParachainStaking::rejoin(RuntimeOrigin::signed(1)),
+            Error::<Test>::CandidateCannotRejoinYet
+        );
+    });
+}
+
+#[test]
+fn rejoin_returns_candidate_to_pool() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        roll_to_round_begin(5);
+        assert_ok!(ParachainStaking::rejoin(RuntimeOrigin::signed(1)));
+        assert_last_event!(MetaEvent::ParachainStaking(Event::CandidateRejoined {
+            candidate: 1
+        }));
+        assert!(ParachainStaking::candidate_info(&1).unwrap().is_active());
+        assert!(ParachainStaking::candidate_pool()
+            .0
+            .iter()
+            .any(|bond| bond.owner == 1 && bond.amount == 18));
+        assert!(ParachainStaking::jailed_until(&1).is_none());
+    });
+}
+
+#[test]
+fn rejoin_requires_bond_above_min_after_slash() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .with_candidates(vec![(1, 12), (2, 20), (3, 20), (4, 20), (5, 20), (6, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_offline_policy(
+                RuntimeOrigin::root(),
+                OfflinePolicy {
+                    slash: Perbill::from_percent(50),
+                    ..offline_policy()
+                }
+            ));
+            author_all_but_1(1);
+            roll_to_round_begin(2);
+            author_all_but_1(2);
+            roll_to_round_begin(3);
+            assert_eq!(ParachainStaking::candidate_info(&1).unwrap().bond, 6);
+            roll_to_round_begin(5);
+            assert_noop!(
+                ParachainStaking::rejoin(RuntimeOrigin::signed(1)),
+                Error::<Test>::CandidateBondBelowMin
+            );
+            assert_ok!(ParachainStaking::candidate_bond_more(
+                RuntimeOrigin::signed(1),
+                4
+            ));
+            assert_ok!(ParachainStaking::rejoin(RuntimeOrigin::signed(1)));
+        });
+}
+
+#[test]
+fn cancel_leave_candidates_keeps_jailed_candidate_offline() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        assert_ok!(ParachainStaking::schedule_leave_candidates(
+            RuntimeOrigin::signed(1),
+            5
+        ));
+        assert_ok!(ParachainStaking::cancel_leave_candidates(
+            RuntimeOrigin::signed(1),
+            5
+        ));
+        let state = ParachainStaking::candidate_info(&1).unwrap();
+        assert_eq!(state.status, CollatorStatus::Idle);
+        assert!(!ParachainStaking::candidate_pool()
+            .0
+            .iter()
+            .any(|bond| bond.owner == 1));
+    });
+}
+
+#[test]
+fn execute_leave_candidates_clears_jail() {
+    offline_ext().execute_with(|| {
+        author_all_but_1(1);
+        roll_to_round_begin(2);
+        author_all_but_1(2);
+        roll_to_round_begin(3);
+        assert_ok!(ParachainStaking::schedule_leave_candidates(
+            RuntimeOrigin::signed(1),
+            5
+        ));
+        roll_to_round_begin(5);
+        assert_ok!(ParachainStaking::execute_leave_candidates(
+            RuntimeOrigin::signed(1),
+            1,
+            0
+        ));
+        assert!(ParachainStaking::jailed_until(&1).is_none());
+        assert_eq!(Balances::free_balance(&1), 18);
+    });
+}
+
+// PAYOUT STAKERS
+
+/// Builds three selected collators of which 1 and 2 authored blocks in round 2, and rolls to the
+/// start of round 4 in which the first of them is paid
+fn lagging_payout_ext() -> sp_io::TestExternalities {
+    let mut ext = ExtBuilder::default()
+        .with_balances(vec![
+            (1, 100),
+            (2, 100),
+            (3, 100),
+            (4, 100),
+            (5, 100),
+            (6, 100),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+        .with_delegations(vec![(4, 1, 10), (5, 2, 10)])
+        .build();
+    ext.execute_with(|| {
+        set_author(2, 1, 20);
+        set_author(2, 2, 20);
+        roll_to_round_begin(4);
+        assert_eq!(<AwardedPts<Test>>::iter_prefix(2).count(), 1);
+    });
+    ext
+}
+
+/// Asserts that the payout state of `round` is removed, and its snapshots in the next block
+fn assert_round_payouts_cleaned_up(round: u32) {
+    assert!(!<DelayedPayouts<Test>>::contains_key(round));
+    assert!(!<Points<Test>>::contains_key(round));
+    roll_one_block();
+    assert_eq!(<AwardedPts<Test>>::iter_prefix(round).count(), 0);
+    assert_eq!(<AtStake<Test>>::iter_prefix(round).count(), 0);
+    assert_eq!(<AtStakeCommission<Test>>::iter_prefix(round).count(), 0);
+}
+
+#[test]
+fn on_idle_pays_remaining_collators_of_round() {
+    lagging_payout_ext().execute_with(|| {
+        ParachainStaking::on_idle(System::block_number(), Weight::MAX);
+        assert_round_payouts_cleaned_up(2);
+        for staker in [1, 2, 4, 5] {
+            assert!(Balances::free_balance(&staker) > 100);
+        }
+        assert_eq!(Balances::free_balance(&3), 100);
+    });
+}
+
+#[test]
+fn on_idle_without_remaining_weight_pays_nothing() {
+    lagging_payout_ext().execute_with(|| {
+        ParachainStaking::on_idle(System::block_number(), Weight::zero());
+        assert!(<DelayedPayouts<Test>>::contains_key(2));
+        assert_eq!(<AwardedPts<Test>>::iter_prefix(2).count(), 1);
+    });
+}
+
+#[test]
+fn payout_stakers_pays_collator_and_cleans_up_round() {
+    lagging_payout_ext().execute_with(|| {
+        let (collator, _) = <AwardedPts<Test>>::iter_prefix(2)
+            .next()
+            .expect("one collator left to pay");
+        let delegator = if collator == 1 { 4 } else { 5 };
+        assert_eq!(Balances::free_balance(&delegator), 100);
+        assert_ok!(ParachainStaking::payout_stakers(
+            RuntimeOrigin::signed(6),
+            2,
+            collator
+        ));
+        assert!(Balances::free_balance(&collator) > 100);
+        assert!(Balances::free_balance(&delegator) > 100);
+        assert_round_payouts_cleaned_up(2);
+    });
+}
+
+#[test]
+fn payout_snapshots_are_removed_across_blocks() {
+    let snapshots = 2 * crate::MAX_PAYOUT_CLEANUP_KEYS as u64;
+    let mut ext = lagging_payout_ext();
+    ext.execute_with(|| {
+        // pay and clean up round 2 first
+        roll_one_block();
+        assert_eq!(crate::PendingPayoutCleanups::<Test>::iter().count(), 0);
+        for account in 100..100 + snapshots {
+            <AwardedPts<Test>>::insert(9, account, 20);
+        }
+        ParachainStaking::clean_up_round_payouts(9);
+    });
+    // NOTE: The snapshots are written in earlier blocks than their removal on chain.
+    ext.commit_all().expect("committing the snapshots works");
+    ext.execute_with(|| {
+        roll_one_block();
+        assert_eq!(
+            <AwardedPts<Test>>::iter_prefix(9).count() as u64,
+            snapshots - crate::MAX_PAYOUT_CLEANUP_KEYS as u64
+        );
+        assert!(crate::PendingPayoutCleanups::<Test>::contains_key(9));
+        roll_one_block();
+        roll_one_block();
+        assert_eq!(<AwardedPts<Test>>::iter_prefix(9).count(), 0);
+        assert!(!crate::PendingPayoutCleanups::<Test>::contains_key(9));
+    });
+}
+
+#[test]
+fn cannot_payout_stakers_without_delayed_payout() {
+    lagging_payout_ext().execute_with(|| {
+        assert_noop!(
+            ParachainStaking::payout_stakers(RuntimeOrigin::signed(6), 3, 1),
+            Error::<Test>::DelayedPayoutDNE
+        );
+    });
+}
+
+#[test]
+fn cannot_payout_stakers_twice_or_without_points() {
+    lagging_payout_ext().execute_with(|| {
+        let (collator, _) = <AwardedPts<Test>>::iter_prefix(2)
+            .next()
+            .expect("one collator left to pay");
+        let paid = if collator == 1 { 2 } else { 1 };
+        assert_noop!(
+            ParachainStaking::payout_stakers(RuntimeOrigin::signed(6), 2, paid),
+            Error::<Test>::AwardedPtsDNE
+        );
+        assert_noop!(
+            ParachainStaking::payout_stakers(RuntimeOrigin::signed(6), 2, 3),
+            Error::<Test>::AwardedPtsDNE
+        );
+    });
+}
+
+// STAKING RUNTIME API
+
+#[test]
+fn delegator_position_flags_bottom_delegations() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (8, 20),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![
+            (3, 1, 10),
+            (4, 1, 10),
+            (5, 1, 10),
+            (6, 1, 10),
+            (8, 1, 5),
+            (8, 2, 5),
+        ])
+        .build()
+        .execute_with(|| {
+            let position = ParachainStaking::delegator_position(&8).expect("delegator exists");
+            assert_eq!(position.total, 10);
+            assert_eq!(position.less_total, 0);
+            let mut delegations = position.delegations;
+            delegations.sort_by_key(|d| d.collator);
+            assert_eq!(
+                delegations,
+                vec![
+                    DelegationPosition {
+                        collator: 1,
+                        amount: 5,
+                        in_bottom: true,
+                    },
+                    DelegationPosition {
+                        collator: 2,
+                        amount: 5,
+                        in_bottom: false,
+                    },
+                ]
+            );
+            assert!(ParachainStaking::delegator_position(&1).is_none());
+        });
+}
+
+#[test]
+fn delegator_pending_requests_reports_executable_round() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 30), (2, 20), (3, 20)])
+        .with_candidates(vec![(1, 30), (3, 20)])
+        .with_delegations(vec![(2, 1, 10), (2, 3, 10)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::schedule_revoke_delegation(
+                RuntimeOrigin::signed(2),
+                1
+            ));
+            assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+                RuntimeOrigin::signed(2),
+                3,
+                4
+            ));
+            let mut requests = ParachainStaking::delegator_pending_requests(&2);
+            requests.sort_by_key(|r| r.collator);
+            assert_eq!(
+                requests,
+                vec![
+                    PendingUnbondRequest {
+                        collator: 1,
+                        when_executable: 3,
+                        executable: false,
+                        action: DelegationAction::Revoke(10),
+                    },
+                    PendingUnbondRequest {
+                        collator: 3,
+                        when_executable: 3,
+                        executable: false,
+                        action: DelegationAction::Decrease(4),
+                    },
+                ]
+            );
+            roll_to_round_begin(3);
+            assert!(ParachainStaking::delegator_pending_requests(&2)
+                .iter()
+                .all(|r| r.executable));
+        });
+}
+
+#[test]
+fn candidate_summary_reports_thresholds() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (1, 20),
+            (2, 20),
+            (3, 20),
+            (4, 20),
+            (5, 20),
+            (6, 20),
+            (8, 20),
+        ])
+        .with_candidates(vec![(1, 20), (2, 20)])
+        .with_delegations(vec![
+            (3, 1, 11),
+            (4, 1, 12),
+            (5, 1, 13),
+            (6, 1, 14),
+            (8, 1, 5),
+        ])
+        .build()
+        .execute_with(|| {
+            let summary = ParachainStaking::candidate_summary(&1).expect("candidate exists");
+            assert_eq!(summary.bond, 20);
+            assert_eq!(summary.delegation_count, 5);
+            assert_eq!(summary.total_counted, 70);
+            assert_eq!(summary.lowest_top_delegation_amount, 11);
+            assert_eq!(summary.highest_bottom_delegation_amount, 5);
+            assert_eq!(summary.lowest_bottom_delegation_amount, 5);
+            assert_eq!(summary.top_capacity, CapacityStatus::Full);
+            assert_eq!(summary.bottom_capacity, CapacityStatus::Partial);
+            assert_eq!(summary.status, CollatorStatus::Active);
+            assert_eq!(summary.commission, Perbill::from_percent(20));
+            assert!(summary.is_selected);
+            assert_eq!(ParachainStaking::candidate_summaries().len(), 2);
+            assert!(ParachainStaking::candidate_summary(&3).is_none());
+        });
+}
+
+#[test]
+fn estimated_round_reward_splits_evenly_without_points() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 1000), (2, 1000)])
+        .with_candidates(vec![(1, 100), (2, 100)])
+        .build()
+        .execute_with(|| {
+            roll_to_round_begin(2);
+            let reward = ParachainStaking::estimated_round_reward(&1);
+            assert!(!reward.is_zero());
+            assert_eq!(ParachainStaking::estimated_round_reward(&2), reward);
+            assert!(ParachainStaking::estimated_round_reward(&3).is_zero());
+        });
+}
+
+#[test]
+fn estimated_round_reward_matches_payout() {
+    ExtBuilder::default()
+        .with_balances(vec![(1, 100), (2, 100), (11, 1)])
+        .with_candidates(vec![(1, 100)])
+        .with_delegations(vec![(2, 1, 50)])
+        .build()
+        .execute_with(|| {
+            assert_ok!(ParachainStaking::set_parachain_bond_account(
+                RuntimeOrigin::root(),
+                11
+            ));
+            roll_to_round_begin(2);
+            set_author(2, 1, 20);
+            let collator_estimate = ParachainStaking::estimated_round_reward(&1);
+            let delegator_estimate = ParachainStaking::estimated_round_reward(&2);
+            assert!(!collator_estimate.is_zero());
+            assert!(!delegator_estimate.is_zero());
+            roll_to_round_begin(4);
+            roll_one_block();
+            assert_eq!(Balances::free_balance(&1), 100 + collator_estimate);
+            assert_eq!(Balances::free_balance(&2), 100 + delegator_estimate);
+        });
+}
+
 // ~~ PROPERTY-BASED TESTS ~~
 
 #[test]
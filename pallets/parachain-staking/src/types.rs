@@ -17,14 +17,17 @@
 //! Types for parachain-staking
 
 use crate::{
-    set::OrderedSet, BalanceOf, BottomDelegations, CandidateInfo, Config, DelegatorState, Error,
-    Event, Pallet, Round, RoundIndex, TopDelegations, Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
+    set::OrderedSet, BalanceOf, BottomDelegations, CandidateInfo, Config, DelegationAction,
+    DelegatorState, Error, Event, LastRedelegation, Pallet, RewardDestinations, Round, RoundIndex,
+    TopDelegations, Total, COLLATOR_LOCK_ID, DELEGATOR_LOCK_ID,
 };
 use frame_support::{
     pallet_prelude::*,
     traits::{tokens::WithdrawReasons, LockableCurrency},
 };
 use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::{
     traits::{AtLeast32BitUnsigned, Saturating, Zero},
     Perbill, Percent, RuntimeDebug,
@@ -81,6 +84,7 @@ impl<AccountId: Ord, Balance> PartialEq for Bond<AccountId, Balance> {
     }
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 /// The activity status of the collator
 pub enum CollatorStatus {
@@ -160,7 +164,8 @@ pub struct DelayedPayout<Balance> {
     pub round_issuance: Balance,
     /// The total inflation paid this round to stakers (e.g. less parachain bond fund)
     pub total_staking_reward: Balance,
-    /// Snapshot of collator commission rate at the end of the round
+    /// Snapshot of the global collator commission rate at the end of the round, used for
+    /// collators without a rate in `AtStakeCommission`
     pub collator_commission: Perbill,
 }
 
@@ -209,6 +214,60 @@ pub struct CandidateBondLessRequest<Balance> {
     pub when_executable: RoundIndex,
 }
 
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Request scheduled to increase the collator candidate commission
+pub struct CandidateCommissionIncreaseRequest {
+    pub commission: Perbill,
+    pub when_executable: RoundIndex,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Governance bounds for the commission collator candidates may set { min, max }
+pub struct CommissionBounds {
+    pub min: Perbill,
+    pub max: Perbill,
+}
+impl Default for CommissionBounds {
+    fn default() -> CommissionBounds {
+        CommissionBounds {
+            min: Perbill::zero(),
+            max: Perbill::one(),
+        }
+    }
+}
+impl CommissionBounds {
+    pub fn is_valid(&self) -> bool {
+        self.min <= self.max
+    }
+    pub fn contains(&self, commission: Perbill) -> bool {
+        self.min <= commission && commission <= self.max
+    }
+    /// Clamp `commission` into the bounds
+    pub fn clamp(&self, commission: Perbill) -> Perbill {
+        commission.max(self.min).min(self.max)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+/// Governance policy applied to selected candidates which author too few blocks in a round
+pub struct OfflinePolicy {
+    /// Share of its expected blocks a selected candidate must author in each round
+    pub min_authored: Percent,
+    /// Consecutive underperforming rounds after which the candidate is jailed
+    pub max_missed_rounds: u32,
+    /// Number of rounds a jailed candidate must wait before it may `rejoin`
+    pub jail_rounds: RoundIndex,
+    /// Share of the self bond slashed into the treasury when the candidate is jailed
+    pub slash: Perbill,
+}
+impl OfflinePolicy {
+    /// The default policy never jails any candidate
+    pub fn is_enabled(&self) -> bool {
+        !self.min_authored.is_zero() && !self.max_missed_rounds.is_zero()
+    }
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// DEPRECATED, replaced by `CandidateMetadata` and two storage instances of `Delegations`
 /// Collator candidate state with self bond + delegations
@@ -325,7 +384,8 @@ impl<AccountId, Balance: Copy + Ord + sp_std::ops::AddAssign + Zero + Saturating
     }
 }
 
-#[derive(PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
 /// Capacity status for top or bottom delegations
 pub enum CapacityStatus {
     /// Reached capacity
@@ -689,6 +749,8 @@ impl<
             });
             if leaving {
                 <DelegatorState<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+                <RewardDestinations<T>>::remove(&lowest_bottom_to_be_kicked.owner);
+                <LastRedelegation<T>>::remove(&lowest_bottom_to_be_kicked.owner);
                 Pallet::<T>::deposit_event(Event::DelegatorLeft {
                     delegator: lowest_bottom_to_be_kicked.owner,
                     unstaked_amount: lowest_bottom_to_be_kicked.amount,
@@ -1685,6 +1747,109 @@ impl<A: Decode> Default for ParachainBondConfig<A> {
     }
 }
 
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+/// Beneficiary of a share of the parachain bond reserve
+pub struct ParachainBondBeneficiary<AccountId> {
+    /// Account which receives the share
+    pub account: AccountId,
+    /// Weight of the beneficiary relative to the other beneficiaries
+    pub weight: u32,
+    /// Number of rounds each allocation stays locked in the beneficiary account
+    pub lock_rounds: RoundIndex,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Parachain bond allocation locked in a beneficiary account until `unlock_round`
+pub struct ParachainBondLock<Balance> {
+    pub amount: Balance,
+    pub unlock_round: RoundIndex,
+}
+
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Where the staking rewards of a collator or delegator are paid
+pub enum RewardDestination<AccountId> {
+    /// Pay into the staking account as free balance
+    Staked,
+    /// Pay into a separate payee account
+    Account(AccountId),
+    /// Pay into the treasury
+    Treasury,
+}
+impl<A> Default for RewardDestination<A> {
+    fn default() -> RewardDestination<A> {
+        RewardDestination::Staked
+    }
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// A single delegation of a delegator as seen by the `ParachainStakingApi`
+pub struct DelegationPosition<AccountId, Balance> {
+    /// The collator delegated to
+    pub collator: AccountId,
+    /// The delegated amount
+    pub amount: Balance,
+    /// Whether the delegation is in the bottom delegations of the collator and earns no rewards
+    pub in_bottom: bool,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Full staking position of a delegator as seen by the `ParachainStakingApi`
+pub struct DelegatorPosition<AccountId, Balance> {
+    /// Total balance locked for this delegator
+    pub total: Balance,
+    /// Sum of pending revocation amounts + bond less amounts
+    pub less_total: Balance,
+    /// All current delegations
+    pub delegations: Vec<DelegationPosition<AccountId, Balance>>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// A scheduled revoke or bond decrease of a delegator as seen by the `ParachainStakingApi`
+pub struct PendingUnbondRequest<AccountId, Balance> {
+    /// The collator the request applies to
+    pub collator: AccountId,
+    /// The round from which on the request can be executed
+    pub when_executable: RoundIndex,
+    /// Whether the request can be executed in the current round
+    pub executable: bool,
+    /// The scheduled action
+    pub action: DelegationAction<Balance>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Summary of a collator candidate as seen by the `ParachainStakingApi`
+pub struct CandidateSummary<AccountId, Balance> {
+    /// The candidate account
+    pub candidate: AccountId,
+    /// This candidate's self bond amount
+    pub bond: Balance,
+    /// Total number of delegations to this candidate
+    pub delegation_count: u32,
+    /// Self bond + sum of top delegations
+    pub total_counted: Balance,
+    /// The smallest top delegation amount, a new delegation must exceed it when top is full
+    pub lowest_top_delegation_amount: Balance,
+    /// The highest bottom delegation amount
+    pub highest_bottom_delegation_amount: Balance,
+    /// The smallest bottom delegation amount, a new delegation must exceed it when bottom is full
+    pub lowest_bottom_delegation_amount: Balance,
+    /// Capacity status for top delegations
+    pub top_capacity: CapacityStatus,
+    /// Capacity status for bottom delegations
+    pub bottom_capacity: CapacityStatus,
+    /// Current status of the collator
+    pub status: CollatorStatus,
+    /// Commission rate applied to the rewards of this candidate
+    pub commission: Perbill,
+    /// Whether the candidate is selected to collate in the current round
+    pub is_selected: bool,
+}
+
 pub enum BondAdjust<Balance> {
     Increase(Balance),
     Decrease,
@@ -50,6 +50,7 @@ const VESTING_ID: LockIdentifier = *b"calamvst";
 pub mod pallet {
     use super::*;
     use frame_support::traits::StorageVersion;
+    use sp_std::vec::Vec;
 
     const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -111,6 +112,31 @@ pub mod pallet {
         .unwrap_or_default()
     }
 
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Accounts and the amount of their balance locked by the vesting schedule.
+        pub vesting: Vec<(T::AccountId, BalanceOf<T>)>,
+    }
+
+    #[cfg(feature = "std")]
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            Self {
+                vesting: Default::default(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+        fn build(&self) {
+            for (who, locked) in &self.vesting {
+                Pallet::<T>::new_vesting_account(who, *locked)
+                    .expect("Genesis vesting accounts must be unique.");
+            }
+        }
+    }
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     #[pallet::without_storage_info]
@@ -40,7 +40,7 @@ frame_support::construct_runtime!(
         System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
         Timestamp: pallet_timestamp::{Pallet, Call, Storage, Inherent},
         Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-        CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Event<T>},
+        CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Config<T>, Event<T>},
     }
 );
 
@@ -113,12 +113,14 @@ impl Config for Test {
 
 pub struct ExtBuilder {
     existential_deposit: Balance,
+    vesting: Vec<(AccountId, Balance)>,
 }
 
 impl Default for ExtBuilder {
     fn default() -> Self {
         Self {
             existential_deposit: 1,
+            vesting: vec![],
         }
     }
 }
@@ -129,6 +131,11 @@ impl ExtBuilder {
         self
     }
 
+    pub fn vesting(mut self, vesting: Vec<(AccountId, Balance)>) -> Self {
+        self.vesting = vesting;
+        self
+    }
+
     pub fn build(self) -> sp_io::TestExternalities {
         EXISTENTIAL_DEPOSIT.with(|v| *v.borrow_mut() = self.existential_deposit);
         let mut t = frame_system::GenesisConfig::default()
@@ -139,6 +146,11 @@ impl ExtBuilder {
         }
         .assimilate_storage(&mut t)
         .unwrap();
+        calamari_vesting::GenesisConfig::<Test> {
+            vesting: self.vesting,
+        }
+        .assimilate_storage(&mut t)
+        .unwrap();
 
         let mut ext = sp_io::TestExternalities::new(t);
         ext.execute_with(|| {
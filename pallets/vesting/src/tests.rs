@@ -560,3 +560,21 @@ fn check_vesting_schedule() {
             }
         });
 }
+
+#[test]
+fn genesis_vesting_should_lock_balance() {
+    let locked = ALICE_DEPOSIT / 2;
+    ExtBuilder::default()
+        .vesting(vec![(ALICE, locked)])
+        .build()
+        .execute_with(|| {
+            assert_eq!(VestingBalances::<Test>::get(ALICE), Some(locked));
+            assert_eq!(Balances::locks(ALICE)[0].amount, locked);
+            assert!(Balances::transfer(
+                RuntimeOrigin::signed(ALICE),
+                BOB,
+                ALICE_DEPOSIT - locked + 1
+            )
+            .is_err());
+        });
+}
@@ -155,6 +155,23 @@ where
         AssetId = Self::AssetId,
         Balance = Self::Balance,
     >;
+
+    /// Frozen Assets
+    ///
+    /// The source of truth for which assets are frozen. Frozen assets are rejected by the
+    /// [`FungibleLedger`] implementations.
+    type FrozenAssets: FrozenAssets<AssetId = Self::AssetId>;
+
+    /// Restricted Assets
+    ///
+    /// Assets that are registered in a restricted state and cannot be used in MantaPay.
+    type RestrictedAssets: RestrictedAssets<AssetId = Self::AssetId>;
+
+    /// Outbound Rate Limits
+    ///
+    /// Throttles the amount of each asset that can be withdrawn by XCM from accounts other than
+    /// the sovereign accounts of the relay chain and of siblings.
+    type OutboundRateLimits: OutboundRateLimits<AssetId = Self::AssetId, Balance = Self::Balance>;
 }
 
 /// Asset Storage Metadata
@@ -208,6 +225,9 @@ pub struct AssetRegistryMetadata<B> {
 pub trait TestingDefault {
     /// Returns some default asset metadata
     fn testing_default() -> Self;
+
+    /// Returns the testing default value for an asset which is not sufficient.
+    fn testing_default_insufficient() -> Self;
 }
 
 impl TestingDefault for AssetRegistryMetadata<MantaBalance> {
@@ -223,6 +243,13 @@ impl TestingDefault for AssetRegistryMetadata<MantaBalance> {
             is_sufficient: true,
         }
     }
+
+    fn testing_default_insufficient() -> Self {
+        Self {
+            is_sufficient: false,
+            ..Self::testing_default()
+        }
+    }
 }
 
 impl<B> BalanceType for AssetRegistryMetadata<B> {
@@ -295,6 +322,36 @@ pub trait UnitsPerSecond: AssetIdType {
     fn units_per_second(asset_id: &Self::AssetId) -> Option<u128>;
 }
 
+/// Defines which assets are currently frozen.
+pub trait FrozenAssets: AssetIdType {
+    /// Returns `true` if `asset_id` is frozen and must not be moved in or out of any account.
+    fn is_frozen(asset_id: &Self::AssetId) -> bool;
+}
+
+/// Defines which assets are registered in a restricted state, e.g. while their registration is
+/// still pending approval.
+pub trait RestrictedAssets: AssetIdType {
+    /// Returns `true` if `asset_id` is restricted and must not be used in MantaPay.
+    fn is_restricted(asset_id: &Self::AssetId) -> bool;
+}
+
+/// Defines how much of each asset may leave the chain through XCM.
+pub trait OutboundRateLimits: AssetIdType + BalanceType {
+    /// Returns `true` if withdrawing `amount` of `asset_id` now stays within its outbound limit.
+    fn is_within_limit(asset_id: &Self::AssetId, amount: &Self::Balance) -> bool;
+
+    /// Records that `amount` of `asset_id` was withdrawn to leave the chain.
+    fn note_outbound(asset_id: &Self::AssetId, amount: &Self::Balance);
+
+    /// Records that withdrawing `amount` of `asset_id` was rejected because it exceeds the
+    /// outbound limit.
+    ///
+    /// The record is reverted with the rest of a failing extrinsic, so it only persists for XCM
+    /// executed from incoming messages. Local transfers such as `xtokens::transfer` only surface
+    /// the rejection as their dispatch error.
+    fn note_rejected(asset_id: &Self::AssetId, amount: &Self::Balance);
+}
+
 /// Converter struct implementing `Convert`. MultiLocation to AssetId and the reverse.
 pub struct AssetIdLocationConvert<M>(PhantomData<M>);
 
@@ -355,6 +412,9 @@ pub enum FungibleLedgerError<I, B> {
 
     /// Encode Error
     EncodeError,
+
+    /// The asset has been frozen and cannot be deposited, withdrawn or transferred.
+    FrozenAsset(I),
 }
 
 impl<I, B> FungibleLedgerError<I, B> {
@@ -390,7 +450,8 @@ pub trait FungibleLedger: AssetIdType + BalanceType {
     /// Account Id Type
     type AccountId;
 
-    /// Checks if an asset id is valid and returning and [`Error`](FungibleLedgerError) otherwise.
+    /// Checks if an asset id is valid and not frozen, returning an [`Error`](FungibleLedgerError)
+    /// otherwise.
     fn ensure_valid(
         asset_id: Self::AssetId,
     ) -> Result<Self::AssetId, FungibleLedgerError<Self::AssetId, Self::Balance>>;
@@ -443,6 +504,17 @@ pub trait FungibleLedger: AssetIdType + BalanceType {
         amount: Self::Balance,
         existence_requirement: ExistenceRequirement,
     ) -> Result<(), FungibleLedgerError<Self::AssetId, Self::Balance>>;
+
+    /// Returns the total supply of `asset_id`.
+    fn supply(asset_id: Self::AssetId) -> Self::Balance;
+
+    /// Burns the entire balance of `who` in the non-native `asset_id`, returning the amount that
+    /// was burned. Unlike [`withdraw_burning`](Self::withdraw_burning), this also works for
+    /// frozen assets.
+    fn force_burn(
+        asset_id: Self::AssetId,
+        who: &Self::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>>;
 }
 
 /// Fungible Ledger Implementation for Native and NonNative Assets
@@ -489,6 +561,9 @@ where
         asset_id: Self::AssetId,
     ) -> Result<Self::AssetId, FungibleLedgerError<Self::AssetId, Self::Balance>> {
         if asset_id >= A::StartNonNativeAssetId::get() || asset_id == A::NativeAssetId::get() {
+            if A::FrozenAssets::is_frozen(&asset_id) {
+                return Err(FungibleLedgerError::FrozenAsset(asset_id));
+            }
             Ok(asset_id)
         } else {
             Err(FungibleLedgerError::InvalidAssetId(asset_id))
@@ -637,4 +712,26 @@ where
         }
         Ok(())
     }
+
+    #[inline]
+    fn supply(asset_id: Self::AssetId) -> Self::Balance {
+        if asset_id == A::NativeAssetId::get() {
+            <Native as fungible::Inspect<C::AccountId>>::total_issuance()
+        } else {
+            NonNative::total_issuance(asset_id)
+        }
+    }
+
+    /// Only non-native assets can be force burned.
+    #[inline]
+    fn force_burn(
+        asset_id: Self::AssetId,
+        who: &C::AccountId,
+    ) -> Result<Self::Balance, FungibleLedgerError<Self::AssetId, Self::Balance>> {
+        if asset_id < A::StartNonNativeAssetId::get() {
+            return Err(FungibleLedgerError::InvalidAssetId(asset_id));
+        }
+        let amount = NonNative::balance(asset_id.clone(), who);
+        NonNative::burn_from(asset_id, who, amount).map_err(FungibleLedgerError::InvalidBurn)
+    }
 }
@@ -27,3 +27,6 @@ pub mod assets;
 pub mod constants;
 pub mod types;
 pub mod xcm;
+
+#[cfg(feature = "std")]
+pub mod scale_hex;
@@ -0,0 +1,49 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! SCALE Hex Serialization
+//!
+//! Serializes values as the hex string of their SCALE encoding, for genesis configurations whose
+//! types have no `serde` support, e.g. XCM locations or shielded ledger entries:
+//!
+//! ```ignore
+//! #[serde(with = "manta_primitives::scale_hex")]
+//! pub assets: Vec<(T::Location, AssetRegistryMetadataOf<T>)>,
+//! ```
+
+use codec::{Decode, Encode};
+use serde::{de::Error, Deserializer, Serializer};
+
+/// Serializes the SCALE encoding of `value` as a hex string.
+#[inline]
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Encode,
+    S: Serializer,
+{
+    sp_core::bytes::serialize(&value.encode(), serializer)
+}
+
+/// Deserializes a value from the hex string of its SCALE encoding.
+#[inline]
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Decode,
+    D: Deserializer<'de>,
+{
+    let bytes = sp_core::bytes::deserialize(deserializer)?;
+    T::decode(&mut &bytes[..]).map_err(D::Error::custom)
+}
@@ -17,33 +17,44 @@
 //! XCM primitives and implementations
 
 use super::{
-    assets::{AssetConfig, FungibleLedger},
+    assets::{AssetConfig, AssetIdType, BalanceType, FungibleLedger, OutboundRateLimits},
     constants::WEIGHT_PER_SECOND,
 };
 
-use sp_runtime::traits::{CheckedConversion, Convert, Zero};
-use sp_std::marker::PhantomData;
+use codec::Encode;
+use core::borrow::Borrow;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+    traits::{CheckedConversion, Convert, UniqueSaturatedInto, Zero},
+    DispatchResult,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 use crate::assets::{AssetIdLocationMap, UnitsPerSecond};
 use frame_support::{
+    ensure,
     pallet_prelude::Get,
-    traits::{fungibles::Mutate, tokens::ExistenceRequirement},
+    traits::{tokens::ExistenceRequirement, Contains, OriginTrait},
+    weights::{Weight, WeightToFee},
 };
 use frame_system::Config;
 use xcm::{
-    latest::{prelude::Concrete, Error as XcmError},
+    latest::{
+        prelude::Concrete, Error as XcmError, Instruction, OriginKind, Weight as XcmWeight,
+        WeightLimit, Xcm,
+    },
     v1::{
         AssetId as XcmAssetId, Fungibility,
-        Junction::{AccountId32, Parachain},
-        Junctions::X1,
+        Junction::{AccountId32, AccountKey20, GeneralKey, PalletInstance, Parachain},
+        Junctions::{Here, X1, X2},
         MultiAsset, MultiLocation, NetworkId,
     },
 };
 use xcm_builder::TakeRevenue;
 use xcm_executor::{
     traits::{
-        Convert as XcmConvert, FilterAssetLocation, MatchesFungible, MatchesFungibles,
-        TransactAsset, WeightTrader,
+        Convert as XcmConvert, ConvertOrigin, FilterAssetLocation, MatchesFungible,
+        MatchesFungibles, ShouldExecute, TransactAsset, WeightTrader,
     },
     Assets,
 };
@@ -75,6 +86,14 @@ impl Reserve for MultiAsset {
     }
 }
 
+/// Returns the fee charged for executing `weight` at a price of `units_per_second`.
+///
+/// This is the computation used by [`MultiAssetTrader`] when buying and refunding weight.
+#[inline]
+pub fn units_per_second_to_fee(units_per_second: u128, weight: u64) -> u128 {
+    units_per_second.saturating_mul(weight as u128) / (WEIGHT_PER_SECOND as u128)
+}
+
 /// Multi-Native Asset Filter
 ///
 /// Filters multi-native assets whose reserve is same as the `origin`.
@@ -106,199 +125,246 @@ where
     }
 }
 
+/// Price of an asset accepted by the [`MultiAssetTrader`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum AssetPrice {
+    /// Native asset, priced by the runtime `WeightToFee`
+    Native,
+
+    /// Non-native asset, priced by its units per second
+    UnitsPerSecond(u128),
+}
+
+impl AssetPrice {
+    /// Returns the fee for executing `weight` at this price.
+    #[inline]
+    fn fee<W>(&self, weight: u64) -> u128
+    where
+        W: WeightToFee,
+    {
+        match self {
+            Self::Native => {
+                W::weight_to_fee(&Weight::from_ref_time(weight)).unique_saturated_into()
+            }
+            Self::UnitsPerSecond(units_per_second) => {
+                units_per_second_to_fee(*units_per_second, weight)
+            }
+        }
+    }
+}
+
+/// Weight bought with a single asset by the [`MultiAssetTrader`]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PaidAsset {
+    /// Asset Location
+    location: MultiLocation,
+
+    /// Amount paid and not refunded yet
+    amount: u128,
+
+    /// Weight bought and not refunded yet
+    weight: u64,
+
+    /// Asset Price
+    price: AssetPrice,
+}
+
+/// Multi-Asset Weight Trader
 ///
-/// This trader defines how to charge a XCM call.
-/// This takes the first fungible asset, and takes UnitPerSecondGetter that implements
-/// UnitToWeightRatio trait.
-pub struct FirstAssetTrader<M, R>
+/// Buys weight with the first asset of the payment that has a price and covers the fee. The native
+/// asset, located at `N` or `here()`, is priced by the runtime `WeightToFee` `W`, and the other
+/// assets by their [`UnitsPerSecond`] in `M`.
+///
+/// Every asset used in a `buy_weight` call is tracked separately, so that weight is refunded in the
+/// asset which paid for it, starting from the last one. The fees which are not refunded are handed
+/// to `R` when the trader is dropped.
+pub struct MultiAssetTrader<M, W, N, R>
 where
     R: TakeRevenue,
 {
-    /// Weight
-    weight: u64,
-
-    /// Refund Cache
-    refund_cache: Option<(MultiLocation, u128, u128)>,
+    /// Assets Paid, in the order of their first payment
+    paid: Vec<PaidAsset>,
 
     /// Type Parameter Marker
-    __: PhantomData<(M, R)>,
+    __: PhantomData<(M, W, N, R)>,
+}
+
+impl<M, W, N, R> MultiAssetTrader<M, W, N, R>
+where
+    M: AssetIdLocationMap + UnitsPerSecond,
+    M::Location: From<MultiLocation>,
+    N: Get<MultiLocation>,
+    R: TakeRevenue,
+{
+    /// Returns the price of the asset at `location`, or `None` if it cannot pay for execution.
+    #[inline]
+    fn price(location: &MultiLocation) -> Option<AssetPrice> {
+        if location.is_here() || location == &N::get() {
+            return Some(AssetPrice::Native);
+        }
+        M::asset_id(&location.clone().into())
+            .and_then(|asset_id| M::units_per_second(&asset_id))
+            .map(AssetPrice::UnitsPerSecond)
+    }
 }
 
-impl<M, R> WeightTrader for FirstAssetTrader<M, R>
+impl<M, W, N, R> WeightTrader for MultiAssetTrader<M, W, N, R>
 where
     M: AssetIdLocationMap + UnitsPerSecond,
     M::Location: From<MultiLocation>,
+    W: WeightToFee,
+    N: Get<MultiLocation>,
     R: TakeRevenue,
 {
     #[inline]
     fn new() -> Self {
         Self {
-            weight: 0,
-            refund_cache: None,
+            paid: Vec::new(),
             __: PhantomData,
         }
     }
 
     /// Buys weight for XCM execution. We always return the [`TooExpensive`](Error::TooExpensive)
-    /// error if this fails.
+    /// error if no asset in `payment` can pay for `weight`.
     #[inline]
     fn buy_weight(&mut self, weight: u64, payment: Assets) -> Result<Assets> {
         log::debug!(
-            target: "FirstAssetTrader::buy_weight",
+            target: "MultiAssetTrader::buy_weight",
             "weight: {:?}, payment: {:?}",
             weight,
             payment
         );
+        let assets = payment.fungible_assets_iter().collect::<Vec<_>>();
+        let mut payment = payment;
+        for asset in assets {
+            let location = match asset.id {
+                XcmAssetId::Concrete(location) => location,
+                _ => continue,
+            };
+            let price = match Self::price(&location) {
+                Some(price) => price,
+                None => {
+                    log::debug!(
+                        target: "MultiAssetTrader::buy_weight",
+                        "no price for asset with location: {:?}",
+                        location,
+                    );
+                    continue;
+                }
+            };
+            let amount = price.fee::<W>(weight);
 
-        let first_asset = payment.fungible_assets_iter().next().ok_or({
+            // we don't need to proceed if amount is zero.
+            // This is very useful in tests.
+            if amount.is_zero() {
+                return Ok(payment);
+            }
+            let required = MultiAsset {
+                fun: Fungibility::Fungible(amount),
+                id: XcmAssetId::Concrete(location.clone()),
+            };
             log::debug!(
-                target: "FirstAssetTrader::buy_weight",
-                "no assets in payment: {:?}",
+                target: "MultiAssetTrader::buy_weight",
+                "payment: {:?}, required: {:?}",
                 payment,
+                required,
             );
-            XcmError::TooExpensive
-        })?;
-
-        // Check the first asset
-        match (first_asset.id, first_asset.fun) {
-            (XcmAssetId::Concrete(id), Fungibility::Fungible(_)) => {
-                let asset_id = M::asset_id(&id.clone().into()).ok_or({
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "asset_id missing for asset location with id: {:?}",
-                        id,
-                    );
-                    XcmError::TooExpensive
-                })?;
-                let units_per_second = M::units_per_second(&asset_id).ok_or({
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "units_per_second missing for asset with id: {:?}",
-                        id,
-                    );
-                    XcmError::TooExpensive
-                })?;
-
-                let amount =
-                    (units_per_second.saturating_mul(weight as u128)) / (WEIGHT_PER_SECOND as u128);
-
-                // we don't need to proceed if amount is zero.
-                // This is very useful in tests.
-                if amount.is_zero() {
-                    return Ok(payment);
-                }
-                let required = MultiAsset {
-                    fun: Fungibility::Fungible(amount),
-                    id: XcmAssetId::Concrete(id.clone()),
-                };
-
-                log::debug!(
-                    target: "FirstAssetTrader::buy_weight",
-                    "payment: {:?}, required: {:?}",
-                    payment,
-                    required,
-                );
-                let unused = payment.checked_sub(required).map_err(|_| {
-                    log::debug!(
-                        target: "FirstAssetTrader::buy_weight",
-                        "not enough required assets in payment",
-                    );
-                    XcmError::TooExpensive
-                })?;
-                self.weight = self.weight.saturating_add(weight);
-
-                // In case the asset matches the one the trader already stored before, add
-                // to later refund
-
-                // Else we are always going to subtract the weight if we can, but we latter do
-                // not refund it
-
-                // In short, we only refund on the asset the trader first successfully was able
-                // to pay for an execution
-                let new_asset = match &self.refund_cache {
-                    Some((prev_id, prev_amount, units_per_second)) => {
-                        if prev_id == &id {
-                            Some((id, prev_amount.saturating_add(amount), *units_per_second))
-                        } else {
-                            None
+            payment = match payment.checked_sub(required) {
+                Ok(unused) => {
+                    match self.paid.iter_mut().find(|paid| paid.location == location) {
+                        Some(paid) => {
+                            paid.amount = paid.amount.saturating_add(amount);
+                            paid.weight = paid.weight.saturating_add(weight);
                         }
+                        None => self.paid.push(PaidAsset {
+                            location,
+                            amount,
+                            weight,
+                            price,
+                        }),
                     }
-                    None => Some((id, amount, units_per_second)),
-                };
-
-                // Due to the trait bound, we can only refund one asset.
-                if let Some(new_asset) = new_asset {
-                    self.weight = self.weight.saturating_add(weight);
-                    self.refund_cache = Some(new_asset);
-                };
-                Ok(unused)
-            }
-            _ => {
-                log::debug!(
-                    target: "FirstAssetTrader::buy_weight",
-                    "no matching XcmAssetId for first_asset in payment: {:?}",
-                    payment,
-                );
-                Err(XcmError::TooExpensive)
-            }
+                    return Ok(unused);
+                }
+                Err(payment) => payment,
+            };
         }
+        log::debug!(
+            target: "MultiAssetTrader::buy_weight",
+            "no asset in payment can pay for the weight",
+        );
+        Err(XcmError::TooExpensive)
     }
 
-    ///
+    /// Refunds `weight` in the last asset which still has weight bought, up to that weight.
     #[inline]
     fn refund_weight(&mut self, weight: u64) -> Option<MultiAsset> {
-        if let Some((id, prev_amount, units_per_second)) = &mut self.refund_cache {
-            let weight = weight.min(self.weight);
-            self.weight = self.weight.saturating_sub(weight);
-            let amount =
-                ((*units_per_second).saturating_mul(weight as u128)) / (WEIGHT_PER_SECOND as u128);
-            *prev_amount = prev_amount.saturating_sub(amount);
-            Some(MultiAsset {
-                fun: Fungibility::Fungible(amount),
-                id: XcmAssetId::Concrete(id.clone()),
-            })
+        let paid = self.paid.iter_mut().rev().find(|paid| paid.weight > 0)?;
+        let weight = weight.min(paid.weight);
+        let amount = if weight == paid.weight {
+            paid.amount
         } else {
-            None
+            paid.price.fee::<W>(weight).min(paid.amount)
+        };
+        paid.weight = paid.weight.saturating_sub(weight);
+        paid.amount = paid.amount.saturating_sub(amount);
+        if amount.is_zero() {
+            return None;
         }
+        Some(MultiAsset {
+            fun: Fungibility::Fungible(amount),
+            id: XcmAssetId::Concrete(paid.location.clone()),
+        })
     }
 }
 
-impl<M, R> Drop for FirstAssetTrader<M, R>
+impl<M, W, N, R> Drop for MultiAssetTrader<M, W, N, R>
 where
     R: TakeRevenue,
 {
     /// Handles spent fees, depositing them as defined by `R`.
     #[inline]
     fn drop(&mut self) {
-        if let Some((id, amount, _)) = &self.refund_cache {
-            R::take_revenue((id.clone(), *amount).into());
+        for paid in &self.paid {
+            if !paid.amount.is_zero() {
+                R::take_revenue((paid.location.clone(), paid.amount).into());
+            }
         }
     }
 }
 
 ///
 /// XCM fee depositor to which we implement the TakeRevenue trait
-/// It receives a fungibles::Mutate implemented argument, a matcher to convert MultiAsset into
-/// AssetId and amount, and the fee receiver account
-pub struct XcmFeesToAccount<AccountId, A, M, R>(PhantomData<(AccountId, A, M, R)>);
+/// It receives the asset configuration `A`, the matchers `Native` and `NonNative` to convert
+/// MultiAsset into AssetId and amount, and the fee receiver account `R`. Both native and
+/// non-native fees are deposited through the [`FungibleLedger`] of `A`.
+pub struct XcmFeesToAccount<T, A, Native, NonNative, R>(PhantomData<(T, A, Native, NonNative, R)>);
 
-impl<AccountId, A, M, R> TakeRevenue for XcmFeesToAccount<AccountId, A, M, R>
+impl<T, A, Native, NonNative, R> TakeRevenue for XcmFeesToAccount<T, A, Native, NonNative, R>
 where
-    A: Mutate<AccountId>,
-    M: MatchesFungibles<A::AssetId, A::Balance>,
-    R: Get<AccountId>,
+    T: Config,
+    A: AssetConfig<T>,
+    Native: MatchesFungible<A::Balance>,
+    NonNative: MatchesFungibles<A::AssetId, A::Balance>,
+    R: Get<T::AccountId>,
 {
     #[inline]
     fn take_revenue(revenue: MultiAsset) {
-        match M::matches_fungibles(&revenue) {
-            Ok((asset_id, amount)) => {
-                if !amount.is_zero() {
-                    if let Err(err) = A::mint_into(asset_id, &R::get(), amount) {
-                        log::debug!(target: "manta-xcm", "mint_into failed with {:?}", err);
-                    }
-                }
+        if revenue.fun == Fungibility::Fungible(0) {
+            return;
+        }
+        let (asset_id, amount) = match (
+            Native::matches_fungible(&revenue),
+            NonNative::matches_fungibles(&revenue),
+        ) {
+            (Some(amount), _) => (A::NativeAssetId::get(), amount),
+            (_, Ok((asset_id, amount))) => (asset_id, amount),
+            _ => {
+                log::debug!(target: "manta-xcm", "take revenue failed matching fungible");
+                return;
             }
-            _ => log::debug!(target: "manta-xcm", "take revenue failed matching fungible"),
+        };
+        if let Err(err) = A::FungibleLedger::deposit_minting(asset_id, &R::get(), amount) {
+            log::debug!(target: "manta-xcm", "deposit_minting failed with {:?}", err);
         }
     }
 }
@@ -326,6 +392,29 @@ where
     }
 }
 
+/// Matches the incoming `asset` to an `asset_id` and `amount` on this chain, using the matcher
+/// implementation of both native and non-native assets.
+#[inline]
+fn match_asset<T, A, Native, NonNative>(asset: &MultiAsset) -> Result<(A::AssetId, A::Balance)>
+where
+    T: Config,
+    A: AssetConfig<T>,
+    Native: MatchesFungible<A::Balance>,
+    NonNative: MatchesFungibles<A::AssetId, A::Balance>,
+{
+    match (
+        Native::matches_fungible(asset),
+        NonNative::matches_fungibles(asset),
+    ) {
+        // native asset
+        (Some(amount), _) => Ok((A::NativeAssetId::get(), amount)),
+        // assets asset
+        (_, Ok((asset_id, amount))) => Ok((asset_id, amount)),
+        // unknown asset
+        _ => Err(XcmError::FailedToTransactAsset("Unknown Asset")),
+    }
+}
+
 ///
 pub struct MultiAssetAdapter<T, A, AccountIdConverter, Native, NonNative>(
     PhantomData<(T, A, AccountIdConverter, Native, NonNative)>,
@@ -352,17 +441,7 @@ where
         let receiver = AccountIdConverter::convert_ref(location).map_err(|_| {
             XcmError::FailedToTransactAsset("Failed Location to AccountId Conversion")
         })?;
-        let (asset_id, amount) = match (
-            Native::matches_fungible(asset),
-            NonNative::matches_fungibles(asset),
-        ) {
-            // native asset
-            (Some(amount), _) => (A::NativeAssetId::get(), amount),
-            // assets asset
-            (_, Ok((asset_id, amount))) => (asset_id, amount),
-            // unknown asset
-            _ => return Err(XcmError::FailedToTransactAsset("Unknown Asset")),
-        };
+        let (asset_id, amount) = match_asset::<T, A, Native, NonNative>(asset)?;
         Ok((asset_id, receiver, amount))
     }
 }
@@ -400,13 +479,202 @@ where
             asset, location,
         );
         let (asset_id, who, amount) = Self::match_asset_and_location(asset, location)?;
+        // NOTE: Withdrawals from the sovereign accounts of the relay chain and of siblings are the
+        //       first step of inbound transfers. Any other account, including the derivative
+        //       accounts of sibling users, is controlled on this chain and its withdrawals can
+        //       leave the chain.
+        let is_outbound = !matches!(
+            (location.parents, &location.interior),
+            (1, Here) | (1, X1(Parachain(_)))
+        );
+        if is_outbound && !A::OutboundRateLimits::is_within_limit(&asset_id, &amount) {
+            A::OutboundRateLimits::note_rejected(&asset_id, &amount);
+            return Err(XcmError::FailedToTransactAsset(
+                "Outbound Rate Limit Exceeded",
+            ));
+        }
         A::FungibleLedger::withdraw_burning(
-            asset_id,
+            asset_id.clone(),
             &who,
-            amount,
+            amount.clone(),
             ExistenceRequirement::AllowDeath,
         )
         .map_err(|_| XcmError::FailedToTransactAsset("Failed Burn"))?;
+        if is_outbound {
+            A::OutboundRateLimits::note_outbound(&asset_id, &amount);
+        }
         Ok(asset.clone().into())
     }
 }
+
+/// Private Deposit
+///
+/// Receives assets deposited over XCM on behalf of a `to_private` transfer which is posted
+/// separately, so that the assets are shielded without ever reaching a public account.
+pub trait PrivateDeposit: AssetIdType + BalanceType {
+    /// Holds `amount` of `asset_id` for the `to_private` transfer post whose hash is
+    /// `commitment`.
+    fn deposit(
+        asset_id: Self::AssetId,
+        amount: Self::Balance,
+        commitment: [u8; 32],
+    ) -> DispatchResult;
+}
+
+/// Private Deposit Adapter
+///
+/// Deposits to `(0, X2(PalletInstance(P::get()), GeneralKey(commitment)))` are handed over to
+/// the [`PrivateDeposit`] handler `D`, where `commitment` is the 32-byte hash of the
+/// `to_private` transfer post shielding the deposit. Any other deposit or withdrawal is left to
+/// the next transactor, so this adapter goes in front of the [`MultiAssetAdapter`] in a tuple.
+pub struct PrivateDepositAdapter<T, A, D, P, Native, NonNative>(
+    PhantomData<(T, A, D, P, Native, NonNative)>,
+);
+
+impl<T, A, D, P, Native, NonNative> TransactAsset
+    for PrivateDepositAdapter<T, A, D, P, Native, NonNative>
+where
+    T: Config,
+    A: AssetConfig<T>,
+    D: PrivateDeposit<AssetId = A::AssetId, Balance = A::Balance>,
+    P: Get<u8>,
+    Native: MatchesFungible<A::Balance>,
+    NonNative: MatchesFungibles<A::AssetId, A::Balance>,
+{
+    #[inline]
+    fn deposit_asset(asset: &MultiAsset, location: &MultiLocation) -> Result {
+        let commitment = match (location.parents, &location.interior) {
+            (0, X2(PalletInstance(index), GeneralKey(key))) if *index == P::get() => {
+                <[u8; 32]>::try_from(&key[..]).map_err(|_| {
+                    XcmError::FailedToTransactAsset("Invalid Private Deposit Commitment")
+                })?
+            }
+            _ => return Err(XcmError::AssetNotFound),
+        };
+        log::debug!(
+            target: "xcm::private_deposit_adapter",
+            "deposit_asset asset: {:?}, commitment: {:?}",
+            asset, commitment,
+        );
+        let (asset_id, amount) = match_asset::<T, A, Native, NonNative>(asset)?;
+        D::deposit(asset_id, amount, commitment)
+            .map_err(|_| XcmError::FailedToTransactAsset("Failed Private Deposit"))
+    }
+}
+
+/// Sibling Account Hash Converter
+///
+/// Converts an account on a sibling parachain, `(1, X2(Parachain(id), AccountId32 | AccountKey20))`,
+/// into a derivative account on this chain, by hashing the parachain `id` together with the
+/// account key. Nobody holds the key of the derivative account, so it is only controlled by its
+/// sibling account through XCM, e.g. with a `Transact` to delegate to a collator.
+pub struct SiblingAccountHash<AccountId>(PhantomData<AccountId>);
+
+impl<AccountId> XcmConvert<MultiLocation, AccountId> for SiblingAccountHash<AccountId>
+where
+    AccountId: Clone + From<[u8; 32]>,
+{
+    #[inline]
+    fn convert_ref(location: impl Borrow<MultiLocation>) -> core::result::Result<AccountId, ()> {
+        match (location.borrow().parents, &location.borrow().interior) {
+            (1, X2(Parachain(para_id), AccountId32 { id, .. })) => {
+                Ok((b"SiblingChain", para_id, id)
+                    .using_encoded(blake2_256)
+                    .into())
+            }
+            (1, X2(Parachain(para_id), AccountKey20 { key, .. })) => {
+                Ok((b"SiblingChain", para_id, key)
+                    .using_encoded(blake2_256)
+                    .into())
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+/// Allows paid execution of the messages in which an origin `T` first descends into one of its
+/// accounts, i.e. a `DescendOrigin` followed by the instructions accepted by
+/// [`AllowTopLevelPaidExecutionFrom`](xcm_builder::AllowTopLevelPaidExecutionFrom). This is the
+/// shape of the messages sent by sibling chains to `Transact` from a [`SiblingAccountHash`]
+/// derivative account.
+pub struct AllowTopLevelPaidExecutionDescendOriginFirst<T>(PhantomData<T>);
+
+impl<T> ShouldExecute for AllowTopLevelPaidExecutionDescendOriginFirst<T>
+where
+    T: Contains<MultiLocation>,
+{
+    #[inline]
+    fn should_execute<RuntimeCall>(
+        origin: &MultiLocation,
+        message: &mut Xcm<RuntimeCall>,
+        max_weight: XcmWeight,
+        _weight_credit: &mut XcmWeight,
+    ) -> core::result::Result<(), ()> {
+        ensure!(T::contains(origin), ());
+        let mut iter = message.0.iter_mut();
+        match iter.next().ok_or(())? {
+            Instruction::DescendOrigin(..) => (),
+            _ => return Err(()),
+        }
+        match iter.next().ok_or(())? {
+            Instruction::ReceiveTeleportedAsset(..)
+            | Instruction::WithdrawAsset(..)
+            | Instruction::ReserveAssetDeposited(..)
+            | Instruction::ClaimAsset { .. } => (),
+            _ => return Err(()),
+        }
+        let mut instruction = iter.next().ok_or(())?;
+        while let Instruction::ClearOrigin = instruction {
+            instruction = iter.next().ok_or(())?;
+        }
+        match instruction {
+            Instruction::BuyExecution {
+                weight_limit: WeightLimit::Limited(ref mut weight),
+                ..
+            } if *weight >= max_weight => {
+                *weight = max_weight;
+                Ok(())
+            }
+            Instruction::BuyExecution {
+                ref mut weight_limit,
+                ..
+            } if weight_limit == &WeightLimit::Unlimited => {
+                *weight_limit = WeightLimit::Limited(max_weight);
+                Ok(())
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+/// Call Filtering Origin Converter
+///
+/// Converts origins with `C`, restricting the ones converted from a location in `T` to the calls
+/// in `F`, for instance the derivative accounts of sibling chains converted by
+/// [`SiblingAccountHash`]. A call outside of `F` transacted from such an origin fails with
+/// `CallFiltered`, like any filtered call, while the origins converted from other locations, e.g.
+/// the relay chain as a superuser, are left unrestricted.
+pub struct FilteredOriginConverter<C, T, F>(PhantomData<(C, T, F)>);
+
+impl<C, T, F, RuntimeOrigin> ConvertOrigin<RuntimeOrigin> for FilteredOriginConverter<C, T, F>
+where
+    C: ConvertOrigin<RuntimeOrigin>,
+    T: Contains<MultiLocation>,
+    F: Contains<RuntimeOrigin::Call> + 'static,
+    RuntimeOrigin: OriginTrait,
+    RuntimeOrigin::Call: 'static,
+{
+    #[inline]
+    fn convert_origin(
+        origin: impl Into<MultiLocation>,
+        kind: OriginKind,
+    ) -> core::result::Result<RuntimeOrigin, MultiLocation> {
+        let origin = origin.into();
+        let is_filtered = T::contains(&origin);
+        let mut converted = C::convert_origin(origin, kind)?;
+        if is_filtered {
+            converted.add_filter(F::contains);
+        }
+        Ok(converted)
+    }
+}
@@ -25,6 +25,10 @@ pub mod aura;
 pub mod nimbus;
 pub mod vrf;
 
+#[cfg(feature = "runtime")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "runtime")))]
+pub mod runtime;
+
 pub use aura::AuraId;
 pub use nimbus::NimbusId;
 pub use vrf::VrfId;
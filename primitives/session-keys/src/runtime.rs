@@ -0,0 +1,70 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Session Keys Registry Runtime APIs
+
+use parity_scale_codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::crypto::KeyTypeId;
+use sp_std::vec::Vec;
+
+/// Registration Status of a Single Session Key
+#[derive(Clone, Debug, Decode, Encode, Eq, PartialEq, TypeInfo)]
+pub struct KeyStatus {
+    /// Key Type of the Session Key, e.g. `aura`, `nmbs` or `rand`
+    pub key_type: KeyTypeId,
+
+    /// Raw Public Key registered on-chain with `set_keys`, if any
+    pub registered: Option<Vec<u8>>,
+
+    /// Whether the registered key is used by the active authority set to author blocks
+    pub active: bool,
+
+    /// Whether the registered key is queued for the next session
+    pub queued: bool,
+}
+
+/// Registration Status of the Session Keys of an Account
+#[derive(Clone, Debug, Decode, Default, Encode, Eq, PartialEq, TypeInfo)]
+pub struct SessionKeyStatus {
+    /// Status of every key type of the runtime session keys
+    pub keys: Vec<KeyStatus>,
+
+    /// Whether the account is a validator of the current session
+    pub is_validator: bool,
+
+    /// Whether the account is queued for the next session with all its registered keys
+    pub queued_complete: bool,
+}
+
+impl SessionKeyStatus {
+    /// Returns `true` if every key type has a registered key.
+    #[inline]
+    pub fn is_registered(&self) -> bool {
+        self.keys.iter().all(|key| key.registered.is_some())
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// Session Keys Registry API
+    pub trait SessionKeysRegistryApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Returns the registration status of the session keys of `account`.
+        fn session_key_status(account: AccountId) -> SessionKeyStatus;
+    }
+}
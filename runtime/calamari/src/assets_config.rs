@@ -17,7 +17,7 @@
 use super::{
     weights, xcm_config::SelfReserve, AssetManager, Assets, Balances,
     NativeTokenExistentialDeposit, Runtime, RuntimeEvent, RuntimeOrigin, TechnicalCollective,
-    Timestamp, KMA,
+    Timestamp, Treasury, KMA,
 };
 
 use manta_primitives::{
@@ -26,17 +26,17 @@ use manta_primitives::{
         AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
     constants::{
-        ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, MANTA_PAY_PALLET_ID, MANTA_SBT_PALLET_ID,
-        WEIGHT_PER_MILLIS,
+        time::DAYS, ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, MANTA_PAY_PALLET_ID,
+        MANTA_SBT_PALLET_ID, WEIGHT_PER_MILLIS, WEIGHT_PER_SECOND,
     },
-    types::{AccountId, Balance, CalamariAssetId},
+    types::{AccountId, Balance, BlockNumber, CalamariAssetId},
 };
 
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse},
-    weights::Weight,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse, Get},
+    weights::{Weight, WeightToFee},
     PalletId,
 };
 
@@ -145,6 +145,7 @@ parameter_types! {
         is_sufficient: true,
     };
     pub const AssetManagerPalletId: PalletId = ASSET_MANAGER_PALLET_ID;
+    pub const AssetRegistrationDeposit: Balance = 1_000 * KMA;
 }
 
 pub type CalamariConcreteFungibleLedger =
@@ -171,6 +172,21 @@ impl AssetConfig<Runtime> for CalamariAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = CalamariAssetRegistry;
     type FungibleLedger = CalamariConcreteFungibleLedger;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
+}
+
+/// Native token units charged per second of XCM execution, from which the units-per-second of
+/// assets with a relative price are derived.
+pub struct NativeUnitsPerSecond;
+impl Get<u128> for NativeUnitsPerSecond {
+    #[inline]
+    fn get() -> u128 {
+        <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+            &Weight::from_ref_time(WEIGHT_PER_SECOND),
+        )
+    }
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -181,11 +197,22 @@ impl pallet_asset_manager::Config for Runtime {
     type AssetConfig = CalamariAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = AssetRegistrationDeposit;
+    type Slash = Treasury;
+    type PriceFeederOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 2, 3>,
+    >;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type PriceStalenessThreshold = ConstU32<DAYS>;
     type WeightInfo = weights::pallet_asset_manager::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
     pub const MantaPayPalletId: PalletId = MANTA_PAY_PALLET_ID;
+    pub const PrivateDepositExpiry: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_manta_pay::Config for Runtime {
@@ -193,6 +220,8 @@ impl pallet_manta_pay::Config for Runtime {
     type WeightInfo = weights::pallet_manta_pay::SubstrateWeight<Runtime>;
     type AssetConfig = CalamariAssetConfig;
     type PalletId = MantaPayPalletId;
+    type DepositReclaimOrigin = EnsureRoot<AccountId>;
+    type DepositExpiry = PrivateDepositExpiry;
 }
 
 parameter_types! {
@@ -280,6 +280,8 @@ impl Contains<RuntimeCall> for BaseFilter {
                 | pallet_parachain_staking::Call::schedule_candidate_bond_less{..}
                 | pallet_parachain_staking::Call::execute_candidate_bond_less{..}
                 | pallet_parachain_staking::Call::cancel_candidate_bond_less{..}
+                | pallet_parachain_staking::Call::set_candidate_commission{..}
+                | pallet_parachain_staking::Call::rejoin{..}
                 // Delegator extrinsics
                 | pallet_parachain_staking::Call::delegate{..}
                 | pallet_parachain_staking::Call::schedule_leave_delegators{..}
@@ -289,11 +291,22 @@ impl Contains<RuntimeCall> for BaseFilter {
                 | pallet_parachain_staking::Call::delegator_bond_more{..}
                 | pallet_parachain_staking::Call::schedule_delegator_bond_less{..}
                 | pallet_parachain_staking::Call::execute_delegation_request{..}
-                | pallet_parachain_staking::Call::cancel_delegation_request{..})
+                | pallet_parachain_staking::Call::cancel_delegation_request{..}
+                | pallet_parachain_staking::Call::schedule_redelegate{..}
+                // Collator and delegator extrinsics
+                | pallet_parachain_staking::Call::set_reward_destination{..}
+                // Parachain bond beneficiary extrinsics
+                | pallet_parachain_staking::Call::unlock_parachain_bond{..}
+                // Permissionless payout of delayed rewards
+                | pallet_parachain_staking::Call::payout_stakers{..})
             | RuntimeCall::Balances(_)
             | RuntimeCall::Preimage(_)
             | RuntimeCall::MantaPay(_)
             | RuntimeCall::MantaSbt(_)
+            // Proposed assets are only registered once governance approves them
+            | RuntimeCall::AssetManager(pallet_asset_manager::Call::propose_asset_registration {..}
+                // Relative prices are also fed by the Technical Committee, see `PriceFeederOrigin`
+                | pallet_asset_manager::Call::feed_relative_price {..})
             | RuntimeCall::XTokens(orml_xtokens::Call::transfer {..}
                 | orml_xtokens::Call::transfer_multicurrencies {..})
             | RuntimeCall::TransactionPause(_)
@@ -588,11 +601,14 @@ parameter_types! {
     pub const DefaultParachainBondReservePercent: Percent = Percent::zero();
     pub DefaultBlocksPerRound: BlockNumber = prod_or_fast!(6 * HOURS,15,"CALAMARI_DEFAULTBLOCKSPERROUND");
     pub LeaveDelayRounds: BlockNumber = prod_or_fast!(28,1,"CALAMARI_LEAVEDELAYROUNDS"); // == 7 * DAYS / 6 * HOURS
+    /// Receives rewards of stakers paying out to the treasury
+    pub StakingTreasuryAccount: AccountId = Treasury::account_id();
 }
 impl pallet_parachain_staking::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type BlockAuthor = AuthorInherent;
+    type TreasuryAccount = StakingTreasuryAccount;
     type MonetaryGovernanceOrigin = EnsureRoot<AccountId>;
     /// Minimum round length is 2 minutes (10 * 12 second block times)
     type MinBlocksPerRound = ConstU32<10>;
@@ -608,6 +624,12 @@ impl pallet_parachain_staking::Config for Runtime {
     type RevokeDelegationDelay = LeaveDelayRounds;
     /// Rounds before the delegator bond increase/decrease can be executed
     type DelegationBondLessDelay = LeaveDelayRounds;
+    /// Rounds before the candidate commission increase takes effect
+    type CommissionIncreaseDelay = LeaveDelayRounds;
+    /// Rounds before the redelegation can be executed, one day
+    type RedelegationDelay = ConstU32<4>;
+    /// Rounds between two redelegations of a delegator
+    type RedelegationCooldown = LeaveDelayRounds;
     /// Rounds before the reward is paid
     type RewardPaymentDelay = ConstU32<2>;
     /// Minimum collators selected per round, default at genesis and minimum forever after
@@ -618,6 +640,8 @@ impl pallet_parachain_staking::Config for Runtime {
     type MaxBottomDelegationsPerCandidate = ConstU32<50>;
     /// Maximum delegations per delegator
     type MaxDelegationsPerDelegator = ConstU32<25>;
+    type MaxParachainBondBeneficiaries = ConstU32<8>;
+    type MaxParachainBondLocks = ConstU32<64>;
     type DefaultCollatorCommission = DefaultCollatorCommission;
     type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
     /// Minimum stake on a collator to be considered for block production
@@ -771,6 +795,7 @@ impl manta_collator_selection::Config for Runtime {
     /// Nimbus filter pipeline step 2:
     /// Filters collators not part of the current pallet_session::validators()
     type CanAuthor = AuraAuthorFilter;
+    type PerformanceHistoryLength = ConstU32<4>; // 4 sessions of performance for reputation
 }
 
 // Calamari pallets configuration
@@ -848,11 +873,11 @@ construct_runtime!(
         // Assets management
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 45,
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Config<T>, Event<T>} = 46,
-        MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Event<T>} = 47,
-        MantaSbt: pallet_manta_sbt::{Pallet, Call, Storage, Event<T>} = 49,
+        MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Config, Event<T>} = 47,
+        MantaSbt: pallet_manta_sbt::{Pallet, Call, Storage, Config, Event<T>} = 49,
 
         // Calamari stuff
-        CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Event<T>} = 50,
+        CalamariVesting: calamari_vesting::{Pallet, Call, Storage, Config<T>, Event<T>} = 50,
     }
 );
 
@@ -882,7 +907,7 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, Si
 
 /// Types for runtime upgrading.
 /// Each type should implement trait `OnRuntimeUpgrade`.
-pub type OnRuntimeUpgradeHooks = ();
+pub type OnRuntimeUpgradeHooks = (manta_collator_selection::migrations::MigrateV1ToV2<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -1082,6 +1107,81 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_parachain_staking::runtime::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+        fn delegator_position(
+            delegator: AccountId,
+        ) -> Option<pallet_parachain_staking::DelegatorPosition<AccountId, Balance>> {
+            ParachainStaking::delegator_position(&delegator)
+        }
+        fn delegator_pending_requests(
+            delegator: AccountId,
+        ) -> Vec<pallet_parachain_staking::PendingUnbondRequest<AccountId, Balance>> {
+            ParachainStaking::delegator_pending_requests(&delegator)
+        }
+        fn candidate_summary(
+            candidate: AccountId,
+        ) -> Option<pallet_parachain_staking::CandidateSummary<AccountId, Balance>> {
+            ParachainStaking::candidate_summary(&candidate)
+        }
+        fn candidate_summaries() -> Vec<pallet_parachain_staking::CandidateSummary<AccountId, Balance>> {
+            ParachainStaking::candidate_summaries()
+        }
+        fn estimated_round_reward(account: AccountId) -> Balance {
+            ParachainStaking::estimated_round_reward(&account)
+        }
+    }
+
+    impl pallet_asset_manager::runtime::AssetManagerApi<
+        Block,
+        manta_primitives::types::CalamariAssetId,
+        manta_primitives::assets::AssetLocation,
+        manta_primitives::assets::AssetRegistryMetadata<Balance>,
+    > for Runtime {
+        fn asset(
+            asset_id: manta_primitives::types::CalamariAssetId,
+        ) -> Option<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_info(asset_id)
+        }
+        fn assets() -> Vec<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_infos()
+        }
+        fn quote_weight_fee(asset_id: manta_primitives::types::CalamariAssetId, weight: u64) -> Option<u128> {
+            AssetManager::quote_weight_fee(asset_id, weight)
+        }
+        fn quote_transfer_fee(
+            asset_id: manta_primitives::types::CalamariAssetId,
+            dest: manta_primitives::assets::AssetLocation,
+            dest_weight: u64,
+        ) -> Option<u128> {
+            AssetManager::quote_transfer_fee(asset_id, dest, dest_weight)
+        }
+    }
+
+    impl manta_collator_selection::runtime::CollatorSelectionApi<Block, AccountId> for Runtime {
+        fn collator_performance(
+            collator: AccountId,
+        ) -> Vec<manta_collator_selection::SessionPerformance> {
+            CollatorSelection::performance_history(collator)
+        }
+        fn reputation(collator: AccountId) -> Percent {
+            CollatorSelection::reputation(&collator)
+        }
+        fn reputations() -> Vec<(AccountId, Percent)> {
+            CollatorSelection::reputations()
+        }
+    }
+
+    impl session_key_primitives::runtime::SessionKeysRegistryApi<Block, AccountId> for Runtime {
+        fn session_key_status(
+            account: AccountId,
+        ) -> session_key_primitives::runtime::SessionKeyStatus {
+            runtime_common::session_keys::session_key_status::<Runtime, _>(account, |key_type, key| {
+                (key_type == sp_core::crypto::key_types::AURA)
+                    .then(|| Aura::authorities().iter().any(|id| id.as_ref() == key))
+            })
+        }
+    }
+
     impl nimbus_primitives::NimbusApi<Block> for Runtime {
         fn can_author(author: NimbusId, relay_parent: u32, parent_header: &<Block as BlockT>::Header) -> bool {
             let next_block_number = parent_header.number + 1;
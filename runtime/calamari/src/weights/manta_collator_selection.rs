@@ -57,6 +57,9 @@ pub trait WeightInfo {
     fn register_candidate(c: u32, ) -> Weight;
     fn note_author() -> Weight;
     fn new_session(c: u32, ) -> Weight;
+    fn set_reputation_selection() -> Weight;
+    fn set_eviction_cooldown() -> Weight;
+    fn update_bond(c: u32, ) -> Weight;
 }
 
 /// Weights for manta_collator_selection using the Substrate node and recommended hardware.
@@ -170,6 +173,28 @@ impl<T: frame_system::Config> manta_collator_selection::WeightInfo for Substrate
 			.saturating_add(T::DbWeight::get().writes(3))
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
+	// Storage: CollatorSelection ReputationSelection (r:0 w:1)
+	fn set_reputation_selection() -> Weight {
+		// Minimum execution time: 9_100 nanoseconds.
+		Weight::from_ref_time(9_100_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection EvictionCooldown (r:0 w:1)
+	fn set_eviction_cooldown() -> Weight {
+		// Minimum execution time: 9_050 nanoseconds.
+		Weight::from_ref_time(9_050_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection CandidacyBond (r:1 w:0)
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	fn update_bond(c: u32, ) -> Weight {
+		// Minimum execution time: 31_200 nanoseconds.
+		Weight::from_ref_time(31_200_000)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(512_000).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -282,4 +307,26 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3))
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
 	}
+	// Storage: CollatorSelection ReputationSelection (r:0 w:1)
+	fn set_reputation_selection() -> Weight {
+		// Minimum execution time: 9_100 nanoseconds.
+		Weight::from_ref_time(9_100_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection EvictionCooldown (r:0 w:1)
+	fn set_eviction_cooldown() -> Weight {
+		// Minimum execution time: 9_050 nanoseconds.
+		Weight::from_ref_time(9_050_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: CollatorSelection CandidacyBond (r:1 w:0)
+	// Storage: CollatorSelection Candidates (r:1 w:1)
+	fn update_bond(c: u32, ) -> Weight {
+		// Minimum execution time: 31_200 nanoseconds.
+		Weight::from_ref_time(31_200_000)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(512_000).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -50,6 +50,8 @@ pub trait WeightInfo {
     fn to_public() -> Weight;
     fn private_transfer() -> Weight;
     fn public_transfer() -> Weight;
+    fn to_private_from_deposit() -> Weight;
+    fn reclaim_deposit() -> Weight;
 }
 
 /// Weights for pallet_manta_pay using the Substrate node and recommended hardware.
@@ -105,6 +107,29 @@ impl<T: frame_system::Config> pallet_manta_pay::WeightInfo for SubstrateWeight<T
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	// Storage: MantaPay PendingDeposits (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: MantaPay UtxoSet (r:1 w:1)
+	// Storage: MantaPay NullifierSetSize (r:1 w:0)
+	// Storage: MantaPay ShardTrees (r:1 w:1)
+	// Storage: MantaPay UtxoAccumulatorOutputs (r:0 w:1)
+	// Storage: MantaPay Shards (r:0 w:1)
+	fn to_private_from_deposit() -> Weight {
+		// Minimum execution time: 39_467_081 nanoseconds.
+		Weight::from_ref_time(39_467_081_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: MantaPay PendingDeposits (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn reclaim_deposit() -> Weight {
+		// Minimum execution time: 64_398 nanoseconds.
+		Weight::from_ref_time(98_068_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
 
 // For backwards compatibility and tests
@@ -159,4 +184,27 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(3))
 			.saturating_add(RocksDbWeight::get().writes(3))
 	}
+	// Storage: MantaPay PendingDeposits (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: MantaPay UtxoSet (r:1 w:1)
+	// Storage: MantaPay NullifierSetSize (r:1 w:0)
+	// Storage: MantaPay ShardTrees (r:1 w:1)
+	// Storage: MantaPay UtxoAccumulatorOutputs (r:0 w:1)
+	// Storage: MantaPay Shards (r:0 w:1)
+	fn to_private_from_deposit() -> Weight {
+		// Minimum execution time: 39_467_081 nanoseconds.
+		Weight::from_ref_time(39_467_081_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	// Storage: MantaPay PendingDeposits (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:2 w:2)
+	fn reclaim_deposit() -> Weight {
+		// Minimum execution time: 64_398 nanoseconds.
+		Weight::from_ref_time(98_068_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
 }
@@ -15,16 +15,16 @@
 // along with Manta.  If not, see <http://www.gnu.org/licenses/>.
 
 use super::{
-    assets_config::CalamariAssetConfig, AssetManager, Assets, Balances, DmpQueue,
-    EnsureRootOrMoreThanHalfCouncil, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime,
-    RuntimeCall, RuntimeEvent, RuntimeOrigin, Treasury, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
+    assets_config::CalamariAssetConfig, AssetManager, DmpQueue, EnsureRootOrMoreThanHalfCouncil,
+    MantaPay, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeOrigin, Treasury, XcmpQueue, MAXIMUM_BLOCK_WEIGHT,
 };
 use codec::{Decode, Encode};
 use core::marker::PhantomData;
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use frame_support::{
     match_types, parameter_types,
-    traits::{Currency, Everything, Nothing},
+    traits::{Contains, Everything, Nothing, PalletInfoAccess},
     weights::Weight,
 };
 use frame_system::EnsureRoot;
@@ -32,8 +32,9 @@ use manta_primitives::{
     assets::AssetIdLocationConvert,
     types::{AccountId, Balance, CalamariAssetId},
     xcm::{
-        AccountIdToMultiLocation, FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter,
-        MultiNativeAsset,
+        AccountIdToMultiLocation, AllowTopLevelPaidExecutionDescendOriginFirst,
+        FilteredOriginConverter, IsNativeConcrete, MultiAssetAdapter, MultiAssetTrader,
+        MultiNativeAsset, PrivateDepositAdapter, SiblingAccountHash,
     },
 };
 use orml_traits::location::AbsoluteReserveProvider;
@@ -45,10 +46,9 @@ use xcm::latest::prelude::*;
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteAssetId,
-    EnsureXcmOrigin, FixedRateOfFungible, LocationInverter, ParentAsSuperuser, ParentIsPreset,
-    RelayChainAsNative, SiblingParachainAsNative, SiblingParachainConvertsVia,
-    SignedAccountId32AsNative, SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit,
-    WeightInfoBounds,
+    EnsureXcmOrigin, LocationInverter, ParentAsSuperuser, ParentIsPreset, RelayChainAsNative,
+    SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
+    SovereignSignedViaLocation, TakeWeightCredit, WeightInfoBounds,
 };
 use xcm_executor::{traits::JustTry, Config, XcmExecutor};
 
@@ -92,6 +92,9 @@ pub type LocationToAccountId = (
     SiblingParachainConvertsVia<Sibling, AccountId>,
     // Straight up local `AccountId32` origins just alias directly to `AccountId`.
     AccountId32Aliases<RelayNetwork, AccountId>,
+    // Accounts on sibling parachains convert to a derivative `AccountId` hashed from the para id
+    // and the account key.
+    SiblingAccountHash<AccountId>,
 );
 
 /// This is the type to convert an (incoming) XCM origin into a local `Origin` instance,
@@ -122,10 +125,6 @@ pub type XcmOriginToCallOrigin = (
 parameter_types! {
     /// One XCM operation is 1_000_000_000 weight - almost certainly a conservative estimate.
     pub UnitWeightCost: u64 = 1_000_000_000;
-    /// Used in native traders
-    /// This might be able to skipped.
-    /// We have to use `here()` because of reanchoring logic
-    pub ParaTokenPerSecond: (xcm::v2::AssetId, u128) = (Concrete(MultiLocation::here()), 1_000_000_000);
     pub const MaxInstructions: u32 = 100;
 }
 
@@ -148,6 +147,26 @@ pub type MultiAssetTransactor = MultiAssetAdapter<
     >,
 >;
 
+parameter_types! {
+    pub MantaPayPalletIndex: u8 = <MantaPay as PalletInfoAccess>::index() as u8;
+}
+
+/// Transactor for deposits into the MantaPay private ledger, sent to
+/// `(0, X2(PalletInstance(MantaPayPalletIndex), GeneralKey(commitment)))`
+pub type PrivateDepositTransactor = PrivateDepositAdapter<
+    Runtime,
+    CalamariAssetConfig,
+    MantaPay,
+    MantaPayPalletIndex,
+    IsNativeConcrete<SelfReserve>,
+    ConvertedConcreteAssetId<
+        CalamariAssetId,
+        Balance,
+        AssetIdLocationConvert<AssetManager>,
+        JustTry,
+    >,
+>;
+
 match_types! {
     pub type ParentLocation: impl Contains<MultiLocation> = {
         MultiLocation { parents: 1, interior: Here }
@@ -160,12 +179,22 @@ match_types! {
     };
 }
 
+match_types! {
+    pub type SiblingAccounts: impl Contains<MultiLocation> = {
+        MultiLocation { parents: 1, interior: X2(Parachain(_), AccountId32 { .. }) } |
+        MultiLocation { parents: 1, interior: X2(Parachain(_), AccountKey20 { .. }) }
+    };
+}
+
 pub type Barrier = (
     // Allows local origin messages which call weight_credit >= weight_limit.
     TakeWeightCredit,
     // Allows non-local origin messages, for example from from the xcmp queue,
     // which have the ability to deposit assets and pay for their own execution.
     AllowTopLevelPaidExecutionFrom<Everything>,
+    // Allows the same paid execution from an account of a non-local origin, which first descends
+    // into it, for example to `Transact` from its derivative account.
+    AllowTopLevelPaidExecutionDescendOriginFirst<Everything>,
     // Parent root gets free execution
     AllowUnpaidExecutionFrom<ParentLocation>,
     // Expected responses are OK.
@@ -177,31 +206,48 @@ pub type Barrier = (
     AllowSubscriptionsFrom<ParentOrSiblings>,
 );
 
-parameter_types! {
-    pub XcmFeesAccount: AccountId = Treasury::account_id();
-}
-
-/// Xcm fee of native token
-pub struct XcmNativeFeeToTreasury;
-
-impl TakeRevenue for XcmNativeFeeToTreasury {
-    #[inline]
-    fn take_revenue(revenue: MultiAsset) {
-        if let MultiAsset {
-            id: Concrete(location),
-            fun: Fungible(amount),
-        } = revenue
-        {
-            if location == MultiLocation::here() {
-                let _ = Balances::deposit_creating(&XcmFeesAccount::get(), amount);
-            }
+/// Calls which can be dispatched with an XCM `Transact` by an account on a sibling chain from
+/// its derivative account, see [`SiblingAccountHash`]. The origins converted from the other
+/// locations, e.g. the relay chain as a superuser, are not restricted to these calls.
+pub struct XcmTransactFilter;
+impl Contains<RuntimeCall> for XcmTransactFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        match call {
+            RuntimeCall::Balances(
+                pallet_balances::Call::transfer { .. }
+                | pallet_balances::Call::transfer_keep_alive { .. },
+            )
+            | RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::delegate { .. }
+                | pallet_parachain_staking::Call::delegator_bond_more { .. }
+                | pallet_parachain_staking::Call::schedule_delegator_bond_less { .. }
+                | pallet_parachain_staking::Call::schedule_revoke_delegation { .. }
+                | pallet_parachain_staking::Call::schedule_redelegate { .. }
+                | pallet_parachain_staking::Call::execute_delegation_request { .. }
+                | pallet_parachain_staking::Call::cancel_delegation_request { .. }
+                | pallet_parachain_staking::Call::schedule_leave_delegators { .. }
+                | pallet_parachain_staking::Call::execute_leave_delegators { .. }
+                | pallet_parachain_staking::Call::cancel_leave_delegators { .. }
+                | pallet_parachain_staking::Call::set_reward_destination { .. },
+            )
+            | RuntimeCall::MantaPay(pallet_manta_pay::Call::to_private_from_deposit { .. }) => true,
+            RuntimeCall::Utility(
+                pallet_utility::Call::batch { calls } | pallet_utility::Call::batch_all { calls },
+            ) => calls.iter().all(Self::contains),
+            _ => false,
         }
     }
 }
 
+parameter_types! {
+    pub XcmFeesAccount: AccountId = Treasury::account_id();
+}
+
+/// Xcm fees of both native and non-native tokens
 pub type XcmFeesToAccount = manta_primitives::xcm::XcmFeesToAccount<
-    AccountId,
-    Assets,
+    Runtime,
+    CalamariAssetConfig,
+    IsNativeConcrete<SelfReserve>,
     ConvertedConcreteAssetId<
         CalamariAssetId,
         Balance,
@@ -216,8 +262,10 @@ impl Config for XcmExecutorConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = XcmRouter;
     // Defines how to Withdraw and Deposit instruction work
-    type AssetTransactor = MultiAssetTransactor;
-    type OriginConverter = XcmOriginToCallOrigin;
+    type AssetTransactor = (PrivateDepositTransactor, MultiAssetTransactor);
+    // The derivative accounts of sibling chains can only dispatch the calls in `XcmTransactFilter`.
+    type OriginConverter =
+        FilteredOriginConverter<XcmOriginToCallOrigin, SiblingAccounts, XcmTransactFilter>;
     // Combinations of (Location, Asset) pairs which we trust as reserves.
     type IsReserve = MultiNativeAsset;
     type IsTeleporter = ();
@@ -229,15 +277,15 @@ impl Config for XcmExecutorConfig {
         MaxInstructions,
     >;
     // Trader is the means to purchasing weight credit for XCM execution.
-    // We define two traders:
-    // The first one will charge parachain's native currency, who's `MultiLocation`
-    // is defined in `SelfReserve`.
-    // The second one will charge the first asset in the MultiAssets with pre-defined rate
-    // i.e. units_per_second in `AssetManager`
-    type Trader = (
-        FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, XcmFeesToAccount>,
-    );
+    // It charges the first asset in the payment which has a price: the native currency, whose
+    // `MultiLocation` is defined in `SelfReserve`, is priced by the transaction payment
+    // `WeightToFee`, and the other assets by their units_per_second in `AssetManager`.
+    type Trader = MultiAssetTrader<
+        AssetManager,
+        <Runtime as pallet_transaction_payment::Config>::WeightToFee,
+        SelfReserve,
+        XcmFeesToAccount,
+    >;
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
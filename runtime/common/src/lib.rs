@@ -20,6 +20,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod migration;
+pub mod session_keys;
 
 #[cfg(feature = "test-helpers")]
 pub mod test_helpers;
@@ -0,0 +1,80 @@
+// Copyright 2020-2023 Manta Network.
+// This file is part of Manta.
+//
+// Manta is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Manta is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Manta.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Session keys registry helpers shared by the runtimes.
+
+use pallet_session::{NextKeys, Pallet as Session};
+use session_key_primitives::runtime::{KeyStatus, SessionKeyStatus};
+use sp_runtime::{
+    traits::{Convert, OpaqueKeys},
+    KeyTypeId,
+};
+use sp_std::vec::Vec;
+
+/// Returns the registration status of the session keys of `account` in runtime `T`.
+///
+/// `is_active` decides whether a registered key of the given type is used by the active authority
+/// set, e.g. by looking it up in the Aura authorities. For key types it returns `None` for, a key is
+/// active if it resolves to `account` among the current validators, which is how `AuthorInherent`
+/// finds the account of a `NimbusId`.
+pub fn session_key_status<T, F>(account: T::AccountId, is_active: F) -> SessionKeyStatus
+where
+    T: pallet_session::Config,
+    F: Fn(KeyTypeId, &[u8]) -> Option<bool>,
+{
+    let validator = match T::ValidatorIdOf::convert(account) {
+        Some(validator) => validator,
+        None => return Default::default(),
+    };
+    let registered_keys = NextKeys::<T>::get(&validator);
+    let is_validator = Session::<T>::validators().contains(&validator);
+    let queued_keys = Session::<T>::queued_keys()
+        .into_iter()
+        .find(|(queued, _)| *queued == validator)
+        .map(|(_, keys)| keys);
+    let keys = T::Keys::key_ids()
+        .iter()
+        .map(|&key_type| {
+            let registered = registered_keys
+                .as_ref()
+                .map(|keys| keys.get_raw(key_type).to_vec());
+            let (active, queued) = match &registered {
+                Some(key) => (
+                    is_validator
+                        && is_active(key_type, key).unwrap_or_else(|| {
+                            Session::<T>::key_owner(key_type, key).as_ref() == Some(&validator)
+                        }),
+                    queued_keys
+                        .as_ref()
+                        .map_or(false, |keys| keys.get_raw(key_type) == key.as_slice()),
+                ),
+                None => (false, false),
+            };
+            KeyStatus {
+                key_type,
+                registered,
+                active,
+                queued,
+            }
+        })
+        .collect::<Vec<_>>();
+    let queued_complete = queued_keys.is_some() && keys.iter().all(|key| key.queued);
+    SessionKeyStatus {
+        keys,
+        is_validator,
+        queued_complete,
+    }
+}
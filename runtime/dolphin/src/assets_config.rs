@@ -17,7 +17,7 @@
 use super::{
     weights, xcm_config::SelfReserve, AssetManager, Assets, Balances,
     NativeTokenExistentialDeposit, Runtime, RuntimeEvent, RuntimeOrigin, TechnicalCollective,
-    Timestamp, DOL,
+    Timestamp, Treasury, DOL,
 };
 
 use manta_primitives::{
@@ -26,17 +26,17 @@ use manta_primitives::{
         AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
     constants::{
-        ASSET_MANAGER_PALLET_ID, DOLPHIN_DECIMAL, MANTA_PAY_PALLET_ID, MANTA_SBT_PALLET_ID,
-        WEIGHT_PER_MILLIS,
+        time::DAYS, ASSET_MANAGER_PALLET_ID, DOLPHIN_DECIMAL, MANTA_PAY_PALLET_ID,
+        MANTA_SBT_PALLET_ID, WEIGHT_PER_MILLIS, WEIGHT_PER_SECOND,
     },
-    types::{AccountId, Balance, DolphinAssetId},
+    types::{AccountId, Balance, BlockNumber, DolphinAssetId},
 };
 
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse},
-    weights::Weight,
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU16, ConstU32, EitherOfDiverse, Get},
+    weights::{Weight, WeightToFee},
     PalletId,
 };
 use frame_system::EnsureRoot;
@@ -155,6 +155,7 @@ parameter_types! {
         is_sufficient: true,
     };
     pub const AssetManagerPalletId: PalletId = ASSET_MANAGER_PALLET_ID;
+    pub const AssetRegistrationDeposit: Balance = 1_000 * DOL;
 }
 
 pub type DolphinConcreteFungibleLedger =
@@ -181,6 +182,21 @@ impl AssetConfig<Runtime> for DolphinAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
     type FungibleLedger = DolphinConcreteFungibleLedger;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
+}
+
+/// Native token units charged per second of XCM execution, from which the units-per-second of
+/// assets with a relative price are derived.
+pub struct NativeUnitsPerSecond;
+impl Get<u128> for NativeUnitsPerSecond {
+    #[inline]
+    fn get() -> u128 {
+        <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+            &Weight::from_ref_time(WEIGHT_PER_SECOND),
+        )
+    }
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -191,11 +207,22 @@ impl pallet_asset_manager::Config for Runtime {
     type AssetConfig = DolphinAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = AssetRegistrationDeposit;
+    type Slash = Treasury;
+    type PriceFeederOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureMembers<AccountId, TechnicalCollective, 2>,
+    >;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type PriceStalenessThreshold = ConstU32<DAYS>;
     type WeightInfo = weights::pallet_asset_manager::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
     pub const MantaPayPalletId: PalletId = MANTA_PAY_PALLET_ID;
+    pub const PrivateDepositExpiry: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_manta_pay::Config for Runtime {
@@ -203,6 +230,8 @@ impl pallet_manta_pay::Config for Runtime {
     type WeightInfo = weights::pallet_manta_pay::SubstrateWeight<Runtime>;
     type AssetConfig = DolphinAssetConfig;
     type PalletId = MantaPayPalletId;
+    type DepositReclaimOrigin = EnsureRoot<AccountId>;
+    type DepositExpiry = PrivateDepositExpiry;
 }
 
 parameter_types! {
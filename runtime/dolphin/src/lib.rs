@@ -31,7 +31,7 @@ use sp_runtime::{
     create_runtime_str, generic, impl_opaque_keys,
     traits::{AccountIdLookup, BlakeTwo256, Block as BlockT},
     transaction_validity::{TransactionSource, TransactionValidity},
-    ApplyExtrinsicResult, Perbill, Permill,
+    ApplyExtrinsicResult, Perbill, Percent, Permill,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 
@@ -219,9 +219,14 @@ impl Contains<RuntimeCall> for BaseFilter {
         #[allow(clippy::match_like_matches_macro)]
         // keep CallFilter with explicit true/false for documentation
         match call {
+            // Explicitly ALLOWED AssetManager calls, matched before the rest of AssetManager is filtered
+            | RuntimeCall::AssetManager(pallet_asset_manager::Call::propose_asset_registration {..}
+                // Relative prices are also fed by the Technical Committee, see `PriceFeederOrigin`
+                | pallet_asset_manager::Call::feed_relative_price {..}) => true,
+
             // Explicitly DISALLOWED calls
             | RuntimeCall::Assets(_) // Filter Assets. Assets should only be accessed by AssetManager.
-            | RuntimeCall::AssetManager(_) // AssetManager is also filtered because all of its extrinsics
+            | RuntimeCall::AssetManager(_) // AssetManager is also filtered because its other extrinsics
                                     // are callable only by Root, and Root calls skip this whole filter.
             // Currently, we filter `register_as_candidate` as this call is not yet ready for community.
             | RuntimeCall::CollatorSelection( manta_collator_selection::Call::register_as_candidate{..})
@@ -701,6 +706,7 @@ impl manta_collator_selection::Config for Runtime {
     /// Nimbus filter pipeline step 2:
     /// Filters collators not part of the current pallet_session::validators()
     type CanAuthor = AuraAuthorFilter;
+    type PerformanceHistoryLength = ConstU32<4>; // 4 sessions of performance for reputation
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -763,8 +769,8 @@ construct_runtime!(
         // Asset and Private Payment
         Assets: pallet_assets::{Pallet, Call, Storage, Event<T>} = 45,
         AssetManager: pallet_asset_manager::{Pallet, Call, Storage, Config<T>, Event<T>} = 46,
-        MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Event<T>} = 47,
-        MantaSbt: pallet_manta_sbt::{Pallet, Call, Storage, Event<T>} = 48,
+        MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Config, Event<T>} = 47,
+        MantaSbt: pallet_manta_sbt::{Pallet, Call, Storage, Config, Event<T>} = 48,
     }
 );
 
@@ -794,7 +800,7 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, Si
 
 /// Types for runtime upgrading.
 /// Each type should implement trait `OnRuntimeUpgrade`.
-pub type OnRuntimeUpgradeHooks = ();
+pub type OnRuntimeUpgradeHooks = (manta_collator_selection::migrations::MigrateV1ToV2<Runtime>,);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -991,6 +997,57 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_asset_manager::runtime::AssetManagerApi<
+        Block,
+        manta_primitives::types::DolphinAssetId,
+        manta_primitives::assets::AssetLocation,
+        manta_primitives::assets::AssetRegistryMetadata<Balance>,
+    > for Runtime {
+        fn asset(
+            asset_id: manta_primitives::types::DolphinAssetId,
+        ) -> Option<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_info(asset_id)
+        }
+        fn assets() -> Vec<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_infos()
+        }
+        fn quote_weight_fee(asset_id: manta_primitives::types::DolphinAssetId, weight: u64) -> Option<u128> {
+            AssetManager::quote_weight_fee(asset_id, weight)
+        }
+        fn quote_transfer_fee(
+            asset_id: manta_primitives::types::DolphinAssetId,
+            dest: manta_primitives::assets::AssetLocation,
+            dest_weight: u64,
+        ) -> Option<u128> {
+            AssetManager::quote_transfer_fee(asset_id, dest, dest_weight)
+        }
+    }
+
+    impl manta_collator_selection::runtime::CollatorSelectionApi<Block, AccountId> for Runtime {
+        fn collator_performance(
+            collator: AccountId,
+        ) -> Vec<manta_collator_selection::SessionPerformance> {
+            CollatorSelection::performance_history(collator)
+        }
+        fn reputation(collator: AccountId) -> Percent {
+            CollatorSelection::reputation(&collator)
+        }
+        fn reputations() -> Vec<(AccountId, Percent)> {
+            CollatorSelection::reputations()
+        }
+    }
+
+    impl session_key_primitives::runtime::SessionKeysRegistryApi<Block, AccountId> for Runtime {
+        fn session_key_status(
+            account: AccountId,
+        ) -> session_key_primitives::runtime::SessionKeyStatus {
+            runtime_common::session_keys::session_key_status::<Runtime, _>(account, |key_type, key| {
+                (key_type == sp_core::crypto::key_types::AURA)
+                    .then(|| Aura::authorities().iter().any(|id| id.as_ref() == key))
+            })
+        }
+    }
+
     impl nimbus_primitives::NimbusApi<Block> for Runtime {
         fn can_author(author: NimbusId, relay_parent: u32, parent_header: &<Block as BlockT>::Header) -> bool {
             System::initialize(&(parent_header.number + 1), &parent_header.hash(), &parent_header.digest);
@@ -34,6 +34,11 @@
 // --heap-pages=4096
 // --output=./scripts/benchmarking/frame-weights-output/pallet_asset_manager.rs
 // --template=.github/resources/frame-weight-template.hbs
+//
+// NOTE: The weights of `freeze_asset` to `feed_relative_price` were not produced by the command
+//       above. They are estimates derived from the measured weights of comparable extrinsics and
+//       account for every storage read and write listed above them, including the worst case of
+//       `MaxAliasLocations = 4` aliases for `deregister_asset`. Rerun the benchmark to replace them.
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -52,6 +57,20 @@ pub trait WeightInfo {
     fn update_asset_metadata() -> Weight;
     fn mint_asset() -> Weight;
     fn set_min_xcm_fee() -> Weight;
+    fn freeze_asset() -> Weight;
+    fn thaw_asset() -> Weight;
+    fn force_burn_asset() -> Weight;
+    fn deregister_asset(a: u32, ) -> Weight;
+    fn add_alias_location() -> Weight;
+    fn remove_alias_location() -> Weight;
+    fn propose_asset_registration() -> Weight;
+    fn approve_asset_registration() -> Weight;
+    fn reject_asset_registration() -> Weight;
+    fn set_outbound_rate_limit() -> Weight;
+    fn remove_outbound_rate_limit() -> Weight;
+    fn set_relative_price_bounds() -> Weight;
+    fn remove_relative_price_bounds() -> Weight;
+    fn feed_relative_price() -> Weight;
 }
 
 /// Weights for pallet_asset_manager using the Substrate node and recommended hardware.
@@ -111,6 +130,146 @@ impl<T: frame_system::Config> pallet_asset_manager::WeightInfo for SubstrateWeig
 		Weight::from_ref_time(48_152_000)
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:1)
+	fn freeze_asset() -> Weight {
+		// Estimated execution time: 38_412 nanoseconds.
+		Weight::from_ref_time(38_412_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:1)
+	fn thaw_asset() -> Weight {
+		// Estimated execution time: 38_164 nanoseconds.
+		Weight::from_ref_time(38_164_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn force_burn_asset() -> Weight {
+		// Estimated execution time: 79_503 nanoseconds.
+		Weight::from_ref_time(79_503_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:1)
+	// Storage: AssetManager FrozenAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:0 w:1)
+	// Storage: AssetManager UnitsPerSecond (r:0 w:1)
+	// Storage: AssetManager PriceBounds (r:0 w:1)
+	// Storage: AssetManager RelativePrices (r:0 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	// Storage: AssetManager AliasLocationAssetId (r:0 w:4)
+	/// The range of component `a` is `[0, 4]`.
+	fn deregister_asset(a: u32, ) -> Weight {
+		// Estimated execution time: 66_000 nanoseconds.
+		Weight::from_ref_time(66_000_000)
+			.saturating_add(Weight::from_ref_time(2_500_000).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager LocationAssetId (r:1 w:0)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	fn add_alias_location() -> Weight {
+		// Estimated execution time: 45_218 nanoseconds.
+		Weight::from_ref_time(45_218_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	fn remove_alias_location() -> Weight {
+		// Estimated execution time: 41_736 nanoseconds.
+		Weight::from_ref_time(41_736_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:1 w:0)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+	// Storage: AssetManager NextAssetId (r:1 w:1)
+	// Storage: AssetManager PendingAssetRegistrations (r:0 w:1)
+	fn propose_asset_registration() -> Weight {
+		// Estimated execution time: 41_000 nanoseconds.
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetManager AssetIdMetadata (r:0 w:1)
+	// Storage: AssetManager AssetIdLocation (r:0 w:1)
+	fn approve_asset_registration() -> Weight {
+		// Estimated execution time: 88_000 nanoseconds.
+		Weight::from_ref_time(88_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_asset_registration() -> Weight {
+		// Estimated execution time: 41_000 nanoseconds.
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager OutboundRateLimits (r:1 w:1)
+	// Storage: AssetManager OutboundFlows (r:1 w:1)
+	fn set_outbound_rate_limit() -> Weight {
+		// Estimated execution time: 31_226 nanoseconds.
+		Weight::from_ref_time(31_226_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager OutboundRateLimits (r:1 w:1)
+	// Storage: AssetManager OutboundFlows (r:0 w:1)
+	fn remove_outbound_rate_limit() -> Weight {
+		// Estimated execution time: 24_318 nanoseconds.
+		Weight::from_ref_time(24_318_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:0)
+	// Storage: AssetManager RelativePrices (r:1 w:1)
+	// Storage: AssetManager PriceBounds (r:0 w:1)
+	fn set_relative_price_bounds() -> Weight {
+		// Estimated execution time: 27_841 nanoseconds.
+		Weight::from_ref_time(27_841_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager PriceBounds (r:1 w:1)
+	// Storage: AssetManager RelativePrices (r:0 w:1)
+	fn remove_relative_price_bounds() -> Weight {
+		// Estimated execution time: 23_517 nanoseconds.
+		Weight::from_ref_time(23_517_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: AssetManager PriceBounds (r:1 w:0)
+	// Storage: AssetManager RelativePrices (r:1 w:1)
+	fn feed_relative_price() -> Weight {
+		// Estimated execution time: 26_093 nanoseconds.
+		Weight::from_ref_time(26_093_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -169,4 +328,144 @@ impl WeightInfo for () {
 		Weight::from_ref_time(48_152_000)
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:1)
+	fn freeze_asset() -> Weight {
+		// Estimated execution time: 38_412 nanoseconds.
+		Weight::from_ref_time(38_412_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:1)
+	fn thaw_asset() -> Weight {
+		// Estimated execution time: 38_164 nanoseconds.
+		Weight::from_ref_time(38_164_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager FrozenAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	fn force_burn_asset() -> Weight {
+		// Estimated execution time: 79_503 nanoseconds.
+		Weight::from_ref_time(79_503_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:1)
+	// Storage: AssetManager FrozenAssets (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:0)
+	// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:0 w:1)
+	// Storage: AssetManager UnitsPerSecond (r:0 w:1)
+	// Storage: AssetManager PriceBounds (r:0 w:1)
+	// Storage: AssetManager RelativePrices (r:0 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	// Storage: AssetManager AliasLocationAssetId (r:0 w:4)
+	/// The range of component `a` is `[0, 4]`.
+	fn deregister_asset(a: u32, ) -> Weight {
+		// Estimated execution time: 66_000 nanoseconds.
+		Weight::from_ref_time(66_000_000)
+			.saturating_add(Weight::from_ref_time(2_500_000).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager LocationAssetId (r:1 w:0)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	fn add_alias_location() -> Weight {
+		// Estimated execution time: 45_218 nanoseconds.
+		Weight::from_ref_time(45_218_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocations (r:1 w:1)
+	fn remove_alias_location() -> Weight {
+		// Estimated execution time: 41_736 nanoseconds.
+		Weight::from_ref_time(41_736_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:1 w:0)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+	// Storage: AssetManager NextAssetId (r:1 w:1)
+	// Storage: AssetManager PendingAssetRegistrations (r:0 w:1)
+	fn propose_asset_registration() -> Weight {
+		// Estimated execution time: 41_000 nanoseconds.
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:1)
+	// Storage: AssetManager LocationAssetId (r:1 w:1)
+	// Storage: AssetManager AliasLocationAssetId (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:1)
+	// Storage: AssetManager AllowedDestParaIds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: AssetManager AssetIdMetadata (r:0 w:1)
+	// Storage: AssetManager AssetIdLocation (r:0 w:1)
+	fn approve_asset_registration() -> Weight {
+		// Estimated execution time: 88_000 nanoseconds.
+		Weight::from_ref_time(88_000_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn reject_asset_registration() -> Weight {
+		// Estimated execution time: 41_000 nanoseconds.
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager OutboundRateLimits (r:1 w:1)
+	// Storage: AssetManager OutboundFlows (r:1 w:1)
+	fn set_outbound_rate_limit() -> Weight {
+		// Estimated execution time: 31_226 nanoseconds.
+		Weight::from_ref_time(31_226_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager OutboundRateLimits (r:1 w:1)
+	// Storage: AssetManager OutboundFlows (r:0 w:1)
+	fn remove_outbound_rate_limit() -> Weight {
+		// Estimated execution time: 24_318 nanoseconds.
+		Weight::from_ref_time(24_318_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager AssetIdLocation (r:1 w:0)
+	// Storage: AssetManager PendingAssetRegistrations (r:1 w:0)
+	// Storage: AssetManager RelativePrices (r:1 w:1)
+	// Storage: AssetManager PriceBounds (r:0 w:1)
+	fn set_relative_price_bounds() -> Weight {
+		// Estimated execution time: 27_841 nanoseconds.
+		Weight::from_ref_time(27_841_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager PriceBounds (r:1 w:1)
+	// Storage: AssetManager RelativePrices (r:0 w:1)
+	fn remove_relative_price_bounds() -> Weight {
+		// Estimated execution time: 23_517 nanoseconds.
+		Weight::from_ref_time(23_517_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: AssetManager PriceBounds (r:1 w:0)
+	// Storage: AssetManager RelativePrices (r:1 w:1)
+	fn feed_relative_price() -> Weight {
+		// Estimated execution time: 26_093 nanoseconds.
+		Weight::from_ref_time(26_093_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
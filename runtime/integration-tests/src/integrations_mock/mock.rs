@@ -156,6 +156,7 @@ impl ExtBuilder {
 
         pallet_asset_manager::GenesisConfig::<Runtime> {
             start_id: <RuntimeAssetConfig as AssetConfig<Runtime>>::StartNonNativeAssetId::get(),
+            assets: vec![],
         }
         .assimilate_storage(&mut t)
         .unwrap();
@@ -21,7 +21,7 @@ use frame_support::{
     assert_noop, assert_ok,
     codec::Encode,
     dispatch::Dispatchable,
-    traits::{PalletInfo, StorageInfo, StorageInfoTrait, StorePreimage},
+    traits::{Contains, PalletInfo, StorageInfo, StorageInfoTrait, StorePreimage},
     StorageHasher, Twox128,
 };
 use manta_primitives::{constants::time::DAYS, types::AccountId};
@@ -822,3 +822,112 @@ fn reward_fees_to_block_author_and_treasury() {
             );
         });
 }
+
+#[test]
+fn set_reward_destination_should_pass_the_base_filter() {
+    ExtBuilder::default()
+        .with_collators(vec![(ALICE.clone(), MIN_BOND_TO_BE_CONSIDERED_COLLATOR)])
+        .build()
+        .execute_with(|| {
+            let call = RuntimeCall::ParachainStaking(
+                pallet_parachain_staking::Call::set_reward_destination {
+                    destination: pallet_parachain_staking::RewardDestination::Account(BOB.clone()),
+                },
+            );
+            assert!(calamari_runtime::BaseFilter::contains(&call));
+            assert_ok!(call.dispatch(RuntimeOrigin::signed(ALICE.clone())));
+            assert_eq!(
+                ParachainStaking::reward_destination(&*ALICE),
+                pallet_parachain_staking::RewardDestination::Account(BOB.clone())
+            );
+        });
+}
+
+#[test]
+fn feed_relative_price_from_technical_committee_should_pass_the_base_filter() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call = RuntimeCall::AssetManager(pallet_asset_manager::Call::feed_relative_price {
+            asset_id: 8,
+            price: sp_runtime::FixedU128::from(1),
+        });
+        let technical_committee = RuntimeOrigin::from(pallet_collective::RawOrigin::<
+            AccountId,
+            pallet_collective::Instance2,
+        >::Members(2, 3));
+        assert!(calamari_runtime::BaseFilter::contains(&call));
+        // The call reaches the pallet, which only rejects it for the missing price bounds.
+        assert_eq!(
+            call.dispatch(technical_committee).map_err(|e| e.error),
+            Err(pallet_asset_manager::Error::<Runtime>::NoRelativePriceBounds.into())
+        );
+    });
+}
+
+#[test]
+fn xcm_transact_filter_calls_should_pass_the_base_filter() {
+    use pallet_parachain_staking::{Call as StakingCall, RewardDestination};
+
+    ExtBuilder::default().build().execute_with(|| {
+        // `to_private_from_deposit` is not listed, `MantaPay` is allowed as a whole.
+        let calls: Vec<RuntimeCall> = vec![
+            RuntimeCall::Balances(pallet_balances::Call::transfer {
+                dest: BOB.clone().into(),
+                value: KMA,
+            }),
+            RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+                dest: BOB.clone().into(),
+                value: KMA,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::delegate {
+                candidate: BOB.clone(),
+                amount: KMA,
+                candidate_delegation_count: 0,
+                delegation_count: 0,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::delegator_bond_more {
+                candidate: BOB.clone(),
+                more: KMA,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::schedule_delegator_bond_less {
+                candidate: BOB.clone(),
+                less: KMA,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::schedule_revoke_delegation {
+                collator: BOB.clone(),
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::schedule_redelegate {
+                from: BOB.clone(),
+                to: ALICE.clone(),
+                amount: KMA,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::execute_delegation_request {
+                delegator: ALICE.clone(),
+                candidate: BOB.clone(),
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::cancel_delegation_request {
+                candidate: BOB.clone(),
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::schedule_leave_delegators {}),
+            RuntimeCall::ParachainStaking(StakingCall::execute_leave_delegators {
+                delegator: ALICE.clone(),
+                delegation_count: 1,
+            }),
+            RuntimeCall::ParachainStaking(StakingCall::cancel_leave_delegators {}),
+            RuntimeCall::ParachainStaking(StakingCall::set_reward_destination {
+                destination: RewardDestination::Account(BOB.clone()),
+            }),
+        ];
+        let batch = RuntimeCall::Utility(pallet_utility::Call::batch_all {
+            calls: calls.clone(),
+        });
+        for call in calls.iter().chain([&batch]) {
+            assert!(calamari_runtime::xcm_config::XcmTransactFilter::contains(
+                call
+            ));
+            assert!(
+                calamari_runtime::BaseFilter::contains(call),
+                "{call:?} is filtered by the BaseFilter"
+            );
+        }
+    });
+}
@@ -983,3 +983,40 @@ fn test_sender_side_xcm_weights() {
     let weight = <XcmExecutorConfig as xcm_executor::Config>::Weigher::weight(&mut msg).unwrap();
     assert!(weight < ADVERTISED_DEST_WEIGHT);
 }
+
+#[test]
+fn session_key_status_reports_registered_and_queued_keys() {
+    use runtime_common::session_keys::session_key_status;
+
+    ExtBuilder::default()
+        .with_balances(vec![
+            (ALICE.clone(), INITIAL_BALANCE),
+            (BOB.clone(), INITIAL_BALANCE),
+        ])
+        .build()
+        .execute_with(|| {
+            // ALICE is a genesis authority with all keys registered and queued
+            let status = session_key_status::<Runtime, _>(ALICE.clone(), |_, _| None);
+            assert!(status.is_validator);
+            assert!(status.is_registered());
+            assert!(status.queued_complete);
+            assert!(status.keys.iter().all(|key| key.active && key.queued));
+
+            // BOB has no keys yet
+            let status = session_key_status::<Runtime, _>(BOB.clone(), |_, _| None);
+            assert!(!status.is_validator);
+            assert!(!status.is_registered());
+            assert!(!status.queued_complete);
+
+            // registered keys are not queued before BOB is selected
+            assert_ok!(Session::set_keys(
+                RuntimeOrigin::signed(BOB.clone()),
+                BOB_SESSION_KEYS.clone(),
+                vec![]
+            ));
+            let status = session_key_status::<Runtime, _>(BOB.clone(), |_, _| None);
+            assert!(status.is_registered());
+            assert!(!status.queued_complete);
+            assert!(status.keys.iter().all(|key| !key.active && !key.queued));
+        });
+}
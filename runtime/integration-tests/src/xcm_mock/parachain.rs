@@ -24,8 +24,10 @@ use frame_support::{
     assert_ok, construct_runtime, match_types,
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU32, Currency, Everything, Nothing},
-    weights::Weight,
+    traits::{
+        AsEnsureOriginWithArg, ConstU128, ConstU32, Contains, Everything, Nothing, PalletInfoAccess,
+    },
+    weights::{Weight, WeightToFee},
     PalletId,
 };
 use frame_system::{EnsureNever, EnsureRoot};
@@ -42,9 +44,15 @@ use manta_primitives::{
         AssetConfig, AssetIdLocationConvert, AssetIdType, AssetLocation, AssetRegistry,
         AssetRegistryMetadata, AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
-    constants::{ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, WEIGHT_PER_SECOND},
+    constants::{
+        ASSET_MANAGER_PALLET_ID, CALAMARI_DECIMAL, MANTA_PAY_PALLET_ID, WEIGHT_PER_SECOND,
+    },
     types::{BlockNumber, CalamariAssetId, Header},
-    xcm::{FirstAssetTrader, IsNativeConcrete, MultiAssetAdapter, MultiNativeAsset},
+    xcm::{
+        units_per_second_to_fee, AllowTopLevelPaidExecutionDescendOriginFirst,
+        FilteredOriginConverter, IsNativeConcrete, MultiAssetAdapter, MultiAssetTrader,
+        MultiNativeAsset, PrivateDepositAdapter, SiblingAccountHash,
+    },
 };
 use pallet_xcm::XcmPassthrough;
 use polkadot_core_primitives::BlockNumber as RelayBlockNumber;
@@ -55,9 +63,9 @@ use xcm::{latest::prelude::*, Version as XcmVersion, VersionedMultiLocation, Ver
 use xcm_builder::{
     AccountId32Aliases, AllowKnownQueryResponses, AllowSubscriptionsFrom,
     AllowTopLevelPaidExecutionFrom, AllowUnpaidExecutionFrom, ConvertedConcreteAssetId,
-    EnsureXcmOrigin, FixedRateOfFungible, LocationInverter, ParentIsPreset,
-    SiblingParachainAsNative, SiblingParachainConvertsVia, SignedAccountId32AsNative,
-    SovereignSignedViaLocation, TakeRevenue, TakeWeightCredit, WeightInfoBounds,
+    EnsureXcmOrigin, LocationInverter, ParentAsSuperuser, ParentIsPreset, SiblingParachainAsNative,
+    SiblingParachainConvertsVia, SignedAccountId32AsNative, SovereignSignedViaLocation,
+    TakeWeightCredit, WeightInfoBounds,
 };
 use xcm_executor::{traits::JustTry, Config, XcmExecutor};
 use xcm_simulator::{DmpMessageHandlerT, Get, TestExt, XcmpMessageHandlerT};
@@ -176,6 +184,9 @@ pub type LocationToAccountId = (
     // Sibling parachain origins convert to AccountId via the `ParaId::into`.
     SiblingParachainConvertsVia<Sibling, AccountId>,
     AccountId32Aliases<RelayNetwork, AccountId>,
+    // Accounts on sibling parachains convert to a derivative `AccountId` hashed from the para id
+    // and the account key.
+    SiblingAccountHash<AccountId>,
 );
 
 /// This is the type to convert an (incoming) XCM origin into a local `Origin` instance,
@@ -193,6 +204,9 @@ pub type XcmOriginToCallOrigin = (
     // Native converter for sibling Parachains; will convert to a `SiblingPara` origin when
     // recognised.
     SiblingParachainAsNative<cumulus_pallet_xcm::Origin, RuntimeOrigin>,
+    // Superuser converter for the Relay-chain (Parent) location. This will allow it to issue a
+    // transaction from the Root origin.
+    ParentAsSuperuser<RuntimeOrigin>,
     // Xcm origins can be represented natively under the Xcm pallet's Xcm origin.
     XcmPassthrough<RuntimeOrigin>,
 );
@@ -203,6 +217,7 @@ parameter_types! {
     // This might be able to skipped.
     // We have to use `here()` because of reanchoring logic
     pub ParaTokenPerSecond: (xcm::v2::AssetId, u128) = (Concrete(MultiLocation::here()), 1_000_000_000);
+    pub ParaNativeUnitsPerSecond: u128 = ParaTokenPerSecond::get().1;
     pub const MaxInstructions: u32 = 100;
 }
 
@@ -225,6 +240,26 @@ pub type MultiAssetTransactor = MultiAssetAdapter<
     >,
 >;
 
+parameter_types! {
+    pub MantaPayPalletIndex: u8 = <MantaPay as PalletInfoAccess>::index() as u8;
+}
+
+/// Transactor for deposits into the MantaPay private ledger, sent to
+/// `(0, X2(PalletInstance(MantaPayPalletIndex), GeneralKey(commitment)))`
+pub type PrivateDepositTransactor = PrivateDepositAdapter<
+    Runtime,
+    ParachainAssetConfig,
+    MantaPay,
+    MantaPayPalletIndex,
+    IsNativeConcrete<SelfReserve>,
+    ConvertedConcreteAssetId<
+        CalamariAssetId,
+        Balance,
+        AssetIdLocationConvert<AssetManager>,
+        JustTry,
+    >,
+>;
+
 pub type XcmRouter = super::ParachainXcmRouter<MsgQueue>;
 
 match_types! {
@@ -238,12 +273,22 @@ match_types! {
         MultiLocation { parents: 1, interior: X1(_) }
     };
 }
+match_types! {
+    pub type SiblingAccounts: impl Contains<MultiLocation> = {
+        MultiLocation { parents: 1, interior: X2(Parachain(_), AccountId32 { .. }) } |
+        MultiLocation { parents: 1, interior: X2(Parachain(_), AccountKey20 { .. }) }
+    };
+}
+
 pub type Barrier = (
     // Allows local origin messages which call weight_credit >= weight_limit.
     TakeWeightCredit,
     // Allows non-local origin messages, for example from from the xcmp queue,
     // which have the ability to deposit assets and pay for their own execution.
     AllowTopLevelPaidExecutionFrom<Everything>,
+    // Allows the same paid execution from an account of a non-local origin, which first descends
+    // into it, for example to `Transact` from its derivative account.
+    AllowTopLevelPaidExecutionDescendOriginFirst<Everything>,
     // Parent root gets free execution
     AllowUnpaidExecutionFrom<ParentLocation>,
     // Expected responses are OK.
@@ -255,33 +300,45 @@ pub type Barrier = (
     AllowSubscriptionsFrom<ParentOrSiblings>,
 );
 
+/// Calls which can be dispatched with an XCM `Transact` by an account on a sibling chain from
+/// its derivative account
+pub struct XcmTransactFilter;
+impl Contains<RuntimeCall> for XcmTransactFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(
+            call,
+            RuntimeCall::System(frame_system::Call::remark_with_event { .. })
+                | RuntimeCall::Balances(
+                    pallet_balances::Call::transfer { .. }
+                        | pallet_balances::Call::transfer_keep_alive { .. }
+                )
+                | RuntimeCall::MantaPay(pallet_manta_pay::Call::to_private_from_deposit { .. })
+        )
+    }
+}
+
 parameter_types! {
     /// Xcm fees will go to the asset manager (we don't implement treasury yet for mock parachain)
     pub XcmFeesAccount: AccountId = AssetManager::account_id();
 }
 
-/// Xcm fee of native token
-pub struct XcmNativeFeeToTreasury;
+/// Native token fees at the rate of `ParaTokenPerSecond`
+pub struct ParaTokenWeightToFee;
+
+impl WeightToFee for ParaTokenWeightToFee {
+    type Balance = Balance;
 
-impl TakeRevenue for XcmNativeFeeToTreasury {
     #[inline]
-    fn take_revenue(revenue: MultiAsset) {
-        if let MultiAsset {
-            id: Concrete(location),
-            fun: Fungible(amount),
-        } = revenue
-        {
-            if location == MultiLocation::here() {
-                let _ = Balances::deposit_creating(&XcmFeesAccount::get(), amount);
-            }
-        }
+    fn weight_to_fee(weight: &Weight) -> Self::Balance {
+        units_per_second_to_fee(ParaTokenPerSecond::get().1, weight.ref_time())
     }
 }
 
-/// Xcm fee of non native token
+/// Xcm fees of both native and non-native tokens
 pub type XcmFeesToAccount = manta_primitives::xcm::XcmFeesToAccount<
-    AccountId,
-    Assets,
+    Runtime,
+    ParachainAssetConfig,
+    IsNativeConcrete<SelfReserve>,
     ConvertedConcreteAssetId<
         CalamariAssetId,
         Balance,
@@ -296,8 +353,10 @@ impl Config for XcmExecutorConfig {
     type RuntimeCall = RuntimeCall;
     type XcmSender = XcmRouter;
     // Defines how to Withdraw and Deposit instruction work
-    type AssetTransactor = MultiAssetTransactor;
-    type OriginConverter = XcmOriginToCallOrigin;
+    type AssetTransactor = (PrivateDepositTransactor, MultiAssetTransactor);
+    // The derivative accounts of sibling chains can only dispatch the calls in `XcmTransactFilter`.
+    type OriginConverter =
+        FilteredOriginConverter<XcmOriginToCallOrigin, SiblingAccounts, XcmTransactFilter>;
     // Combinations of (Location, Asset) pairs which we trust as reserves.
     type IsReserve = MultiNativeAsset;
     type IsTeleporter = ();
@@ -305,15 +364,11 @@ impl Config for XcmExecutorConfig {
     type Barrier = Barrier;
     type Weigher = WeightInfoBounds<RuntimeXcmWeight, RuntimeCall, MaxInstructions>;
     // Trader is the means to purchasing weight credit for XCM execution.
-    // We define two traders:
-    // The first one will charge parachain's native currency, who's `MultiLocation`
-    // is defined in `SelfReserve`.
-    // The second one will charge the first asset in the MultiAssets with pre-defined rate
-    // i.e. units_per_second in `AssetManager`
-    type Trader = (
-        FixedRateOfFungible<ParaTokenPerSecond, XcmNativeFeeToTreasury>,
-        FirstAssetTrader<AssetManager, XcmFeesToAccount>,
-    );
+    // It charges the first asset in the payment which has a price: the native currency, whose
+    // `MultiLocation` is defined in `SelfReserve`, is priced at `ParaTokenPerSecond`, and the
+    // other assets by their units_per_second in `AssetManager`.
+    type Trader =
+        MultiAssetTrader<AssetManager, ParaTokenWeightToFee, SelfReserve, XcmFeesToAccount>;
     type ResponseHandler = PolkadotXcm;
     type AssetTrap = PolkadotXcm;
     type AssetClaims = PolkadotXcm;
@@ -618,6 +673,9 @@ impl AssetConfig<Runtime> for ParachainAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = CalamariAssetRegistry;
     type FungibleLedger = NativeAndNonNative<Runtime, ParachainAssetConfig, Balances, Assets>;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -628,9 +686,29 @@ impl pallet_asset_manager::Config for Runtime {
     type AssetConfig = ParachainAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = ConstU128<1_000>;
+    type Slash = ();
+    type PriceFeederOrigin = EnsureRoot<AccountId>;
+    type NativeUnitsPerSecond = ParaNativeUnitsPerSecond;
+    type PriceStalenessThreshold = ConstU32<10>;
     type WeightInfo = ();
 }
 
+parameter_types! {
+    pub const MantaPayPalletId: PalletId = MANTA_PAY_PALLET_ID;
+}
+
+impl pallet_manta_pay::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_manta_pay::weights::SubstrateWeight<Runtime>;
+    type AssetConfig = ParachainAssetConfig;
+    type PalletId = MantaPayPalletId;
+    type DepositReclaimOrigin = EnsureRoot<AccountId>;
+    type DepositExpiry = ConstU32<10>;
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type XcmExecutor = XcmExecutor<XcmExecutorConfig>;
@@ -706,6 +784,7 @@ construct_runtime!(
         ParachainInfo: parachain_info::{Pallet, Storage, Config} = 8,
         XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 9,
         ParachainSystem: cumulus_pallet_parachain_system::{Pallet, Call, Config, Storage, Inherent, Event<T>, ValidateUnsigned} = 10,
+        MantaPay: pallet_manta_pay::{Pallet, Call, Storage, Event<T>} = 11,
     }
 );
 
@@ -21,7 +21,8 @@
 use crate::xcm_mock::parachain::XcmFeesAccount;
 use codec::Encode;
 use frame_support::{
-    assert_err, assert_noop, assert_ok, traits::tokens::fungibles::Mutate, WeakBoundedVec,
+    assert_err, assert_noop, assert_ok, dispatch::Dispatchable, traits::tokens::fungibles::Mutate,
+    WeakBoundedVec,
 };
 use manta_primitives::{assets::AssetLocation, constants::WEIGHT_PER_SECOND};
 use runtime_common::test_helpers::{
@@ -30,7 +31,10 @@ use runtime_common::test_helpers::{
     ADVERTISED_DEST_WEIGHT,
 };
 use xcm::{latest::prelude::*, v2::Response, VersionedMultiLocation, WrapVersion};
-use xcm_executor::traits::{Convert, WeightBounds};
+use xcm_executor::{
+    traits::{Convert, WeightBounds, WeightTrader},
+    Assets,
+};
 use xcm_simulator::TestExt;
 
 use super::{
@@ -132,6 +136,38 @@ fn dmp_transact_from_parent_should_pass_barrier() {
     });
 }
 
+#[test]
+fn dmp_transact_as_superuser_should_not_be_filtered() {
+    MockNet::reset();
+
+    // `set_balance` is not allowed by the `XcmTransactFilter`, which only restricts the
+    // derivative accounts of sibling chains.
+    let bob = sp_runtime::AccountId32::new([1u8; 32]);
+    let set_balance = parachain::RuntimeCall::Balances(
+        pallet_balances::Call::<parachain::Runtime>::set_balance {
+            who: bob.clone(),
+            new_free: 1_000,
+            new_reserved: 0,
+        },
+    );
+
+    Relay::execute_with(|| {
+        assert_ok!(RelayChainPalletXcm::send_xcm(
+            Here,
+            Parachain(1),
+            Xcm(vec![Transact {
+                origin_type: OriginKind::Superuser,
+                require_weight_at_most: INITIAL_BALANCE as u64,
+                call: set_balance.encode().into(),
+            }]),
+        ));
+    });
+
+    ParaA::execute_with(|| {
+        assert_eq!(parachain::Balances::free_balance(&bob), 1_000);
+    });
+}
+
 #[test]
 fn ump() {
     MockNet::reset();
@@ -194,6 +230,465 @@ fn xcmp_transact_from_sibling_parachain_blocked_by_barrier() {
     });
 }
 
+// Message sent by `ALICE` on ParaA to transact `call` from its derivative account on ParaB,
+// paying for the execution with `fees` of the ParaA native asset.
+fn transact_from_alice_derivative_on_para_b<C>(fees: u128, call: Vec<u8>) -> Xcm<C> {
+    let para_a_asset = MultiAsset::from((MultiLocation::new(1, X1(Parachain(PARA_A_ID))), fees));
+    Xcm(vec![
+        DescendOrigin(X1(AccountId32 {
+            network: NetworkId::Any,
+            id: ALICE.into(),
+        })),
+        WithdrawAsset(para_a_asset.clone().into()),
+        buy_execution(para_a_asset),
+        Transact {
+            origin_type: OriginKind::SovereignAccount,
+            require_weight_at_most: 1_000_000_000,
+            call: call.into(),
+        },
+    ])
+}
+
+#[test]
+fn transact_from_sibling_derivative_account_should_work() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let units_per_second = 1_000_000_000u128;
+    let para_a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(units_per_second),
+        None,
+    );
+
+    let alice_derivative_on_b = parachain::LocationToAccountId::convert_ref(MultiLocation::new(
+        1,
+        X2(
+            Parachain(PARA_A_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    ))
+    .unwrap();
+    assert_ne!(alice_derivative_on_b, ALICE);
+
+    let bob = sp_runtime::AccountId32::new([1u8; 32]);
+    let transfer =
+        parachain::RuntimeCall::Balances(pallet_balances::Call::<parachain::Runtime>::transfer {
+            dest: bob.clone(),
+            value: 1_000,
+        });
+    let fees = INITIAL_BALANCE;
+
+    let mut fee = 0;
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::Balances::transfer(
+            parachain::RuntimeOrigin::signed(ALICE),
+            alice_derivative_on_b.clone(),
+            10_000,
+        ));
+        assert_ok!(parachain::Assets::mint_into(
+            para_a_asset_id_on_b,
+            &alice_derivative_on_b,
+            fees
+        ));
+        let mut message = transact_from_alice_derivative_on_para_b(fees, transfer.encode());
+        let weight =
+            <ParaXcmExecutorConfig as xcm_executor::Config>::Weigher::weight(&mut message).unwrap();
+        fee = calculate_fee(units_per_second, weight);
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            transact_from_alice_derivative_on_para_b(fees, transfer.encode()),
+        ));
+    });
+
+    // The transfer is dispatched from the derivative account, which paid the fee in the
+    // registered ParaA asset.
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Balances::free_balance(&bob), 1_000);
+        assert_eq!(
+            parachain::Balances::free_balance(&alice_derivative_on_b),
+            9_000
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, XcmFeesAccount::get()),
+            fee
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, &alice_derivative_on_b),
+            0
+        );
+    });
+}
+
+#[test]
+fn transact_from_sibling_derivative_account_should_respect_call_filter() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let units_per_second = 1_000_000_000u128;
+    let para_a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(units_per_second),
+        None,
+    );
+
+    let alice_derivative_on_b = parachain::LocationToAccountId::convert_ref(MultiLocation::new(
+        1,
+        X2(
+            Parachain(PARA_A_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    ))
+    .unwrap();
+
+    let bob = sp_runtime::AccountId32::new([1u8; 32]);
+    // `transfer_all` is not allowed by the `XcmTransactFilter`
+    let transfer_all = parachain::RuntimeCall::Balances(pallet_balances::Call::<
+        parachain::Runtime,
+    >::transfer_all {
+        dest: bob.clone(),
+        keep_alive: false,
+    });
+    let fees = INITIAL_BALANCE;
+
+    let mut fee = 0;
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::Balances::transfer(
+            parachain::RuntimeOrigin::signed(ALICE),
+            alice_derivative_on_b.clone(),
+            10_000,
+        ));
+        assert_ok!(parachain::Assets::mint_into(
+            para_a_asset_id_on_b,
+            &alice_derivative_on_b,
+            fees
+        ));
+        let mut message = transact_from_alice_derivative_on_para_b(fees, transfer_all.encode());
+        let weight =
+            <ParaXcmExecutorConfig as xcm_executor::Config>::Weigher::weight(&mut message).unwrap();
+        fee = calculate_fee(units_per_second, weight);
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            transact_from_alice_derivative_on_para_b(fees, transfer_all.encode()),
+        ));
+    });
+
+    // The fee is paid, but the call is filtered out of the derivative account origin.
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Balances::free_balance(&bob), 0);
+        assert_eq!(
+            parachain::Balances::free_balance(&alice_derivative_on_b),
+            10_000
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, &alice_derivative_on_b),
+            fees - fee
+        );
+    });
+}
+
+#[test]
+fn withdraw_from_sibling_derivative_account_should_respect_outbound_rate_limit() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let para_a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(1_000_000_000u128),
+        None,
+    );
+
+    let alice_derivative_on_b = parachain::LocationToAccountId::convert_ref(MultiLocation::new(
+        1,
+        X2(
+            Parachain(PARA_A_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    ))
+    .unwrap();
+
+    let remark = parachain::RuntimeCall::System(
+        frame_system::Call::<parachain::Runtime>::remark_with_event {
+            remark: vec![1, 2, 3],
+        },
+    );
+    let fees = INITIAL_BALANCE;
+
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::Assets::mint_into(
+            para_a_asset_id_on_b,
+            &alice_derivative_on_b,
+            fees
+        ));
+        assert_ok!(parachain::AssetManager::set_outbound_rate_limit(
+            parachain::RuntimeOrigin::root(),
+            para_a_asset_id_on_b,
+            fees - 1,
+            100
+        ));
+    });
+
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            transact_from_alice_derivative_on_para_b(fees, remark.encode()),
+        ));
+    });
+
+    // The derivative account is controlled on ParaB, so its withdrawal counts as outbound.
+    ParaB::execute_with(|| {
+        use parachain::{RuntimeEvent, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::AssetManager(pallet_asset_manager::Event::OutboundTransferRejected {
+                asset_id,
+                amount,
+            }) if asset_id == para_a_asset_id_on_b && amount == fees
+        )));
+        assert!(!System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::System(frame_system::Event::Remarked { .. })
+        )));
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, &alice_derivative_on_b),
+            fees
+        );
+    });
+}
+
+// Message sent by ParaA to deposit `amount` of its native asset on ParaB into the MantaPay
+// private ledger, for the transfer post whose hash is `commitment`.
+fn private_deposit_from_para_a<C>(amount: u128, commitment: [u8; 32]) -> Xcm<C> {
+    let para_a_asset = MultiAsset::from((MultiLocation::new(1, X1(Parachain(PARA_A_ID))), amount));
+    Xcm(vec![
+        ReserveAssetDeposited(para_a_asset.clone().into()),
+        ClearOrigin,
+        buy_execution(para_a_asset),
+        DepositAsset {
+            assets: All.into(),
+            max_assets: 1,
+            beneficiary: MultiLocation::new(
+                0,
+                X2(
+                    PalletInstance(parachain::MantaPayPalletIndex::get()),
+                    GeneralKey(WeakBoundedVec::force_from(commitment.to_vec(), None)),
+                ),
+            ),
+        },
+    ])
+}
+
+#[test]
+fn private_deposit_from_sibling_should_be_held_until_reclaimed() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let para_a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    let amount = 1_000;
+    let commitment = [7u8; 32];
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            private_deposit_from_para_a(amount, commitment),
+        ));
+    });
+
+    // The deposit is held by MantaPay until it is shielded or reclaimed.
+    let bob = sp_runtime::AccountId32::new([1u8; 32]);
+    ParaB::execute_with(|| {
+        use parachain::{MantaPay, RuntimeEvent, RuntimeOrigin, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::MantaPay(pallet_manta_pay::Event::PrivateDepositReceived {
+                commitment: received,
+                ..
+            }) if received == commitment
+        )));
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, MantaPay::account_id()),
+            amount
+        );
+
+        assert_noop!(
+            MantaPay::reclaim_deposit(RuntimeOrigin::root(), commitment, bob.clone()),
+            pallet_manta_pay::Error::<parachain::Runtime>::PendingDepositNotExpired
+        );
+        System::set_block_number(System::block_number() + 10);
+        assert_ok!(MantaPay::reclaim_deposit(
+            RuntimeOrigin::root(),
+            commitment,
+            bob.clone()
+        ));
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, MantaPay::account_id()),
+            0
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, &bob),
+            amount
+        );
+    });
+
+    // The commitment can be deposited to again once its previous deposit was reclaimed.
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            private_deposit_from_para_a(amount, commitment),
+        ));
+    });
+    ParaB::execute_with(|| {
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, parachain::MantaPay::account_id()),
+            amount
+        );
+    });
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+#[test]
+fn private_deposit_and_transact_from_sibling_derivative_account_should_shield_it() {
+    use codec::Decode;
+    use frame_support::Hashable;
+    use manta_support::manta_pay::{asset_value_decode, id_from_field, TransferPost};
+    use pallet_manta_pay::benchmark::precomputed_coins::TO_PRIVATE;
+
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, true);
+    let para_a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    // The precomputed post shields the first non-native asset registered on ParaB.
+    let post = TransferPost::decode(&mut &*TO_PRIVATE).unwrap();
+    let asset = post.source(0).unwrap();
+    assert_eq!(id_from_field(asset.id), Some(para_a_asset_id_on_b));
+    let amount = asset_value_decode(asset.value);
+
+    let alice_derivative_on_b = parachain::LocationToAccountId::convert_ref(MultiLocation::new(
+        1,
+        X2(
+            Parachain(PARA_A_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    ))
+    .unwrap();
+    let fees = INITIAL_BALANCE / 2;
+    ParaB::execute_with(|| {
+        assert_ok!(parachain::Balances::transfer(
+            parachain::RuntimeOrigin::signed(ALICE),
+            alice_derivative_on_b.clone(),
+            fees,
+        ));
+        assert_ok!(parachain::Assets::mint_into(
+            para_a_asset_id_on_b,
+            &alice_derivative_on_b,
+            amount
+        ));
+    });
+
+    // The derivative account pays the fee in the native asset of ParaB, deposits exactly the
+    // source of the post and then sends the post in a `Transact`.
+    let native_asset = MultiAsset::from((MultiLocation::here(), fees));
+    let para_a_asset = MultiAsset::from((MultiLocation::new(1, X1(Parachain(PARA_A_ID))), amount));
+    let to_private_from_deposit = parachain::RuntimeCall::MantaPay(pallet_manta_pay::Call::<
+        parachain::Runtime,
+    >::to_private_from_deposit {
+        post: post.clone(),
+    });
+    let message = Xcm(vec![
+        DescendOrigin(X1(AccountId32 {
+            network: NetworkId::Any,
+            id: ALICE.into(),
+        })),
+        WithdrawAsset(vec![native_asset.clone(), para_a_asset.clone()].into()),
+        buy_execution(native_asset),
+        DepositAsset {
+            assets: Wild(AllOf {
+                id: para_a_asset.id,
+                fun: WildFungible,
+            }),
+            max_assets: 1,
+            beneficiary: MultiLocation::new(
+                0,
+                X2(
+                    PalletInstance(parachain::MantaPayPalletIndex::get()),
+                    GeneralKey(WeakBoundedVec::force_from(post.blake2_256().to_vec(), None)),
+                ),
+            ),
+        },
+        Transact {
+            origin_type: OriginKind::SovereignAccount,
+            require_weight_at_most: 100_000_000_000,
+            call: to_private_from_deposit.encode().into(),
+        },
+    ]);
+    ParaA::execute_with(|| {
+        assert_ok!(ParachainPalletXcm::send_xcm(
+            Here,
+            (Parent, Parachain(PARA_B_ID)),
+            message,
+        ));
+    });
+
+    ParaB::execute_with(|| {
+        use parachain::{MantaPay, RuntimeEvent, System};
+        assert!(System::events().iter().any(|r| matches!(
+            r.event,
+            RuntimeEvent::MantaPay(pallet_manta_pay::Event::ToPrivate { ref source, .. })
+                if source == &MantaPay::account_id()
+        )));
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, MantaPay::account_id()),
+            amount
+        );
+        assert_eq!(
+            parachain::Assets::balance(para_a_asset_id_on_b, &alice_derivative_on_b),
+            0
+        );
+    });
+}
+
 #[test]
 fn reserve_transfer_relaychain_to_parachain_a_then_back() {
     MockNet::reset();
@@ -367,6 +862,96 @@ fn send_para_a_native_asset_to_para_b() {
     });
 }
 
+#[test]
+fn send_para_a_native_asset_to_para_b_above_outbound_rate_limit_should_fail() {
+    MockNet::reset();
+
+    let para_a_source_location = create_asset_location(1, PARA_A_ID);
+    let para_a_asset_metadata = create_asset_metadata("ParaAToken", "ParaA", 18, 1, false, false);
+
+    let a_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+    let a_asset_id_on_b = register_assets_on_parachain::<ParaB>(
+        &para_a_source_location,
+        &para_a_asset_metadata,
+        Some(0u128),
+        None,
+    );
+
+    let dest = MultiLocation {
+        parents: 1,
+        interior: X2(
+            Parachain(PARA_B_ID),
+            AccountId32 {
+                network: NetworkId::Any,
+                id: ALICE.into(),
+            },
+        ),
+    };
+    let limit = INITIAL_BALANCE / 4;
+
+    ParaA::execute_with(|| {
+        assert_ok!(parachain::AssetManager::set_outbound_rate_limit(
+            parachain::RuntimeOrigin::root(),
+            a_asset_id_on_a,
+            limit,
+            100
+        ));
+
+        // Transfers above the limit are rejected on the sender side. The failed extrinsic is
+        // reverted together with the rejection event, so only its error is observable.
+        let call = parachain::RuntimeCall::XTokens(orml_xtokens::Call::transfer {
+            currency_id: parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+            amount: limit + 1,
+            dest: Box::new(VersionedMultiLocation::V1(dest.clone())),
+            dest_weight_limit: WeightLimit::Limited(ADVERTISED_DEST_WEIGHT),
+        });
+        assert_eq!(
+            call.dispatch(parachain::RuntimeOrigin::signed(ALICE))
+                .map_err(|e| e.error),
+            Err(orml_xtokens::Error::<parachain::Runtime>::XcmExecutionFailed.into())
+        );
+        assert!(!parachain::System::events().iter().any(|r| matches!(
+            r.event,
+            parachain::RuntimeEvent::AssetManager(
+                pallet_asset_manager::Event::OutboundTransferRejected { .. }
+            )
+        )));
+        assert_eq!(parachain::Balances::free_balance(&ALICE), INITIAL_BALANCE);
+
+        // The whole limit can be used, after which no more transfers are accepted.
+        assert_ok!(parachain::XTokens::transfer(
+            parachain::RuntimeOrigin::signed(ALICE),
+            parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+            limit,
+            Box::new(VersionedMultiLocation::V1(dest.clone())),
+            WeightLimit::Limited(ADVERTISED_DEST_WEIGHT)
+        ));
+        assert_err!(
+            parachain::XTokens::transfer(
+                parachain::RuntimeOrigin::signed(ALICE),
+                parachain::CurrencyId::MantaCurrency(a_asset_id_on_a),
+                1,
+                Box::new(VersionedMultiLocation::V1(dest)),
+                WeightLimit::Limited(ADVERTISED_DEST_WEIGHT)
+            ),
+            orml_xtokens::Error::<parachain::Runtime>::XcmExecutionFailed
+        );
+        assert_eq!(
+            parachain::Balances::free_balance(&ALICE),
+            INITIAL_BALANCE - limit
+        );
+    });
+
+    ParaB::execute_with(|| {
+        assert_eq!(parachain::Assets::balance(a_asset_id_on_b, &ALICE), limit);
+    });
+}
+
 #[test]
 fn send_para_b_asset_to_para_b() {
     MockNet::reset();
@@ -1187,6 +1772,96 @@ fn receive_relay_asset_with_trader_on_parachain() {
     });
 }
 
+#[test]
+fn multi_asset_trader_should_try_each_asset_and_refund_per_asset() {
+    MockNet::reset();
+
+    let relay_source_location = AssetLocation(VersionedMultiLocation::V1(MultiLocation::parent()));
+    let para_b_source_location = create_asset_location(1, PARA_B_ID);
+
+    let relay_asset_metadata = create_asset_metadata("Kusama", "KSM", 12, 1, false, true);
+    let para_b_asset_metadata = create_asset_metadata("ParaB", "ParaB", 12, 1, false, true);
+
+    let units_per_second = 1_000_000_000u128;
+    let weight = 4_000_000_000u64;
+    let relay_fee = calculate_fee(units_per_second, weight);
+    let native_fee = calculate_fee(ParaTokenPerSecond::get().1, weight);
+    let amount = 10 * relay_fee.max(native_fee);
+
+    let relay_asset_id_on_a = register_assets_on_parachain::<ParaA>(
+        &relay_source_location,
+        &relay_asset_metadata,
+        Some(units_per_second),
+        None,
+    );
+    // ParaB asset has no units per second, so it cannot pay for execution.
+    let _ = register_assets_on_parachain::<ParaA>(
+        &para_b_source_location,
+        &para_b_asset_metadata,
+        None,
+        None,
+    );
+
+    let relay_location = MultiLocation::parent();
+    let para_b_location = MultiLocation::new(1, X1(Parachain(PARA_B_ID)));
+
+    ParaA::execute_with(|| {
+        let mut trader = <ParaXcmExecutorConfig as xcm_executor::Config>::Trader::new();
+
+        // The ParaB asset is skipped and the relay asset pays for the weight.
+        let payment = Assets::from(vec![
+            MultiAsset::from((para_b_location.clone(), amount)),
+            MultiAsset::from((relay_location.clone(), amount)),
+        ]);
+        let unused = trader.buy_weight(weight, payment).unwrap();
+        assert_eq!(
+            unused,
+            Assets::from(vec![
+                MultiAsset::from((para_b_location.clone(), amount)),
+                MultiAsset::from((relay_location.clone(), amount - relay_fee)),
+            ])
+        );
+
+        // A payment without any priced asset is too expensive.
+        assert_eq!(
+            trader.buy_weight(weight, MultiAsset::from((para_b_location, amount)).into()),
+            Err(XcmError::TooExpensive)
+        );
+
+        // The native asset is priced by the runtime `WeightToFee`.
+        let unused = trader
+            .buy_weight(weight, MultiAsset::from((Here, amount)).into())
+            .unwrap();
+        assert_eq!(unused, MultiAsset::from((Here, amount - native_fee)).into());
+
+        // Refunds start from the last asset and never exceed what it paid for.
+        assert_eq!(
+            trader.refund_weight(weight / 2),
+            Some((Here, native_fee / 2).into())
+        );
+        assert_eq!(
+            trader.refund_weight(weight),
+            Some((Here, native_fee - native_fee / 2).into())
+        );
+        assert_eq!(
+            trader.refund_weight(weight / 4),
+            Some((relay_location.clone(), relay_fee / 4).into())
+        );
+
+        // The remaining fees are taken as revenue when the trader is dropped.
+        let native_fees_before = parachain::Balances::free_balance(XcmFeesAccount::get());
+        drop(trader);
+        assert_eq!(
+            parachain::Assets::balance(relay_asset_id_on_a, XcmFeesAccount::get()),
+            relay_fee - relay_fee / 4
+        );
+        assert_eq!(
+            parachain::Balances::free_balance(XcmFeesAccount::get()),
+            native_fees_before
+        );
+    });
+}
+
 #[test]
 fn send_para_a_asset_to_para_b_with_trader_and_fee() {
     MockNet::reset();
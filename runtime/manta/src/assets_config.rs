@@ -16,7 +16,7 @@
 
 use super::{
     weights, xcm_config::SelfReserve, AssetManager, Assets, Balances,
-    NativeTokenExistentialDeposit, Runtime, RuntimeEvent, RuntimeOrigin,
+    NativeTokenExistentialDeposit, Runtime, RuntimeEvent, RuntimeOrigin, Treasury, MANTA,
 };
 
 use manta_primitives::{
@@ -24,14 +24,15 @@ use manta_primitives::{
         AssetConfig, AssetIdType, AssetLocation, AssetRegistry, AssetRegistryMetadata,
         AssetStorageMetadata, BalanceType, LocationType, NativeAndNonNative,
     },
-    constants::{ASSET_MANAGER_PALLET_ID, MANTA_DECIMAL},
+    constants::{time::DAYS, ASSET_MANAGER_PALLET_ID, MANTA_DECIMAL, WEIGHT_PER_SECOND},
     types::{AccountId, Balance, MantaAssetId},
 };
 
 use frame_support::{
     pallet_prelude::DispatchResult,
     parameter_types,
-    traits::{AsEnsureOriginWithArg, ConstU32},
+    traits::{AsEnsureOriginWithArg, ConstU32, Get},
+    weights::{Weight, WeightToFee},
     PalletId,
 };
 use frame_system::EnsureRoot;
@@ -139,6 +140,7 @@ parameter_types! {
         is_sufficient: true,
     };
     pub const AssetManagerPalletId: PalletId = ASSET_MANAGER_PALLET_ID;
+    pub const AssetRegistrationDeposit: Balance = 100 * MANTA;
 }
 
 pub type MantaConcreteFungibleLedger =
@@ -165,6 +167,21 @@ impl AssetConfig<Runtime> for MantaAssetConfig {
     type StorageMetadata = AssetStorageMetadata;
     type AssetRegistry = MantaAssetRegistry;
     type FungibleLedger = MantaConcreteFungibleLedger;
+    type FrozenAssets = AssetManager;
+    type RestrictedAssets = AssetManager;
+    type OutboundRateLimits = AssetManager;
+}
+
+/// Native token units charged per second of XCM execution, from which the units-per-second of
+/// assets with a relative price are derived.
+pub struct NativeUnitsPerSecond;
+impl Get<u128> for NativeUnitsPerSecond {
+    #[inline]
+    fn get() -> u128 {
+        <Runtime as pallet_transaction_payment::Config>::WeightToFee::weight_to_fee(
+            &Weight::from_ref_time(WEIGHT_PER_SECOND),
+        )
+    }
 }
 
 impl pallet_asset_manager::Config for Runtime {
@@ -175,5 +192,12 @@ impl pallet_asset_manager::Config for Runtime {
     type AssetConfig = MantaAssetConfig;
     type ModifierOrigin = EnsureRoot<AccountId>;
     type PalletId = AssetManagerPalletId;
+    type MaxAliasLocations = ConstU32<4>;
+    type Currency = Balances;
+    type RegistrationDeposit = AssetRegistrationDeposit;
+    type Slash = Treasury;
+    type PriceFeederOrigin = EnsureRoot<AccountId>;
+    type NativeUnitsPerSecond = NativeUnitsPerSecond;
+    type PriceStalenessThreshold = ConstU32<DAYS>;
     type WeightInfo = weights::pallet_asset_manager::SubstrateWeight<Runtime>;
 }
@@ -184,7 +184,7 @@ parameter_types! {
     pub const SS58Prefix: u8 = manta_primitives::constants::MANTA_SS58PREFIX;
 }
 
-// Don't allow permission-less asset creation.
+// Don't allow permission-less asset creation, except for restricted proposals backed by a deposit.
 pub struct MantaFilter;
 impl Contains<RuntimeCall> for MantaFilter {
     fn contains(call: &RuntimeCall) -> bool {
@@ -208,6 +208,8 @@ impl Contains<RuntimeCall> for MantaFilter {
             | RuntimeCall::Multisig(_)
             | RuntimeCall::AuthorInherent(pallet_author_inherent::Call::kick_off_authorship_validation {..}) // executes unsigned on every block
             | RuntimeCall::XTokens(orml_xtokens::Call::transfer {..})
+            // Proposed assets are only registered once governance approves them
+            | RuntimeCall::AssetManager(pallet_asset_manager::Call::propose_asset_registration {..})
             | RuntimeCall::Balances(_)
             | RuntimeCall::Preimage(_)
             | RuntimeCall::Utility(_) => true,
@@ -337,11 +339,14 @@ parameter_types! {
     pub const DefaultParachainBondReservePercent: Percent = Percent::zero();
     pub DefaultBlocksPerRound: BlockNumber = prod_or_fast!(6 * HOURS,15,"MANTA_DEFAULTBLOCKSPERROUND");
     pub LeaveDelayRounds: BlockNumber = prod_or_fast!(28,1,"MANTA_LEAVEDELAYROUNDS"); // == 7 * DAYS / 6 * HOURS
+    /// Receives rewards of stakers paying out to the treasury
+    pub StakingTreasuryAccount: AccountId = Treasury::account_id();
 }
 impl pallet_parachain_staking::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type BlockAuthor = AuthorInherent;
+    type TreasuryAccount = StakingTreasuryAccount;
     type MonetaryGovernanceOrigin = EnsureRoot<AccountId>;
     /// Minimum round length is 2 minutes (10 * 12 second block times)
     type MinBlocksPerRound = ConstU32<10>;
@@ -357,6 +362,12 @@ impl pallet_parachain_staking::Config for Runtime {
     type RevokeDelegationDelay = LeaveDelayRounds;
     /// Rounds before the delegator bond increase/decrease can be executed
     type DelegationBondLessDelay = LeaveDelayRounds;
+    /// Rounds before the candidate commission increase takes effect
+    type CommissionIncreaseDelay = LeaveDelayRounds;
+    /// Rounds before the redelegation can be executed, one day
+    type RedelegationDelay = ConstU32<4>;
+    /// Rounds between two redelegations of a delegator
+    type RedelegationCooldown = LeaveDelayRounds;
     /// Rounds before the reward is paid
     type RewardPaymentDelay = ConstU32<2>;
     /// Minimum collators selected per round, default at genesis and minimum forever after
@@ -367,6 +378,8 @@ impl pallet_parachain_staking::Config for Runtime {
     type MaxBottomDelegationsPerCandidate = ConstU32<50>;
     /// Maximum delegations per delegator
     type MaxDelegationsPerDelegator = ConstU32<25>;
+    type MaxParachainBondBeneficiaries = ConstU32<8>;
+    type MaxParachainBondLocks = ConstU32<64>;
     type DefaultCollatorCommission = DefaultCollatorCommission;
     type DefaultParachainBondReservePercent = DefaultParachainBondReservePercent;
     /// Minimum stake on a collator to be considered for block production
@@ -470,6 +483,7 @@ impl manta_collator_selection::Config for Runtime {
     /// Nimbus filter pipeline step 2:
     /// Filters collators not part of the current pallet_session::validators()
     type CanAuthor = AuraAuthorFilter;
+    type PerformanceHistoryLength = ConstU32<4>; // 4 sessions of performance for reputation
 }
 
 parameter_types! {
@@ -581,7 +595,7 @@ pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, Si
 
 /// Types for runtime upgrading.
 /// Each type should implement trait `OnRuntimeUpgrade`.
-pub type OnRuntimeUpgradeHooks = ();
+pub type OnRuntimeUpgradeHooks = (manta_collator_selection::migrations::MigrateV1ToV2<Runtime>,);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -748,6 +762,78 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_parachain_staking::runtime::ParachainStakingApi<Block, AccountId, Balance> for Runtime {
+        fn delegator_position(
+            delegator: AccountId,
+        ) -> Option<pallet_parachain_staking::DelegatorPosition<AccountId, Balance>> {
+            ParachainStaking::delegator_position(&delegator)
+        }
+        fn delegator_pending_requests(
+            delegator: AccountId,
+        ) -> Vec<pallet_parachain_staking::PendingUnbondRequest<AccountId, Balance>> {
+            ParachainStaking::delegator_pending_requests(&delegator)
+        }
+        fn candidate_summary(
+            candidate: AccountId,
+        ) -> Option<pallet_parachain_staking::CandidateSummary<AccountId, Balance>> {
+            ParachainStaking::candidate_summary(&candidate)
+        }
+        fn candidate_summaries() -> Vec<pallet_parachain_staking::CandidateSummary<AccountId, Balance>> {
+            ParachainStaking::candidate_summaries()
+        }
+        fn estimated_round_reward(account: AccountId) -> Balance {
+            ParachainStaking::estimated_round_reward(&account)
+        }
+    }
+
+    impl pallet_asset_manager::runtime::AssetManagerApi<
+        Block,
+        manta_primitives::types::MantaAssetId,
+        manta_primitives::assets::AssetLocation,
+        manta_primitives::assets::AssetRegistryMetadata<Balance>,
+    > for Runtime {
+        fn asset(
+            asset_id: manta_primitives::types::MantaAssetId,
+        ) -> Option<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_info(asset_id)
+        }
+        fn assets() -> Vec<pallet_asset_manager::AssetInfoOf<Runtime>> {
+            AssetManager::asset_infos()
+        }
+        fn quote_weight_fee(asset_id: manta_primitives::types::MantaAssetId, weight: u64) -> Option<u128> {
+            AssetManager::quote_weight_fee(asset_id, weight)
+        }
+        fn quote_transfer_fee(
+            asset_id: manta_primitives::types::MantaAssetId,
+            dest: manta_primitives::assets::AssetLocation,
+            dest_weight: u64,
+        ) -> Option<u128> {
+            AssetManager::quote_transfer_fee(asset_id, dest, dest_weight)
+        }
+    }
+
+    impl manta_collator_selection::runtime::CollatorSelectionApi<Block, AccountId> for Runtime {
+        fn collator_performance(
+            collator: AccountId,
+        ) -> Vec<manta_collator_selection::SessionPerformance> {
+            CollatorSelection::performance_history(collator)
+        }
+        fn reputation(collator: AccountId) -> Percent {
+            CollatorSelection::reputation(&collator)
+        }
+        fn reputations() -> Vec<(AccountId, Percent)> {
+            CollatorSelection::reputations()
+        }
+    }
+
+    impl session_key_primitives::runtime::SessionKeysRegistryApi<Block, AccountId> for Runtime {
+        fn session_key_status(
+            account: AccountId,
+        ) -> session_key_primitives::runtime::SessionKeyStatus {
+            runtime_common::session_keys::session_key_status::<Runtime, _>(account, |_, _| None)
+        }
+    }
+
     impl nimbus_primitives::NimbusApi<Block> for Runtime {
         fn can_author(author: NimbusId, relay_parent: u32, parent_header: &<Block as BlockT>::Header) -> bool {
             let next_block_number = parent_header.number + 1;
@@ -77,6 +77,15 @@ pub trait WeightInfo {
     fn round_transition_on_initialize(x: u32, y: u32, ) -> Weight;
     fn pay_one_collator_reward(y: u32, ) -> Weight;
     fn base_on_initialize() -> Weight;
+    fn set_reward_destination() -> Weight;
+    fn set_collator_commission_bounds() -> Weight;
+    fn set_candidate_commission() -> Weight;
+    fn schedule_redelegate() -> Weight;
+    fn execute_redelegate() -> Weight;
+    fn set_offline_policy() -> Weight;
+    fn rejoin() -> Weight;
+    fn set_parachain_bond_beneficiaries(x: u32, ) -> Weight;
+    fn unlock_parachain_bond() -> Weight;
 }
 
 /// Weights for pallet_parachain_staking using the Substrate node and recommended hardware.
@@ -418,6 +427,98 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for Substrate
 		// Minimum execution time: 5_875 nanoseconds.
 		Weight::from_ref_time(6_090_000)
 	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking RewardDestinations (r:1 w:1)
+	fn set_reward_destination() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CollatorCommissionBounds (r:1 w:1)
+	fn set_collator_commission_bounds() -> Weight {
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking CollatorCommissionBounds (r:1 w:0)
+	// Storage: ParachainStaking CandidateCommission (r:1 w:1)
+	// Storage: ParachainStaking CollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking CandidateCommissionRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	fn set_candidate_commission() -> Weight {
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_ref_time(27_000_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking LastRedelegation (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking TopDelegations (r:1 w:0)
+	// Storage: ParachainStaking RedelegationTargets (r:0 w:1)
+	fn schedule_redelegate() -> Weight {
+		// Minimum execution time: 42_000 nanoseconds.
+		Weight::from_ref_time(42_000_000)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking RedelegationTargets (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	fn execute_redelegate() -> Weight {
+		// Minimum execution time: 98_000 nanoseconds.
+		Weight::from_ref_time(98_000_000)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	// Storage: ParachainStaking OfflinePolicyConfig (r:1 w:1)
+	fn set_offline_policy() -> Weight {
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking JailedCandidates (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MissedRounds (r:0 w:1)
+	fn rejoin() -> Weight {
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: ParachainStaking ParachainBondBeneficiaries (r:1 w:1)
+	fn set_parachain_bond_beneficiaries(x: u32, ) -> Weight {
+		// Minimum execution time: 17_000 nanoseconds.
+		Weight::from_ref_time(17_000_000)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(1_200_000).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking ParachainBondLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn unlock_parachain_bond() -> Weight {
+		// Minimum execution time: 31_000 nanoseconds.
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
 
 // For backwards compatibility and tests
@@ -758,4 +859,96 @@ impl WeightInfo for () {
 		// Minimum execution time: 5_875 nanoseconds.
 		Weight::from_ref_time(6_090_000)
 	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking RewardDestinations (r:1 w:1)
+	fn set_reward_destination() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CollatorCommissionBounds (r:1 w:1)
+	fn set_collator_commission_bounds() -> Weight {
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking CollatorCommissionBounds (r:1 w:0)
+	// Storage: ParachainStaking CandidateCommission (r:1 w:1)
+	// Storage: ParachainStaking CollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking CandidateCommissionRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	fn set_candidate_commission() -> Weight {
+		// Minimum execution time: 27_000 nanoseconds.
+		Weight::from_ref_time(27_000_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking LastRedelegation (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:1 w:0)
+	// Storage: ParachainStaking TopDelegations (r:1 w:0)
+	// Storage: ParachainStaking RedelegationTargets (r:0 w:1)
+	fn schedule_redelegate() -> Weight {
+		// Minimum execution time: 42_000 nanoseconds.
+		Weight::from_ref_time(42_000_000)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationScheduledRequests (r:2 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking RedelegationTargets (r:1 w:1)
+	// Storage: ParachainStaking CandidateInfo (r:2 w:2)
+	// Storage: ParachainStaking TopDelegations (r:2 w:2)
+	// Storage: ParachainStaking BottomDelegations (r:2 w:2)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Total (r:1 w:1)
+	fn execute_redelegate() -> Weight {
+		// Minimum execution time: 98_000 nanoseconds.
+		Weight::from_ref_time(98_000_000)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	// Storage: ParachainStaking OfflinePolicyConfig (r:1 w:1)
+	fn set_offline_policy() -> Weight {
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidateInfo (r:1 w:1)
+	// Storage: ParachainStaking JailedCandidates (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MissedRounds (r:0 w:1)
+	fn rejoin() -> Weight {
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: ParachainStaking ParachainBondBeneficiaries (r:1 w:1)
+	fn set_parachain_bond_beneficiaries(x: u32, ) -> Weight {
+		// Minimum execution time: 17_000 nanoseconds.
+		Weight::from_ref_time(17_000_000)
+			// Standard Error: 1_000
+			.saturating_add(Weight::from_ref_time(1_200_000).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking ParachainBondLocks (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn unlock_parachain_bond() -> Weight {
+		// Minimum execution time: 31_000 nanoseconds.
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
 }
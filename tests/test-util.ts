@@ -1,3 +1,4 @@
+import { ApiPromise } from '@polkadot/api';
 import { xxhashAsU8a } from '@polkadot/util-crypto';
 import type { HexString } from '@polkadot/util/types';
 import { u8aToHex, u8aToBigInt, numberToU8a, nToU8a} from '@polkadot/util';
@@ -16,6 +17,27 @@ export async function delay(ms: number) {
     return new Promise( resolve => setTimeout(resolve, ms) );
 }
 
+/**
+ * seal a new block on a dev node started with `--sealing manual`.
+ * @param api api object connecting to the node.
+ * @param finalize whether the block should be finalized.
+ * @returns hash of the sealed block.
+ */
+export async function createBlock(api: ApiPromise, finalize = true): Promise<string> {
+    const created = await api.rpc.engine.createBlock(true, finalize);
+    return created.blockHash.toHex();
+}
+
+/**
+ * move the timestamp of the next blocks sealed by a dev node forward.
+ * @param api api object connecting to the node.
+ * @param ms number of millisec to travel forward in time.
+ * @returns total offset from the system time in millisec.
+ */
+export async function advanceTimestamp(api: ApiPromise, ms: number): Promise<number> {
+    return api.provider.send('dev_advanceTimestamp', [ms]);
+}
+
 // emojis
 export const emojis = {
     post: String.fromCodePoint(0x1F4EE),